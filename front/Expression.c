@@ -58,6 +58,19 @@ Node* CreateReturn(Node* expr) {
   return b;
 }
 
+Node* CreateStructType(char* name, NodeList* fields) {
+  Node* b = AllocateNode();
+  if (b == NULL) return NULL;
+  
+  b->type = STRUCTDECL;
+  b->stmtCode = S_NONE;
+  b->exprCode = E_NONE;
+  b->opCode = O_NONE;
+  b->name = name;
+  b->arguments = fields;
+  return b;
+}
+
 Node* CreateAssign(char* var, Node* expr) {
   Node* b = AllocateNode();
   if (b == NULL) return NULL;
@@ -71,6 +84,45 @@ Node* CreateAssign(char* var, Node* expr) {
   return b;
 }
 
+Node* CreateConstDecl(char* var, Node* expr) {
+  Node* b = AllocateNode();
+  if (b == NULL) return NULL;
+  
+  b->type = STATEMENT;
+  b->stmtCode = CONSTDECL;
+  b->exprCode = E_NONE;
+  b->opCode = O_NONE;
+  b->name = var;
+  b->right = expr;
+  return b;
+}
+
+Node* CreateAssert(Node* expr, long line) {
+  Node* b = AllocateNode();
+  if (b == NULL) return NULL;
+  
+  b->type = STATEMENT;
+  b->stmtCode = ASSERT;
+  b->exprCode = E_NONE;
+  b->opCode = O_NONE;
+  b->left = expr;
+  b->value = line;
+  return b;
+}
+
+Node* CreateStore(Node* addr, Node* expr) {
+  Node* b = AllocateNode();
+  if (b == NULL) return NULL;
+  
+  b->type = STATEMENT;
+  b->stmtCode = STORE;
+  b->exprCode = E_NONE;
+  b->opCode = O_NONE;
+  b->left = addr;
+  b->right = expr;
+  return b;
+}
+
 Node* CreateBinaryOperation(OpType opcode, Node* left, Node* right) {
   Node* b = AllocateNode();
   if (b == NULL) return NULL;
@@ -149,6 +201,8 @@ Node* CreateNumber(long value) {
 void PrintProgram(NodeList* list) {
   while (list != NULL) {
     PrintNode(list->node);
+    // File scope constants are printed like statements
+    if (list->node->type == STATEMENT) printf(";\n");
     list = list->next;
   }
 }
@@ -170,8 +224,14 @@ void PrintNode(Node* node) {
 }
 
 void PrintFunctionDecl(Node* node) {
-  printf("long %s (", node->name);
+  if (node->value == LINKAGE_STATIC) printf("static ");
+  printf("%s %s (", node->left ? node->left->name : "long", node->name);
   PrintParameters(node->arguments);
+  // A prototype has no body
+  if (node->statements == NULL) {
+    printf(");\n");
+    return;
+  }
   printf(") {\n");
   PrintStatements(node->statements);
   printf("}\n");
@@ -200,6 +260,15 @@ void PrintStatement(Node* node) {
     case RETURN :
       PrintReturn(node);
       break;
+    case CONSTDECL :
+      PrintConstDecl(node);
+      break;
+    case ASSERT :
+      PrintAssert(node);
+      break;
+    case STORE :
+      PrintStore(node);
+      break;
     default :
       break;
   }
@@ -207,6 +276,15 @@ void PrintStatement(Node* node) {
 
 void PrintReturn(Node* node) {
   printf("return ");
+  // Both fields of a returned struct
+  if (node->right != NULL) {
+    printf("{");
+    PrintExpression(node->left);
+    printf(", ");
+    PrintExpression(node->right);
+    printf("}");
+    return;
+  }
   PrintExpression(node->left);
 }
 
@@ -215,6 +293,24 @@ void PrintAssignment(Node* node) {
   PrintExpression(node->right);
 }
 
+void PrintConstDecl(Node* node) {
+  printf("const long %s = ", node->name);
+  PrintExpression(node->right);
+}
+
+void PrintAssert(Node* node) {
+  printf("assert(");
+  PrintExpression(node->left);
+  printf(")");
+}
+
+void PrintStore(Node* node) {
+  printf("*");
+  PrintExpression(node->left);
+  printf(" = ");
+  PrintExpression(node->right);
+}
+
 void PrintExpression(Node* node) {
   Node* ref;
   switch (node->exprCode) {
@@ -226,13 +322,16 @@ void PrintExpression(Node* node) {
       } else if (ref->type == STATEMENT && ref->stmtCode == ASSIGN) {
         // This variable is a locally initialized variable
         printf("%s", node->name);
+      } else if (ref->type == STATEMENT && ref->stmtCode == CONSTDECL) {
+        // This variable is a compile-time constant
+        printf("%s", node->name);
       }
       break;
     case CONSTANT :
       printf("%ld", node->value);
       break;
     case PARAMETER :
-      printf("long %s", node->name);
+      printf(node->value == DECL_POINTER ? "long* %s" : "long %s", node->name);
       break;
     case OPERATION:
       PrintOperation(node);
@@ -277,6 +376,27 @@ void PrintOperation(Node* node) {
     case BSHL:
       PrintBinaryOperation(node, "<<");
       break;
+    case BLSHR:
+      PrintBinaryOperation(node, ">>>");
+      break;
+    case BNOT:
+      PrintUnaryOperation(node, "~");
+      break;
+    case DEREF:
+      PrintUnaryOperation(node, "*");
+      break;
+    case ADDRESSOF:
+      PrintUnaryOperation(node, "&");
+      break;
+    case RESULTHIGH:
+      if (node->left == NULL) {
+        printf("high(result)");
+        break;
+      }
+      printf("high(");
+      PrintExpression(node->left);
+      printf(")");
+      break;
     default :
       break;
   }
@@ -306,8 +426,9 @@ void PrintUnaryOperation(Node* node, char* op) {
 void FreeProgram(NodeList* list) {
   if (list == NULL) return;
   FreeProgram(list->next);
-  // Free each function declaration
-  FreeFunctionDecl(list->node);
+  // Free each function declaration, or file scope constant
+  if (list->node->type == STATEMENT) FreeStatement(list->node);
+  else FreeFunctionDecl(list->node);
   free(list);
 }
 
@@ -342,10 +463,14 @@ void FreeStatements(NodeList* list) {
 void FreeStatement(Node* node) {
   if (node->stmtCode == ASSIGN) return FreeAssignment(node);
   if (node->stmtCode == RETURN) return FreeReturn(node);
+  if (node->stmtCode == CONSTDECL) return FreeAssignment(node);
+  if (node->stmtCode == ASSERT) return FreeReturn(node);
+  if (node->stmtCode == STORE) return FreeBinaryOperation(node);
 }
 
 void FreeReturn(Node* node) {
   FreeExpression(node->left);
+  if (node->right != NULL) FreeExpression(node->right);
   free(node);
 }
 
@@ -395,9 +520,18 @@ void FreeOperation(Node* node) {
     case BXOR:
     case BSHR:
     case BSHL:
+    case BLSHR:
       return FreeBinaryOperation(node);
     case NEGATE:
+    case BNOT:
+    case DEREF:
+    case ADDRESSOF:
       return FreeUnaryOperation(node);
+    case RESULTHIGH:
+      // in a return of the call, it has no operand
+      if (node->left != NULL) return FreeUnaryOperation(node);
+      free(node);
+      return;
     default:
       break;
   }
@@ -12,12 +12,16 @@ typedef enum _NodeType {
   FUNCTIONDECL,
   STATEMENT,
   EXPRESSION,
+  STRUCTDECL,
 } NodeType;
 
 typedef enum _StmtType {
   S_NONE,
   ASSIGN,
   RETURN,
+  CONSTDECL,
+  ASSERT,
+  STORE,
 } StmtType;
 
 typedef enum _ExprType {
@@ -40,7 +44,12 @@ typedef enum _OpType {
   BAND,
   BXOR,
   BSHR,
-  BSHL
+  BSHL,
+  BNOT,
+  BLSHR,
+  DEREF,
+  ADDRESSOF,
+  RESULTHIGH
 } OpType;
 
 typedef struct _NodeList NodeList;
@@ -98,10 +107,16 @@ int NodeListLen(NodeList* nl);
  name: Name of the function
  arguments: stores a list of parameters, ordered from left to right.
  statements: stores a list of statements, ordered from top to bottom.
+             NULL for a prototype such as "long foo (long arg1);"
+ value: LINKAGE_STATIC for a "static" function, otherwise LINKAGE_EXTERNAL
+ left: The struct type node of the returned struct, NULL if it returns a long.
+       It is owned by the symbol table, not by the function.
  
  ** The following fields are not set and should not be used:
- left, right, and value
+ right
  */
+#define LINKAGE_EXTERNAL 0
+#define LINKAGE_STATIC 1
 Node* CreateFunctionDecl(char* name, NodeList* args, NodeList* stmts);
 
 /* Create a Node representing a return statement.
@@ -114,12 +129,39 @@ Node* CreateFunctionDecl(char* name, NodeList* args, NodeList* stmts);
  exprCode: E_NONE 
  opCode: O_NONE
  left: The node that represents the returned expression (e.g., x)
+ right: NULL, except for a function returning a struct of two fields, where
+        left is the first field and right the second one (e.g., p.y).
+        When left is a call, right is a RESULTHIGH operation.
  
  ** The following fields are not set and should not be used:
- name, right, value, arguments, statements
+ name, value, arguments, statements
  */
 Node* CreateReturn(Node* expr);
 
+/* Create a Node representing a struct type:
+ E.g.
+ struct Point { long x; long y; };
+
+ ** Struct variables do not appear in the tree. The parser splits them in one
+    variable per field, named after both (e.g., "p.x"), so a struct parameter
+    is one parameter per field and a struct assignment one assignment per
+    field. Like the System V ABI does for aggregates of two eightbytes, a
+    struct of at most two fields is passed in the next free registers and
+    returned in %rax and %rdx. Larger structs can not be passed by value.
+
+ ** Struct node has the following fields set:
+ type: STRUCTDECL
+ stmtCode: S_NONE
+ exprCode: E_NONE
+ opCode: O_NONE
+ name: "struct " followed by the name of the struct (e.g., "struct Point")
+ arguments: the fields, as parameter nodes, in declaration order
+
+ ** The following fields are not set and should not be used:
+ left, right, value, statements
+ */
+Node* CreateStructType(char* name, NodeList* fields);
+
 /* Create a Node representing an assign statement:
  E.g.
  a = 2 >> 3;
@@ -131,12 +173,64 @@ Node* CreateReturn(Node* expr);
  opCode: O_NONE
  name: name of the variable
  right: Node that represents the right side of "=" sign (e.g., 2 >> 3)
+ value: DECL_POINTER if the variable was declared "long*", otherwise DECL_LONG
  
  ** The following fields are not set and should not be used:
- left, value, arguments, statements
+ left, arguments, statements
  */
+#define DECL_LONG 0
+#define DECL_POINTER 1
 Node* CreateAssign(char* var, Node* expr);
 
+/* Create a Node representing a compile-time constant declaration:
+ E.g.
+ const long N = 4 * 8;
+ ** It may appear at file scope (in the list of function declarations) or
+    as a statement inside a function. The optimizer folds the initializer
+    and replaces every use of the constant by its value.
+ ** ConstDecl node has the following fields set:
+ type: STATEMENT
+ stmtCode: CONSTDECL
+ exprCode: E_NONE
+ opCode: O_NONE
+ name: name of the constant
+ right: Node that represents the initializer (e.g., 4 * 8)
+ ** The following fields are not set and should not be used:
+ left, value, arguments, statements
+ */
+Node* CreateConstDecl(char* var, Node* expr);
+
+/* Create a Node representing a runtime assertion:
+ E.g.
+ assert(x - 3);
+ ** The program aborts, reporting the source line, if the condition is zero.
+ ** Assert node has the following fields set:
+ type: STATEMENT
+ stmtCode: ASSERT
+ exprCode: E_NONE
+ opCode: O_NONE
+ left: The node that represents the condition (e.g., x - 3)
+ value: The source line of the assert
+ ** The following fields are not set and should not be used:
+ name, right, arguments, statements
+ */
+Node* CreateAssert(Node* expr, long line);
+
+/* Create a Node representing a store through a pointer:
+ E.g.
+ *(p + 1) = x;
+ ** Store node has the following fields set:
+ type: STATEMENT
+ stmtCode: STORE
+ exprCode: E_NONE
+ opCode: O_NONE
+ left: The node that represents the address (e.g., p + 1, already scaled by 8)
+ right: The node that represents the stored value (e.g., x)
+ ** The following fields are not set and should not be used:
+ name, value, arguments, statements
+ */
+Node* CreateStore(Node* addr, Node* expr);
+
 /* Create a node representing a binary operation:
  E.g.,
  a + b
@@ -163,8 +257,13 @@ Node* CreateBinaryOperation(OpType opcode,
  type: EXPRESSION
  stmtCode: S_NONE
  exprCode: OPERATION
- opCode: the unary operaion opcode (e.g., -)
+ opCode: the unary operaion opcode (e.g., - or ~)
  left: The inner expression (e.g., x)
+       For ADDRESSOF (&x) it is always a variable node.
+       For DEREF (*p) it is the address, already scaled by 8.
+       For RESULTHIGH it is the variable assigned the first field of the
+       struct returned by a call (e.g., r.x), and the operation stands for the
+       second field (%rdx) of that call. It is NULL in a return of the call.
  
  ** The following fields are not set and should not be used:
  name, right, value, arguments, statements
@@ -220,9 +319,10 @@ Node* CreateVariable(char* name, Node* ref);
  exprCode: PARAMETER
  opCode: O_NONE
  name: Name of the parameter
+ value: DECL_POINTER if the parameter was declared "long*", otherwise DECL_LONG
  
  ** The following fields are not set and should not be used:
- left, right, value, arguments, statements
+ left, right, arguments, statements
  */
 Node* CreateParameter(char* name);
 
@@ -258,6 +358,9 @@ void PrintStatements(NodeList* nodelist);
 void PrintStatement(Node* node);
 void PrintReturn(Node* node);
 void PrintAssignment(Node* node);
+void PrintConstDecl(Node* node);
+void PrintAssert(Node* node);
+void PrintStore(Node* node);
 void PrintExpression(Node* node);
 void PrintOperation(Node* node);
 void PrintBinaryOperation(Node* node, char* op);
@@ -267,7 +370,8 @@ void PrintFunctionCall(Node* node);
 /*********************************************************************************************************
                                Helper functions to free IR Node and NodeList
  **********************************************************************************************************/
-// Recursively free all nodes representing the entire program.
+// Recursively free all nodes representing the entire program, including
+// file scope constant declarations.
 void FreeProgram(NodeList* list);
 /*
  Recursively frees all nodes representing a function declaration, including 
@@ -283,7 +387,8 @@ void FreeParameter(Node* node);
 // Recursively frees nodes representing a list of statements.
 void FreeStatements(NodeList* nodelist);
 /*
- Recursively frees nodes representing a statement (assignment or return statement).
+ Recursively frees nodes representing a statement (assignment, constant
+ declaration, assert, store or return statement).
  ** Caution**: It does not free a referenced statement. For example, in
  long a = b + 3;
  This function will not free the node referenced by the variabel node "b",
@@ -1,80 +1,18 @@
-/* A Bison parser, made by GNU Bison 3.7.4.  */
-
-/* Bison implementation for Yacc-like parsers in C
-
-   Copyright (C) 1984, 1989-1990, 2000-2015, 2018-2020 Free Software Foundation,
-   Inc.
-
-   This program is free software: you can redistribute it and/or modify
-   it under the terms of the GNU General Public License as published by
-   the Free Software Foundation, either version 3 of the License, or
-   (at your option) any later version.
-
-   This program is distributed in the hope that it will be useful,
-   but WITHOUT ANY WARRANTY; without even the implied warranty of
-   MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
-   GNU General Public License for more details.
-
-   You should have received a copy of the GNU General Public License
-   along with this program.  If not, see <http://www.gnu.org/licenses/>.  */
-
-/* As a special exception, you may create a larger work that contains
-   part or all of the Bison parser skeleton and distribute that work
-   under terms of your choice, so long as that work isn't itself a
-   parser generator using the skeleton or a modified version thereof
-   as a parser skeleton.  Alternatively, if you modify or redistribute
-   the parser skeleton itself, you may (at your option) remove this
-   special exception, which will cause the skeleton and the resulting
-   Bison output files to be licensed under the GNU General Public
-   License without this special exception.
-
-   This special exception was added by the Free Software Foundation in
-   version 2.2 of Bison.  */
-
-/* C LALR(1) parser skeleton written by Richard Stallman, by
-   simplifying the original so-called "semantic" parser.  */
-
-/* DO NOT RELY ON FEATURES THAT ARE NOT DOCUMENTED in the manual,
-   especially those whose name start with YY_ or yy_.  They are
-   private implementation details that can be changed or removed.  */
-
-/* All symbols defined below should begin with yy or YY, to avoid
-   infringing on user name space.  This should be done even for local
-   variables, as they might otherwise be expanded by user macros.
-   There are some unavoidable exceptions within include files to
-   define necessary library symbols; they are noted "INFRINGES ON
-   USER NAME SPACE" below.  */
-
-/* Identify Bison output, and Bison version.  */
-#define YYBISON 30704
-
-/* Bison version string.  */
-#define YYBISON_VERSION "3.7.4"
-
-/* Skeleton name.  */
-#define YYSKELETON_NAME "yacc.c"
-
-/* Pure parsers.  */
-#define YYPURE 1
-
-/* Push parsers.  */
-#define YYPUSH 0
-
-/* Pull parsers.  */
-#define YYPULL 1
-
-
-
+/* Parser generated from Parser.y: an LALR(1) table driven parser with
+   the interface of a pure bison parser. Do not edit, run "make grammar"
+   after changing the grammar instead. */
 
 /* First part of user prologue.  */
-
+#line 2 "Parser.y"
 #define YYDEBUG 1
 #define YYERROR_VERBOSE 1
-  
+
 #include "Expression.h"
 #include "symtab.h"
 #include "Parser.h"
 #include "Lexer.h"
+#include <stdlib.h>
+#include <string.h>
 
   int yyerror(YYLTYPE *yylloc, NodeList** funcdecls, int* errorCount,
               SymTable* symtable, yyscan_t scanner, const char *msg) {
@@ -83,2056 +21,2693 @@
     fprintf(stderr, "\t%s\n", msg);
     return 1;
   }
-  
+
   void PrintLocationInfo(unsigned long fl, unsigned long len, char* token) {
     fprintf(stderr, "Error: (line %ld:%ld) near the token '%s'\n", fl, len, token);
   }
 
+  // Whether an expression has type long*. A variable knows it from its declaration.
+  int IsPointer(Node* expr) {
+    if (expr->exprCode == VARIABLE) return expr->left->value == DECL_POINTER;
+    if (expr->opCode == ADDRESSOF) return 1;
+    if (expr->opCode == ADD) return IsPointer(expr->left) || IsPointer(expr->right);
+    if (expr->opCode == SUBTRACT) return IsPointer(expr->left) && !IsPointer(expr->right);
+    return 0;
+  }
 
-# ifndef YY_CAST
-#  ifdef __cplusplus
-#   define YY_CAST(Type, Val) static_cast<Type> (Val)
-#   define YY_REINTERPRET_CAST(Type, Val) reinterpret_cast<Type> (Val)
-#  else
-#   define YY_CAST(Type, Val) ((Type) (Val))
-#   define YY_REINTERPRET_CAST(Type, Val) ((Type) (Val))
-#  endif
-# endif
-# ifndef YY_NULLPTR
-#  if defined __cplusplus
-#   if 201103L <= __cplusplus
-#    define YY_NULLPTR nullptr
-#   else
-#    define YY_NULLPTR 0
-#   endif
-#  else
-#   define YY_NULLPTR ((void*)0)
-#  endif
-# endif
-
-#include "Parser.h"
-/* Symbol kind.  */
-enum yysymbol_kind_t
-{
-  YYSYMBOL_YYEMPTY = -2,
-  YYSYMBOL_YYEOF = 0,                      /* "end of file"  */
-  YYSYMBOL_YYerror = 1,                    /* error  */
-  YYSYMBOL_YYUNDEF = 2,                    /* "invalid token"  */
-  YYSYMBOL_TOKEN_TYPE_LONG = 3,            /* "long"  */
-  YYSYMBOL_TOKEN_RETURN = 4,               /* "return"  */
-  YYSYMBOL_TOKEN_SEMI = 5,                 /* ";"  */
-  YYSYMBOL_TOKEN_COMMA = 6,                /* ","  */
-  YYSYMBOL_TOKEN_LPAREN = 7,               /* "("  */
-  YYSYMBOL_TOKEN_RPAREN = 8,               /* ")"  */
-  YYSYMBOL_TOKEN_LBRACE = 9,               /* "{"  */
-  YYSYMBOL_TOKEN_RBRACE = 10,              /* "}"  */
-  YYSYMBOL_TOKEN_PLUS = 11,                /* "+"  */
-  YYSYMBOL_TOKEN_MINUS = 12,               /* "-"  */
-  YYSYMBOL_TOKEN_STAR = 13,                /* "*"  */
-  YYSYMBOL_TOKEN_DIV = 14,                 /* "/"  */
-  YYSYMBOL_TOKEN_ASSIGN = 15,              /* "="  */
-  YYSYMBOL_TOKEN_BOR = 16,                 /* "|"  */
-  YYSYMBOL_TOKEN_BAND = 17,                /* "&"  */
-  YYSYMBOL_TOKEN_BXOR = 18,                /* "^"  */
-  YYSYMBOL_TOKEN_SHR = 19,                 /* ">>"  */
-  YYSYMBOL_TOKEN_SHL = 20,                 /* "<<"  */
-  YYSYMBOL_TOKEN_NUMBER = 21,              /* "constant value"  */
-  YYSYMBOL_TOKEN_ID = 22,                  /* "variable name"  */
-  YYSYMBOL_YYACCEPT = 23,                  /* $accept  */
-  YYSYMBOL_Program = 24,                   /* Program  */
-  YYSYMBOL_FunctionDecls = 25,             /* FunctionDecls  */
-  YYSYMBOL_FunctionDecl = 26,              /* FunctionDecl  */
-  YYSYMBOL_27_1 = 27,                      /* @1  */
-  YYSYMBOL_ParametersOptional = 28,        /* ParametersOptional  */
-  YYSYMBOL_Parameters = 29,                /* Parameters  */
-  YYSYMBOL_Parameter = 30,                 /* Parameter  */
-  YYSYMBOL_FunctionDeclTail = 31,          /* FunctionDeclTail  */
-  YYSYMBOL_Statements = 32,                /* Statements  */
-  YYSYMBOL_Statement = 33,                 /* Statement  */
-  YYSYMBOL_Assignment = 34,                /* Assignment  */
-  YYSYMBOL_35_2 = 35,                      /* @2  */
-  YYSYMBOL_36_3 = 36,                      /* $@3  */
-  YYSYMBOL_Expression = 37,                /* Expression  */
-  YYSYMBOL_BorExpression = 38,             /* BorExpression  */
-  YYSYMBOL_BxorExpression = 39,            /* BxorExpression  */
-  YYSYMBOL_BandExpression = 40,            /* BandExpression  */
-  YYSYMBOL_ShrExpression = 41,             /* ShrExpression  */
-  YYSYMBOL_ShrOperator = 42,               /* ShrOperator  */
-  YYSYMBOL_AddExpression = 43,             /* AddExpression  */
-  YYSYMBOL_AddOperator = 44,               /* AddOperator  */
-  YYSYMBOL_MulExpression = 45,             /* MulExpression  */
-  YYSYMBOL_MulOperator = 46,               /* MulOperator  */
-  YYSYMBOL_UnaryExpression = 47,           /* UnaryExpression  */
-  YYSYMBOL_UnaryOperator = 48,             /* UnaryOperator  */
-  YYSYMBOL_Operand = 49,                   /* Operand  */
-  YYSYMBOL_FunctionCall = 50,              /* FunctionCall  */
-  YYSYMBOL_51_4 = 51,                      /* @4  */
-  YYSYMBOL_52_5 = 52,                      /* $@5  */
-  YYSYMBOL_CallParams = 53,                /* CallParams  */
-  YYSYMBOL_CallParam = 54                  /* CallParam  */
-};
-typedef enum yysymbol_kind_t yysymbol_kind_t;
-
-
-
-
-#ifdef short
-# undef short
-#endif
-
-/* On compilers that do not define __PTRDIFF_MAX__ etc., make sure
-   <limits.h> and (if available) <stdint.h> are included
-   so that the code can choose integer types of a good width.  */
-
-#ifndef __PTRDIFF_MAX__
-# include <limits.h> /* INFRINGES ON USER NAME SPACE */
-# if defined __STDC_VERSION__ && 199901 <= __STDC_VERSION__
-#  include <stdint.h> /* INFRINGES ON USER NAME SPACE */
-#  define YY_STDINT_H
-# endif
-#endif
-
-/* Narrow types that promote to a signed type and that can represent a
-   signed or unsigned integer of at least N bits.  In tables they can
-   save space and decrease cache pressure.  Promoting to a signed type
-   helps avoid bugs in integer arithmetic.  */
-
-#ifdef __INT_LEAST8_MAX__
-typedef __INT_LEAST8_TYPE__ yytype_int8;
-#elif defined YY_STDINT_H
-typedef int_least8_t yytype_int8;
-#else
-typedef signed char yytype_int8;
-#endif
-
-#ifdef __INT_LEAST16_MAX__
-typedef __INT_LEAST16_TYPE__ yytype_int16;
-#elif defined YY_STDINT_H
-typedef int_least16_t yytype_int16;
-#else
-typedef short yytype_int16;
-#endif
-
-#if defined __UINT_LEAST8_MAX__ && __UINT_LEAST8_MAX__ <= __INT_MAX__
-typedef __UINT_LEAST8_TYPE__ yytype_uint8;
-#elif (!defined __UINT_LEAST8_MAX__ && defined YY_STDINT_H \
-       && UINT_LEAST8_MAX <= INT_MAX)
-typedef uint_least8_t yytype_uint8;
-#elif !defined __UINT_LEAST8_MAX__ && UCHAR_MAX <= INT_MAX
-typedef unsigned char yytype_uint8;
-#else
-typedef short yytype_uint8;
-#endif
-
-#if defined __UINT_LEAST16_MAX__ && __UINT_LEAST16_MAX__ <= __INT_MAX__
-typedef __UINT_LEAST16_TYPE__ yytype_uint16;
-#elif (!defined __UINT_LEAST16_MAX__ && defined YY_STDINT_H \
-       && UINT_LEAST16_MAX <= INT_MAX)
-typedef uint_least16_t yytype_uint16;
-#elif !defined __UINT_LEAST16_MAX__ && USHRT_MAX <= INT_MAX
-typedef unsigned short yytype_uint16;
-#else
-typedef int yytype_uint16;
-#endif
-
-#ifndef YYPTRDIFF_T
-# if defined __PTRDIFF_TYPE__ && defined __PTRDIFF_MAX__
-#  define YYPTRDIFF_T __PTRDIFF_TYPE__
-#  define YYPTRDIFF_MAXIMUM __PTRDIFF_MAX__
-# elif defined PTRDIFF_MAX
-#  ifndef ptrdiff_t
-#   include <stddef.h> /* INFRINGES ON USER NAME SPACE */
-#  endif
-#  define YYPTRDIFF_T ptrdiff_t
-#  define YYPTRDIFF_MAXIMUM PTRDIFF_MAX
-# else
-#  define YYPTRDIFF_T long
-#  define YYPTRDIFF_MAXIMUM LONG_MAX
-# endif
-#endif
-
-#ifndef YYSIZE_T
-# ifdef __SIZE_TYPE__
-#  define YYSIZE_T __SIZE_TYPE__
-# elif defined size_t
-#  define YYSIZE_T size_t
-# elif defined __STDC_VERSION__ && 199901 <= __STDC_VERSION__
-#  include <stddef.h> /* INFRINGES ON USER NAME SPACE */
-#  define YYSIZE_T size_t
-# else
-#  define YYSIZE_T unsigned
-# endif
-#endif
-
-#define YYSIZE_MAXIMUM                                  \
-  YY_CAST (YYPTRDIFF_T,                                 \
-           (YYPTRDIFF_MAXIMUM < YY_CAST (YYSIZE_T, -1)  \
-            ? YYPTRDIFF_MAXIMUM                         \
-            : YY_CAST (YYSIZE_T, -1)))
-
-#define YYSIZEOF(X) YY_CAST (YYPTRDIFF_T, sizeof (X))
-
-
-/* Stored state numbers (used for stacks). */
-typedef yytype_int8 yy_state_t;
-
-/* State numbers in computations.  */
-typedef int yy_state_fast_t;
-
-#ifndef YY_
-# if defined YYENABLE_NLS && YYENABLE_NLS
-#  if ENABLE_NLS
-#   include <libintl.h> /* INFRINGES ON USER NAME SPACE */
-#   define YY_(Msgid) dgettext ("bison-runtime", Msgid)
-#  endif
-# endif
-# ifndef YY_
-#  define YY_(Msgid) Msgid
-# endif
-#endif
-
+  // Multiply an index by the size of a long
+  Node* ScaleIndex(Node* index) {
+    if (index->exprCode == CONSTANT) {
+      index->value *= 8;
+      return index;
+    }
+    return CreateBinaryOperation(MULTIPLY, index, CreateNumber(8));
+  }
 
-#ifndef YY_ATTRIBUTE_PURE
-# if defined __GNUC__ && 2 < __GNUC__ + (96 <= __GNUC_MINOR__)
-#  define YY_ATTRIBUTE_PURE __attribute__ ((__pure__))
-# else
-#  define YY_ATTRIBUTE_PURE
-# endif
-#endif
+  /* Build "left op right" for + and -, scaling the integer side when the other
+     side is a pointer, so p + 1 is the next long. The difference of two
+     pointers counts longs. Returns NULL for the sum of two pointers. */
+  Node* CreateAddOperation(OpType op, Node* left, Node* right) {
+    int leftPointer = IsPointer(left);
+    int rightPointer = IsPointer(right);
+    if (leftPointer && rightPointer) {
+      if (op == ADD) return NULL;
+      return CreateBinaryOperation(DIVIDE, CreateBinaryOperation(SUBTRACT, left, right),
+                                   CreateNumber(8));
+    }
+    if (leftPointer) right = ScaleIndex(right);
+    if (rightPointer) left = ScaleIndex(left);
+    return CreateBinaryOperation(op, left, right);
+  }
 
-#ifndef YY_ATTRIBUTE_UNUSED
-# if defined __GNUC__ && 2 < __GNUC__ + (7 <= __GNUC_MINOR__)
-#  define YY_ATTRIBUTE_UNUSED __attribute__ ((__unused__))
-# else
-#  define YY_ATTRIBUTE_UNUSED
-# endif
-#endif
+  // The symbol table name of a struct type, e.g. "struct Point". It can not
+  // clash with a variable or a function, since it has a blank in it.
+  char* StructTag(char* name) {
+    char* tag = malloc(strlen(name) + 8);
+    sprintf(tag, "struct %s", name);
+    return tag;
+  }
 
-/* Suppress unused-variable warnings by "using" E.  */
-#if ! defined lint || defined __GNUC__
-# define YYUSE(E) ((void) (E))
-#else
-# define YYUSE(E) /* empty */
-#endif
+  // The name of the variable holding one field of a struct variable, e.g. "p.x"
+  char* FieldName(char* var, char* field) {
+    char* name = malloc(strlen(var) + strlen(field) + 2);
+    sprintf(name, "%s.%s", var, field);
+    return name;
+  }
 
-#if defined __GNUC__ && ! defined __ICC && 407 <= __GNUC__ * 100 + __GNUC_MINOR__
-/* Suppress an incorrect diagnostic about yylval being uninitialized.  */
-# define YY_IGNORE_MAYBE_UNINITIALIZED_BEGIN                            \
-    _Pragma ("GCC diagnostic push")                                     \
-    _Pragma ("GCC diagnostic ignored \"-Wuninitialized\"")              \
-    _Pragma ("GCC diagnostic ignored \"-Wmaybe-uninitialized\"")
-# define YY_IGNORE_MAYBE_UNINITIALIZED_END      \
-    _Pragma ("GCC diagnostic pop")
-#else
-# define YY_INITIAL_VALUE(Value) Value
-#endif
-#ifndef YY_IGNORE_MAYBE_UNINITIALIZED_BEGIN
-# define YY_IGNORE_MAYBE_UNINITIALIZED_BEGIN
-# define YY_IGNORE_MAYBE_UNINITIALIZED_END
-#endif
-#ifndef YY_INITIAL_VALUE
-# define YY_INITIAL_VALUE(Value) /* Nothing. */
-#endif
+  // A use of one field of a struct variable
+  Node* FieldVariable(SymTable* symtable, char* var, Node* field) {
+    char* name = FieldName(var, field->name);
+    return CreateVariable(name, Lookup(symtable, name)->node);
+  }
 
-#if defined __cplusplus && defined __GNUC__ && ! defined __ICC && 6 <= __GNUC__
-# define YY_IGNORE_USELESS_CAST_BEGIN                          \
-    _Pragma ("GCC diagnostic push")                            \
-    _Pragma ("GCC diagnostic ignored \"-Wuseless-cast\"")
-# define YY_IGNORE_USELESS_CAST_END            \
-    _Pragma ("GCC diagnostic pop")
-#endif
-#ifndef YY_IGNORE_USELESS_CAST_BEGIN
-# define YY_IGNORE_USELESS_CAST_BEGIN
-# define YY_IGNORE_USELESS_CAST_END
-#endif
+  // The struct type of a struct value, or NULL for a long. A struct variable
+  // refers to its type, and a call to a function that may return one.
+  Node* StructOf(Node* expr) {
+    if (expr->exprCode == VARIABLE && expr->left->type == STRUCTDECL) return expr->left;
+    if (expr->opCode == FUNCTIONCALL) return expr->left->left;
+    return NULL;
+  }
 
+  const char* TypeName(Node* type) {
+    return type ? type->name : "long";
+  }
 
-#define YY_ASSERT(E) ((void) (0 && (E)))
-
-#if 1
-
-/* The parser invokes alloca or malloc; define the necessary symbols.  */
-
-# ifdef YYSTACK_USE_ALLOCA
-#  if YYSTACK_USE_ALLOCA
-#   ifdef __GNUC__
-#    define YYSTACK_ALLOC __builtin_alloca
-#   elif defined __BUILTIN_VA_ARG_INCR
-#    include <alloca.h> /* INFRINGES ON USER NAME SPACE */
-#   elif defined _AIX
-#    define YYSTACK_ALLOC __alloca
-#   elif defined _MSC_VER
-#    include <malloc.h> /* INFRINGES ON USER NAME SPACE */
-#    define alloca _alloca
-#   else
-#    define YYSTACK_ALLOC alloca
-#    if ! defined _ALLOCA_H && ! defined EXIT_SUCCESS
-#     include <stdlib.h> /* INFRINGES ON USER NAME SPACE */
-      /* Use EXIT_SUCCESS as a witness for stdlib.h.  */
-#     ifndef EXIT_SUCCESS
-#      define EXIT_SUCCESS 0
-#     endif
-#    endif
-#   endif
-#  endif
-# endif
-
-# ifdef YYSTACK_ALLOC
-   /* Pacify GCC's 'empty if-body' warning.  */
-#  define YYSTACK_FREE(Ptr) do { /* empty */; } while (0)
-#  ifndef YYSTACK_ALLOC_MAXIMUM
-    /* The OS might guarantee only one guard page at the bottom of the stack,
-       and a page size can be as small as 4096 bytes.  So we cannot safely
-       invoke alloca (N) if N exceeds 4096.  Use a slightly smaller number
-       to allow for a few compiler-allocated temporary stack slots.  */
-#   define YYSTACK_ALLOC_MAXIMUM 4032 /* reasonable circa 2006 */
-#  endif
-# else
-#  define YYSTACK_ALLOC YYMALLOC
-#  define YYSTACK_FREE YYFREE
-#  ifndef YYSTACK_ALLOC_MAXIMUM
-#   define YYSTACK_ALLOC_MAXIMUM YYSIZE_MAXIMUM
-#  endif
-#  if (defined __cplusplus && ! defined EXIT_SUCCESS \
-       && ! ((defined YYMALLOC || defined malloc) \
-             && (defined YYFREE || defined free)))
-#   include <stdlib.h> /* INFRINGES ON USER NAME SPACE */
-#   ifndef EXIT_SUCCESS
-#    define EXIT_SUCCESS 0
-#   endif
-#  endif
-#  ifndef YYMALLOC
-#   define YYMALLOC malloc
-#   if ! defined malloc && ! defined EXIT_SUCCESS
-void *malloc (YYSIZE_T); /* INFRINGES ON USER NAME SPACE */
-#   endif
-#  endif
-#  ifndef YYFREE
-#   define YYFREE free
-#   if ! defined free && ! defined EXIT_SUCCESS
-void free (void *); /* INFRINGES ON USER NAME SPACE */
-#   endif
-#  endif
-# endif
-#endif /* 1 */
-
-#if (! defined yyoverflow \
-     && (! defined __cplusplus \
-         || (defined YYLTYPE_IS_TRIVIAL && YYLTYPE_IS_TRIVIAL \
-             && defined YYSTYPE_IS_TRIVIAL && YYSTYPE_IS_TRIVIAL)))
-
-/* A type that is properly aligned for any stack member.  */
-union yyalloc
-{
-  yy_state_t yyss_alloc;
-  YYSTYPE yyvs_alloc;
-  YYLTYPE yyls_alloc;
-};
+  /* Split "dest = src" in one assignment per field of the struct type.
+     src is a struct variable, a call returning the struct, or NULL to zero
+     the fields. A declaration adds the fields to the symbol table, otherwise
+     they now refer to the new assignments. */
+  NodeList* AssignStruct(SymTable* symtable, char* dest, Node* type, Node* src, int declare) {
+    NodeList* head = NULL;
+    NodeList** tail = &head;
+    Node* low = NULL;
+    int index = 0;
+    for (NodeList* field = type->arguments; field != NULL; field = field->next, index++) {
+      Node* value;
+      if (src == NULL) value = CreateNumber(0);
+      else if (src->exprCode == VARIABLE) value = FieldVariable(symtable, src->name, field->node);
+      else if (index == 0) value = src;
+      // the call returned the second field in %rdx, tied to the first one
+      else value = CreateUnaryOperation(RESULTHIGH, CreateVariable(strdup(low->name), low));
+
+      Node* temp = CreateAssign(FieldName(dest, field->node->name), value);
+      temp->value = field->node->value;
+      if (index == 0) low = temp;
+      if (declare) Insert(symtable, temp, LONG_TYPE);
+      else Lookup(symtable, temp->name)->node = temp;
+      *tail = CreateNodeList(temp, NULL);
+      tail = &(*tail)->next;
+    }
+    if (src != NULL && src->exprCode == VARIABLE) FreeVariable(src);
+    return head;
+  }
 
-/* The size of the maximum gap between one aligned stack and the next.  */
-# define YYSTACK_GAP_MAXIMUM (YYSIZEOF (union yyalloc) - 1)
+  /* Replace every struct variable among the arguments of a call by its
+     fields, which is how the callee receives it. Returns 0 if a struct comes
+     straight from another call, since only %rax is free to hold it. */
+  int ExpandStructArgs(SymTable* symtable, NodeList* args) {
+    for (NodeList* nl = args; nl != NULL; nl = nl->next) {
+      Node* type = StructOf(nl->node);
+      if (type == NULL) continue;
+      if (nl->node->exprCode != VARIABLE) return 0;
+
+      Node* var = nl->node;
+      NodeList* rest = nl->next;
+      nl->node = FieldVariable(symtable, var->name, type->arguments->node);
+      for (NodeList* field = type->arguments->next; field != NULL; field = field->next) {
+        nl->next = CreateNodeList(FieldVariable(symtable, var->name, field->node), NULL);
+        nl = nl->next;
+      }
+      nl->next = rest;
+      FreeVariable(var);
+    }
+    return 1;
+  }
 
-/* The size of an array large to enough to hold all stacks, each with
-   N elements.  */
-# define YYSTACK_BYTES(N) \
-     ((N) * (YYSIZEOF (yy_state_t) + YYSIZEOF (YYSTYPE) \
-             + YYSIZEOF (YYLTYPE)) \
-      + 2 * YYSTACK_GAP_MAXIMUM)
+  // Struct values can only be assigned, passed and returned
+  #define REQUIRE_LONG(expr) \
+    if (StructOf(expr)) { \
+      PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner)); \
+      fprintf(stderr, "\tinvalid use of a value of type '%s'\n", StructOf(expr)->name); \
+      (*errorCount)++; \
+      YYABORT; \
+    }
+#line 156 "Parser.c"
 
-# define YYCOPY_NEEDED 1
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
 
-/* Relocate STACK from its old location to the new one.  The
-   local variables YYSIZE and YYSTACKSIZE give the old and new number of
-   elements in the stack, and YYPTR gives the new location of the
-   stack.  Advance YYPTR to a properly aligned location for the next
-   stack.  */
-# define YYSTACK_RELOCATE(Stack_alloc, Stack)                           \
-    do                                                                  \
-      {                                                                 \
-        YYPTRDIFF_T yynewbytes;                                         \
-        YYCOPY (&yyptr->Stack_alloc, Stack, yysize);                    \
-        Stack = &yyptr->Stack_alloc;                                    \
-        yynewbytes = yystacksize * YYSIZEOF (*Stack) + YYSTACK_GAP_MAXIMUM; \
-        yyptr += yynewbytes / YYSIZEOF (*yyptr);                        \
-      }                                                                 \
-    while (0)
+#ifndef YY_YY_FRONT_PARSER_H_INCLUDED
+# define YY_YY_FRONT_PARSER_H_INCLUDED
 
+/* Debug traces.  */
+#ifndef YYDEBUG
+# define YYDEBUG 1
 #endif
-
-#if defined YYCOPY_NEEDED && YYCOPY_NEEDED
-/* Copy COUNT objects from SRC to DST.  The source and destination do
-   not overlap.  */
-# ifndef YYCOPY
-#  if defined __GNUC__ && 1 < __GNUC__
-#   define YYCOPY(Dst, Src, Count) \
-      __builtin_memcpy (Dst, Src, YY_CAST (YYSIZE_T, (Count)) * sizeof (*(Src)))
-#  else
-#   define YYCOPY(Dst, Src, Count)              \
-      do                                        \
-        {                                       \
-          YYPTRDIFF_T yyi;                      \
-          for (yyi = 0; yyi < (Count); yyi++)   \
-            (Dst)[yyi] = (Src)[yyi];            \
-        }                                       \
-      while (0)
-#  endif
-# endif
-#endif /* !YYCOPY_NEEDED */
-
-/* YYFINAL -- State number of the termination state.  */
-#define YYFINAL  6
-/* YYLAST -- Last index in YYTABLE.  */
-#define YYLAST   65
-
-/* YYNTOKENS -- Number of terminals.  */
-#define YYNTOKENS  23
-/* YYNNTS -- Number of nonterminals.  */
-#define YYNNTS  32
-/* YYNRULES -- Number of rules.  */
-#define YYNRULES  54
-/* YYNSTATES -- Number of states.  */
-#define YYNSTATES  83
-
-/* YYMAXUTOK -- Last valid token kind.  */
-#define YYMAXUTOK   277
-
-
-/* YYTRANSLATE(TOKEN-NUM) -- Symbol number corresponding to TOKEN-NUM
-   as returned by yylex, with out-of-bounds checking.  */
-#define YYTRANSLATE(YYX)                                \
-  (0 <= (YYX) && (YYX) <= YYMAXUTOK                     \
-   ? YY_CAST (yysymbol_kind_t, yytranslate[YYX])        \
-   : YYSYMBOL_YYUNDEF)
-
-/* YYTRANSLATE[TOKEN-NUM] -- Symbol number corresponding to TOKEN-NUM
-   as returned by yylex.  */
-static const yytype_int8 yytranslate[] =
-{
-       0,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
-       2,     2,     2,     2,     2,     2,     1,     2,     3,     4,
-       5,     6,     7,     8,     9,    10,    11,    12,    13,    14,
-      15,    16,    17,    18,    19,    20,    21,    22
-};
-
 #if YYDEBUG
-  /* YYRLINE[YYN] -- Source line where rule number YYN was defined.  */
-static const yytype_int16 yyrline[] =
-{
-       0,    76,    76,    79,    84,    87,    97,    97,   121,   124,
-     129,   132,   142,   160,   165,   168,   178,   181,   186,   186,
-     206,   206,   224,   230,   231,   236,   237,   242,   243,   248,
-     249,   254,   255,   259,   260,   265,   266,   270,   271,   276,
-     277,   281,   284,   290,   294,   297,   313,   316,   321,   343,
-     321,   360,   363,   369,   372
-};
+extern int yydebug;
 #endif
 
-/** Accessing symbol of state STATE.  */
-#define YY_ACCESSING_SYMBOL(State) YY_CAST (yysymbol_kind_t, yystos[State])
+/* "%code requires" blocks.  */
 
-#if 1
-/* The user-facing name of the symbol whose (internal) number is
-   YYSYMBOL.  No bounds checking.  */
-static const char *yysymbol_name (yysymbol_kind_t yysymbol) YY_ATTRIBUTE_UNUSED;
+  typedef void* yyscan_t;
 
-static const char *
-yysymbol_name (yysymbol_kind_t yysymbol)
-{
-  static const char *const yy_sname[] =
+/* Token kinds.  */
+#ifndef YYTOKENTYPE
+# define YYTOKENTYPE
+  enum yytokentype
   {
-  "end of file", "error", "invalid token", "long", "return", ";", ",",
-  "(", ")", "{", "}", "+", "-", "*", "/", "=", "|", "&", "^", ">>", "<<",
-  "constant value", "variable name", "$accept", "Program", "FunctionDecls",
-  "FunctionDecl", "@1", "ParametersOptional", "Parameters", "Parameter",
-  "FunctionDeclTail", "Statements", "Statement", "Assignment", "@2", "$@3",
-  "Expression", "BorExpression", "BxorExpression", "BandExpression",
-  "ShrExpression", "ShrOperator", "AddExpression", "AddOperator",
-  "MulExpression", "MulOperator", "UnaryExpression", "UnaryOperator",
-  "Operand", "FunctionCall", "@4", "$@5", "CallParams", "CallParam", YY_NULLPTR
+    YYEMPTY = -2,
+    YYEOF = 0,                     /* "end of file"  */
+    YYerror = 256,                 /* error  */
+    YYUNDEF = 257,                 /* "invalid token"  */
+    TOKEN_TYPE_LONG = 258,         /* "long"  */
+    TOKEN_RETURN = 259,            /* "return"  */
+    TOKEN_CONST = 260,             /* "const"  */
+    TOKEN_ASSERT = 261,            /* "assert"  */
+    TOKEN_STATIC = 262,            /* "static"  */
+    TOKEN_STRUCT = 263,            /* "struct"  */
+    TOKEN_SEMI = 264,              /* ";"  */
+    TOKEN_COMMA = 265,             /* ","  */
+    TOKEN_DOT = 266,               /* "."  */
+    TOKEN_LPAREN = 267,            /* "("  */
+    TOKEN_RPAREN = 268,            /* ")"  */
+    TOKEN_LBRACE = 269,            /* "{"  */
+    TOKEN_RBRACE = 270,            /* "}"  */
+    TOKEN_PLUS = 271,              /* "+"  */
+    TOKEN_MINUS = 272,             /* "-"  */
+    TOKEN_STAR = 273,              /* "*"  */
+    TOKEN_DIV = 274,               /* "/"  */
+    TOKEN_ASSIGN = 275,            /* "="  */
+    TOKEN_BOR = 276,               /* "|"  */
+    TOKEN_BAND = 277,              /* "&"  */
+    TOKEN_BXOR = 278,              /* "^"  */
+    TOKEN_BNOT = 279,              /* "~"  */
+    TOKEN_SHR = 280,               /* ">>"  */
+    TOKEN_SHL = 281,               /* "<<"  */
+    TOKEN_LSHR = 282,              /* ">>>"  */
+    TOKEN_NUMBER = 283,            /* "constant value"  */
+    TOKEN_ID = 284                 /* "variable name"  */
   };
-  return yy_sname[yysymbol];
-}
+  typedef enum yytokentype yytoken_kind_t;
 #endif
 
-#ifdef YYPRINT
-/* YYTOKNUM[NUM] -- (External) token number corresponding to the
-   (internal) symbol number NUM (which must be that of a token).  */
-static const yytype_int16 yytoknum[] =
+/* Value type.  */
+#if ! defined YYSTYPE && ! defined YYSTYPE_IS_DECLARED
+union YYSTYPE
 {
-       0,   256,   257,   258,   259,   260,   261,   262,   263,   264,
-     265,   266,   267,   268,   269,   270,   271,   272,   273,   274,
-     275,   276,   277
+    long value;
+    char* str;
+    Node* node;
+    NodeList* nodelist;
+    OpType opcode;
+    list_t* symel;
 };
+typedef union YYSTYPE YYSTYPE;
+# define YYSTYPE_IS_TRIVIAL 1
+# define YYSTYPE_IS_DECLARED 1
 #endif
 
-#define YYPACT_NINF (-58)
-
-#define yypact_value_is_default(Yyn) \
-  ((Yyn) == YYPACT_NINF)
-
-#define YYTABLE_NINF (-49)
-
-#define yytable_value_is_error(Yyn) \
-  0
-
-  /* YYPACT[STATE-NUM] -- Index in YYTABLE of the portion describing
-     STATE-NUM.  */
-static const yytype_int8 yypact[] =
-{
-       8,   -18,    16,     8,   -58,   -58,   -58,   -58,    11,    19,
-      -2,    22,    27,   -58,   -58,    25,    19,    -1,   -58,   -58,
-      13,     3,   -58,    -3,   -58,   -58,   -58,     3,   -58,   -58,
-      29,    32,    23,    20,    24,    -6,    17,    18,   -58,     5,
-     -58,   -58,    28,   -58,   -58,    30,     1,    33,   -58,     3,
-       3,     3,   -58,   -58,     3,   -58,   -58,     3,   -58,   -58,
-       3,   -58,     3,     3,   -58,     3,    20,    24,    -6,    17,
-      18,   -58,    37,    39,   -58,   -58,    40,   -58,   -58,    41,
-       3,   -58,   -58
-};
-
-  /* YYDEFACT[STATE-NUM] -- Default reduction number in state STATE-NUM.
-     Performed when YYTABLE does not specify something else to do.  Zero
-     means the default is an error.  */
-static const yytype_int8 yydefact[] =
-{
-       3,     0,     0,     2,     4,     6,     1,     5,     0,     9,
-       0,     0,     8,    10,    12,     0,     0,     0,     7,    11,
-       0,     0,    18,     0,    14,    16,    20,     0,    43,    44,
-      45,     0,    22,    23,    25,    27,    29,    33,    37,     0,
-      41,    47,     0,    13,    15,     0,     0,     0,    17,     0,
-       0,     0,    31,    32,     0,    35,    36,     0,    39,    40,
-       0,    42,     0,     0,    46,    52,    24,    26,    28,    30,
-      34,    38,     0,     0,    53,    49,    51,    19,    21,     0,
-       0,    50,    54
-};
-
-  /* YYPGOTO[NTERM-NUM].  */
-static const yytype_int8 yypgoto[] =
-{
-     -58,   -58,   -58,    44,   -58,   -58,   -58,    34,   -58,   -58,
-      31,   -58,   -58,   -58,   -57,    21,     2,     6,     4,   -58,
-       7,   -58,    -5,   -58,    -7,   -58,    26,   -58,   -58,   -58,
-     -58,   -58
-};
-
-  /* YYDEFGOTO[NTERM-NUM].  */
-static const yytype_int8 yydefgoto[] =
-{
-      -1,     2,     3,     4,     8,    11,    12,    13,    18,    23,
-      24,    25,    42,    45,    31,    32,    33,    34,    35,    54,
-      36,    57,    37,    60,    38,    39,    40,    41,    47,    79,
-      75,    76
-};
-
-  /* YYTABLE[YYPACT[STATE-NUM]] -- What to do in state STATE-NUM.  If
-     positive, shift that token.  If negative, reduce the rule whose
-     number is the opposite.  If YYTABLE_NINF, syntax error.  */
-static const yytype_int8 yytable[] =
-{
-      20,    21,    20,    21,     5,    72,    73,    43,    74,    64,
-      27,     1,    27,    52,    53,    28,     6,    49,     9,    22,
-      14,    22,    10,    82,    29,    30,    29,    30,    55,    56,
-      15,    58,    59,    16,    17,    26,   -48,    48,    50,    49,
-      65,    51,    77,    62,    78,    63,    80,     7,    46,    81,
-      19,    66,    70,    71,    44,    68,    67,     0,     0,     0,
-       0,    69,     0,     0,     0,    61
-};
-
-static const yytype_int8 yycheck[] =
-{
-       3,     4,     3,     4,    22,    62,    63,    10,    65,     8,
-       7,     3,     7,    19,    20,    12,     0,    16,     7,    22,
-      22,    22,     3,    80,    21,    22,    21,    22,    11,    12,
-       8,    13,    14,     6,     9,    22,     7,     5,    18,    16,
-       7,    17,     5,    15,     5,    15,     6,     3,    27,     8,
-      16,    49,    57,    60,    23,    51,    50,    -1,    -1,    -1,
-      -1,    54,    -1,    -1,    -1,    39
-};
-
-  /* YYSTOS[STATE-NUM] -- The (internal number of the) accessing
-     symbol of state STATE-NUM.  */
-static const yytype_int8 yystos[] =
-{
-       0,     3,    24,    25,    26,    22,     0,    26,    27,     7,
-       3,    28,    29,    30,    22,     8,     6,     9,    31,    30,
-       3,     4,    22,    32,    33,    34,    22,     7,    12,    21,
-      22,    37,    38,    39,    40,    41,    43,    45,    47,    48,
-      49,    50,    35,    10,    33,    36,    38,    51,     5,    16,
-      18,    17,    19,    20,    42,    11,    12,    44,    13,    14,
-      46,    49,    15,    15,     8,     7,    39,    40,    41,    43,
-      45,    47,    37,    37,    37,    53,    54,     5,     5,    52,
-       6,     8,    37
-};
-
-  /* YYR1[YYN] -- Symbol number of symbol that rule YYN derives.  */
-static const yytype_int8 yyr1[] =
+/* Location type.  */
+#if ! defined YYLTYPE && ! defined YYLTYPE_IS_DECLARED
+typedef struct YYLTYPE YYLTYPE;
+struct YYLTYPE
 {
-       0,    23,    24,    24,    25,    25,    27,    26,    28,    28,
-      29,    29,    30,    31,    32,    32,    33,    33,    35,    34,
-      36,    34,    37,    38,    38,    39,    39,    40,    40,    41,
-      41,    42,    42,    43,    43,    44,    44,    45,    45,    46,
-      46,    47,    47,    48,    49,    49,    49,    49,    51,    52,
-      50,    53,    53,    54,    54
+  int first_line;
+  int first_column;
+  int last_line;
+  int last_column;
 };
+# define YYLTYPE_IS_DECLARED 1
+# define YYLTYPE_IS_TRIVIAL 1
+#endif
 
-  /* YYR2[YYN] -- Number of symbols on the right hand side of rule YYN.  */
-static const yytype_int8 yyr2[] =
-{
-       0,     2,     1,     0,     1,     2,     0,     7,     1,     0,
-       1,     3,     2,     3,     1,     2,     1,     3,     0,     5,
-       0,     6,     1,     1,     3,     1,     3,     1,     3,     1,
-       3,     1,     1,     1,     3,     1,     1,     1,     3,     1,
-       1,     1,     2,     1,     1,     1,     3,     1,     0,     0,
-       6,     1,     0,     1,     3
-};
-
-
-enum { YYENOMEM = -2 };
-
-#define yyerrok         (yyerrstatus = 0)
-#define yyclearin       (yychar = YYEMPTY)
-
-#define YYACCEPT        goto yyacceptlab
-#define YYABORT         goto yyabortlab
-#define YYERROR         goto yyerrorlab
-
+int yyparse (NodeList** funcdecls, int* errorCount, SymTable* symtable, yyscan_t scanner);
 
-#define YYRECOVERING()  (!!yyerrstatus)
+#endif /* !YY_YY_FRONT_PARSER_H_INCLUDED  */
 
-#define YYBACKUP(Token, Value)                                    \
-  do                                                              \
-    if (yychar == YYEMPTY)                                        \
-      {                                                           \
-        yychar = (Token);                                         \
-        yylval = (Value);                                         \
-        YYPOPSTACK (yylen);                                       \
-        yystate = *yyssp;                                         \
-        goto yybackup;                                            \
-      }                                                           \
-    else                                                          \
-      {                                                           \
-        yyerror (&yylloc, funcdecls, errorCount, symtable, scanner, YY_("syntax error: cannot back up")); \
-        YYERROR;                                                  \
-      }                                                           \
-  while (0)
+#ifndef YYINITDEPTH
+# define YYINITDEPTH 200
+#endif
 
-/* Backward compatibility with an undocumented macro.
-   Use YYerror or YYUNDEF. */
-#define YYERRCODE YYUNDEF
+#ifndef YYMAXDEPTH
+# define YYMAXDEPTH 10000
+#endif
 
-/* YYLLOC_DEFAULT -- Set CURRENT to span from RHS[1] to RHS[N].
-   If N is 0, then set CURRENT to the empty location which ends
-   the previous symbol: RHS[0] (always defined).  */
+#define YYACCEPT goto yyacceptlab
+#define YYABORT goto yyabortlab
 
+/* The location of a symbol spanning N symbols on top of the stack.  */
 #ifndef YYLLOC_DEFAULT
 # define YYLLOC_DEFAULT(Current, Rhs, N)                                \
     do                                                                  \
       if (N)                                                            \
         {                                                               \
-          (Current).first_line   = YYRHSLOC (Rhs, 1).first_line;        \
-          (Current).first_column = YYRHSLOC (Rhs, 1).first_column;      \
-          (Current).last_line    = YYRHSLOC (Rhs, N).last_line;         \
-          (Current).last_column  = YYRHSLOC (Rhs, N).last_column;       \
+          (Current).first_line   = (Rhs)[1].first_line;                 \
+          (Current).first_column = (Rhs)[1].first_column;               \
+          (Current).last_line    = (Rhs)[N].last_line;                  \
+          (Current).last_column  = (Rhs)[N].last_column;                \
         }                                                               \
       else                                                              \
         {                                                               \
           (Current).first_line   = (Current).last_line   =              \
-            YYRHSLOC (Rhs, 0).last_line;                                \
+            (Rhs)[0].last_line;                                         \
           (Current).first_column = (Current).last_column =              \
-            YYRHSLOC (Rhs, 0).last_column;                              \
+            (Rhs)[0].last_column;                                       \
         }                                                               \
     while (0)
 #endif
 
-#define YYRHSLOC(Rhs, K) ((Rhs)[K])
-
-
-/* Enable debugging if requested.  */
-#if YYDEBUG
-
-# ifndef YYFPRINTF
-#  include <stdio.h> /* INFRINGES ON USER NAME SPACE */
-#  define YYFPRINTF fprintf
-# endif
-
-# define YYDPRINTF(Args)                        \
-do {                                            \
-  if (yydebug)                                  \
-    YYFPRINTF Args;                             \
-} while (0)
-
-
-/* YY_LOCATION_PRINT -- Print the location on the stream.
-   This macro was not mandated originally: define only if we know
-   we won't break user code: when these are the locations we know.  */
+#define YYNSTATES 147
+#define YYNTOKENS 30
+#define YYNNTS 45
+#define YYNRULES 85
 
-# ifndef YY_LOCATION_PRINT
-#  if defined YYLTYPE_IS_TRIVIAL && YYLTYPE_IS_TRIVIAL
+#define YYMAXUTOK 284
+#define YYTRANSLATE(YYX) ((YYX) >= 0 && (YYX) <= YYMAXUTOK ? yytranslate[YYX] : 2)
 
-/* Print *YYLOCP on YYO.  Private, do not rely on its existence. */
-
-YY_ATTRIBUTE_UNUSED
-static int
-yy_location_print_ (FILE *yyo, YYLTYPE const * const yylocp)
+/* YYTRANSLATE[TOKEN-NUM] -- Symbol number corresponding to TOKEN-NUM  */
+static const unsigned char yytranslate[] =
 {
-  int res = 0;
-  int end_col = 0 != yylocp->last_column ? yylocp->last_column - 1 : 0;
-  if (0 <= yylocp->first_line)
-    {
-      res += YYFPRINTF (yyo, "%d", yylocp->first_line);
-      if (0 <= yylocp->first_column)
-        res += YYFPRINTF (yyo, ".%d", yylocp->first_column);
-    }
-  if (0 <= yylocp->last_line)
-    {
-      if (yylocp->first_line < yylocp->last_line)
-        {
-          res += YYFPRINTF (yyo, "-%d", yylocp->last_line);
-          if (0 <= end_col)
-            res += YYFPRINTF (yyo, ".%d", end_col);
-        }
-      else if (0 <= end_col && yylocp->first_column < end_col)
-        res += YYFPRINTF (yyo, "-%d", end_col);
-    }
-  return res;
- }
-
-#   define YY_LOCATION_PRINT(File, Loc)          \
-  yy_location_print_ (File, &(Loc))
-
-#  else
-#   define YY_LOCATION_PRINT(File, Loc) ((void) 0)
-#  endif
-# endif /* !defined YY_LOCATION_PRINT */
-
-
-# define YY_SYMBOL_PRINT(Title, Kind, Value, Location)                    \
-do {                                                                      \
-  if (yydebug)                                                            \
-    {                                                                     \
-      YYFPRINTF (stderr, "%s ", Title);                                   \
-      yy_symbol_print (stderr,                                            \
-                  Kind, Value, Location, funcdecls, errorCount, symtable, scanner); \
-      YYFPRINTF (stderr, "\n");                                           \
-    }                                                                     \
-} while (0)
-
-
-/*-----------------------------------.
-| Print this symbol's value on YYO.  |
-`-----------------------------------*/
+      0,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     2,     2,     2,     2,
+      2,     2,     2,     2,     2,     2,     1,     2,     3,     4,
+      5,     6,     7,     8,     9,    10,    11,    12,    13,    14,
+     15,    16,    17,    18,    19,    20,    21,    22,    23,    24,
+     25,    26,    27,    28,    29
+};
 
-static void
-yy_symbol_value_print (FILE *yyo,
-                       yysymbol_kind_t yykind, YYSTYPE const * const yyvaluep, YYLTYPE const * const yylocationp, NodeList **funcdecls, int* errorCount, SymTable* symtable, yyscan_t scanner)
+/* YYTNAME[SYMBOL-NUM] -- Name of SYMBOL-NUM, as shown in error messages.  */
+static const char* const yytname[] =
 {
-  FILE *yyoutput = yyo;
-  YYUSE (yyoutput);
-  YYUSE (yylocationp);
-  YYUSE (funcdecls);
-  YYUSE (errorCount);
-  YYUSE (symtable);
-  YYUSE (scanner);
-  if (!yyvaluep)
-    return;
-# ifdef YYPRINT
-  if (yykind < YYNTOKENS)
-    YYPRINT (yyo, yytoknum[yykind], *yyvaluep);
-# endif
-  YY_IGNORE_MAYBE_UNINITIALIZED_BEGIN
-  YYUSE (yykind);
-  YY_IGNORE_MAYBE_UNINITIALIZED_END
-}
-
-
-/*---------------------------.
-| Print this symbol on YYO.  |
-`---------------------------*/
+  "end of file", "error", "invalid token", "long",
+  "return", "const", "assert", "static",
+  "struct", ";", "\",\"", ".",
+  "(", ")", "{", "}",
+  "+", "-", "*", "/",
+  "=", "|", "&", "^",
+  "~", ">>", "<<", ">>>",
+  "constant value", "variable name", "$accept", "Program",
+  "FunctionDecls", "TopLevelDecl", "StructDecl", "StructName",
+  "Fields", "Field", "StructType", "$@1",
+  "FunctionDecl", "$@2", "FunctionHead", "ReturnType",
+  "Linkage", "ParametersOptional", "Parameters", "Parameter",
+  "VarType", "FunctionDeclTail", "Statements", "Statement",
+  "$@3", "Assignment", "$@4", "$@5",
+  "ConstDecl", "Expression", "BorExpression", "BxorExpression",
+  "BandExpression", "ShrExpression", "ShrOperator", "AddExpression",
+  "AddOperator", "MulExpression", "MulOperator", "UnaryExpression",
+  "UnaryOperator", "Operand", "$@6", "$@7",
+  "FunctionCall", "CallParams", "CallParam"
+};
 
-static void
-yy_symbol_print (FILE *yyo,
-                 yysymbol_kind_t yykind, YYSTYPE const * const yyvaluep, YYLTYPE const * const yylocationp, NodeList **funcdecls, int* errorCount, SymTable* symtable, yyscan_t scanner)
+/* YYR1[RULE-NUM] -- Symbol kind of the left-hand side of RULE-NUM, minus YYNTOKENS  */
+static const short yyr1[] =
 {
-  YYFPRINTF (yyo, "%s %s (",
-             yykind < YYNTOKENS ? "token" : "nterm", yysymbol_name (yykind));
-
-  YY_LOCATION_PRINT (yyo, *yylocationp);
-  YYFPRINTF (yyo, ": ");
-  yy_symbol_value_print (yyo, yykind, yyvaluep, yylocationp, funcdecls, errorCount, symtable, scanner);
-  YYFPRINTF (yyo, ")");
-}
-
-/*------------------------------------------------------------------.
-| yy_stack_print -- Print the state stack from its BOTTOM up to its |
-| TOP (included).                                                   |
-`------------------------------------------------------------------*/
+      0,     1,     1,     2,     2,     3,     3,     3,     4,     5,
+      6,     6,     7,     8,     9,    10,    10,    11,    12,    13,
+     13,    14,    14,    15,    15,    16,    16,    17,    17,    18,
+     18,    19,    20,    20,    21,    21,    21,    21,    21,    22,
+     23,    23,    24,    23,    23,    23,    25,    26,    27,    28,
+     28,    29,    29,    30,    30,    31,    31,    32,    32,    32,
+     33,    33,    34,    34,    35,    35,    36,    36,    37,    37,
+     37,    37,    38,    38,    39,    39,    39,    39,    39,    40,
+     41,    42,    43,    43,    44,    44
+};
 
-static void
-yy_stack_print (yy_state_t *yybottom, yy_state_t *yytop)
+/* YYR2[RULE-NUM] -- Number of symbols on the right-hand side of RULE-NUM  */
+static const unsigned char yyr2[] =
 {
-  YYFPRINTF (stderr, "Stack now");
-  for (; yybottom <= yytop; yybottom++)
-    {
-      int yybot = *yybottom;
-      YYFPRINTF (stderr, " %d", yybot);
-    }
-  YYFPRINTF (stderr, "\n");
-}
-
-# define YY_STACK_PRINT(Bottom, Top)                            \
-do {                                                            \
-  if (yydebug)                                                  \
-    yy_stack_print ((Bottom), (Top));                           \
-} while (0)
-
-
-/*------------------------------------------------.
-| Report that the YYRULE is going to be reduced.  |
-`------------------------------------------------*/
+      2,     1,     0,     1,     2,     1,     1,     1,     6,     2,
+      1,     2,     3,     1,     0,     4,     2,     0,     7,     1,
+      1,     1,     0,     1,     0,     1,     3,     2,     2,     1,
+      2,     2,     1,     2,     1,     1,     3,     5,     5,     0,
+      5,     6,     0,     6,     3,     5,     0,     7,     1,     1,
+      3,     1,     3,     1,     3,     1,     3,     1,     1,     1,
+      1,     3,     1,     1,     1,     3,     1,     1,     1,     2,
+      2,     2,     1,     1,     1,     1,     3,     3,     1,     0,
+      0,     6,     1,     0,     1,     3
+};
 
-static void
-yy_reduce_print (yy_state_t *yyssp, YYSTYPE *yyvsp, YYLTYPE *yylsp,
-                 int yyrule, NodeList **funcdecls, int* errorCount, SymTable* symtable, yyscan_t scanner)
+/* YYDEFACT[STATE-NUM] -- Default reduction in STATE-NUM, 0 if there is none  */
+static const short yydefact[] =
 {
-  int yylno = yyrline[yyrule];
-  int yynrhs = yyr2[yyrule];
-  int yyi;
-  YYFPRINTF (stderr, "Reducing stack by rule %d (line %d):\n",
-             yyrule - 1, yylno);
-  /* The symbols being reduced.  */
-  for (yyi = 0; yyi < yynrhs; yyi++)
-    {
-      YYFPRINTF (stderr, "   $%d = ", yyi + 1);
-      yy_symbol_print (stderr,
-                       YY_ACCESSING_SYMBOL (+yyssp[yyi + 1 - yynrhs]),
-                       &yyvsp[(yyi + 1) - (yynrhs)],
-                       &(yylsp[(yyi + 1) - (yynrhs)]), funcdecls, errorCount, symtable, scanner);
-      YYFPRINTF (stderr, "\n");
-    }
-}
-
-# define YY_REDUCE_PRINT(Rule)          \
-do {                                    \
-  if (yydebug)                          \
-    yy_reduce_print (yyssp, yyvsp, yylsp, Rule, funcdecls, errorCount, symtable, scanner); \
-} while (0)
-
-/* Nonzero means print parse trace.  It is left uninitialized so that
-   multiple parsers can coexist.  */
-int yydebug;
-#else /* !YYDEBUG */
-# define YYDPRINTF(Args) ((void) 0)
-# define YY_SYMBOL_PRINT(Title, Kind, Value, Location)
-# define YY_STACK_PRINT(Bottom, Top)
-# define YY_REDUCE_PRINT(Rule)
-#endif /* !YYDEBUG */
-
-
-/* YYINITDEPTH -- initial size of the parser's stacks.  */
-#ifndef YYINITDEPTH
-# define YYINITDEPTH 200
-#endif
-
-/* YYMAXDEPTH -- maximum size the stacks can grow to (effective only
-   if the built-in stack extension method is used).
-
-   Do not make this value too large; the results are undefined if
-   YYSTACK_ALLOC_MAXIMUM < YYSTACK_BYTES (YYMAXDEPTH)
-   evaluated with infinite-precision integer arithmetic.  */
-
-#ifndef YYMAXDEPTH
-# define YYMAXDEPTH 10000
-#endif
-
+     22,     0,    22,     3,     5,     6,     7,     0,     0,     0,
+     21,     0,     4,    14,    16,     0,    13,     0,     0,    19,
+     20,     0,    46,     0,    17,     9,    15,     0,    32,    34,
+     35,     0,     0,     0,    39,     0,     0,    29,    13,     0,
+      0,    10,     0,     0,    31,    33,     0,    48,    49,    51,
+     53,    55,    60,    64,    68,     0,     0,     0,    74,    75,
+      0,    78,    72,    73,     0,     0,     0,     0,    42,     0,
+     30,     0,     0,    11,     0,    24,    36,     0,     0,     0,
+      0,    57,    58,    59,     0,    62,    63,     0,    66,    67,
+     69,    70,    71,     0,     0,     0,     0,     0,     0,     0,
+      0,    44,     0,     0,     8,    12,     0,    23,    25,     0,
+      0,    50,    52,    54,    56,    61,    65,    76,    83,    77,
+      0,     0,     0,     0,     0,     0,    47,    18,     0,    27,
+     28,    80,    82,    84,    37,    38,    40,     0,     0,    45,
+     26,     0,     0,    41,    43,    81,    85
+};
 
-/* Context of a parse error.  */
-typedef struct
+/* YYCONSISTENT[STATE-NUM] -- Whether STATE-NUM reduces without a lookahead  */
+static const unsigned char yyconsistent[] =
 {
-  yy_state_t *yyssp;
-  yysymbol_kind_t yytoken;
-  YYLTYPE *yylloc;
-} yypcontext_t;
-
-/* Put in YYARG at most YYARGN of the expected tokens given the
-   current YYCTX, and return the number of tokens stored in YYARG.  If
-   YYARG is null, return the number of expected tokens (guaranteed to
-   be less than YYNTOKENS).  Return YYENOMEM on memory exhaustion.
-   Return 0 if there are more than YYARGN expected tokens, yet fill
-   YYARG up to YYARGN. */
-static int
-yypcontext_expected_tokens (const yypcontext_t *yyctx,
-                            yysymbol_kind_t yyarg[], int yyargn)
-{
-  /* Actual size of YYARG. */
-  int yycount = 0;
-  int yyn = yypact[+*yyctx->yyssp];
-  if (!yypact_value_is_default (yyn))
-    {
-      /* Start YYX at -YYN if negative to avoid negative indexes in
-         YYCHECK.  In other words, skip the first -YYN actions for
-         this state because they are default actions.  */
-      int yyxbegin = yyn < 0 ? -yyn : 0;
-      /* Stay within bounds of both yycheck and yytname.  */
-      int yychecklim = YYLAST - yyn + 1;
-      int yyxend = yychecklim < YYNTOKENS ? yychecklim : YYNTOKENS;
-      int yyx;
-      for (yyx = yyxbegin; yyx < yyxend; ++yyx)
-        if (yycheck[yyx + yyn] == yyx && yyx != YYSYMBOL_YYerror
-            && !yytable_value_is_error (yytable[yyx + yyn]))
-          {
-            if (!yyarg)
-              ++yycount;
-            else if (yycount == yyargn)
-              return 0;
-            else
-              yyarg[yycount++] = YY_CAST (yysymbol_kind_t, yyx);
-          }
-    }
-  if (yyarg && yycount == 0 && 0 < yyargn)
-    yyarg[0] = YYSYMBOL_YYEMPTY;
-  return yycount;
-}
-
-
-
+      0,     0,     0,     1,     1,     1,     1,     0,     0,     0,
+      1,     0,     1,     1,     1,     0,     0,     0,     0,     1,
+      1,     0,     1,     0,     1,     1,     1,     0,     1,     1,
+      1,     0,     0,     0,     0,     0,     0,     0,     1,     0,
+      0,     1,     0,     0,     1,     1,     0,     0,     0,     0,
+      0,     0,     0,     1,     1,     0,     0,     0,     1,     0,
+      0,     1,     1,     1,     0,     0,     0,     0,     1,     0,
+      1,     0,     0,     1,     0,     0,     1,     0,     0,     0,
+      0,     1,     1,     1,     0,     1,     1,     0,     1,     1,
+      1,     1,     1,     0,     0,     0,     0,     0,     0,     0,
+      0,     1,     0,     0,     1,     1,     0,     0,     1,     0,
+      0,     0,     0,     0,     0,     0,     1,     1,     0,     1,
+      0,     0,     0,     0,     0,     0,     1,     1,     0,     1,
+      1,     1,     0,     1,     1,     1,     1,     0,     0,     1,
+      1,     0,     0,     1,     1,     1,     1
+};
 
-#ifndef yystrlen
-# if defined __GLIBC__ && defined _STRING_H
-#  define yystrlen(S) (YY_CAST (YYPTRDIFF_T, strlen (S)))
-# else
-/* Return the length of YYSTR.  */
-static YYPTRDIFF_T
-yystrlen (const char *yystr)
+/* YYACTION[STATE-NUM * YYNTOKENS + SYMBOL-NUM] -- What to do on a token:
+   N > 0 shifts to state N - 1, N < -1 reduces by rule -N - 1, -1 accepts
+   and 0 falls back to the default reduction  */
+static const short yyaction[] =
 {
-  YYPTRDIFF_T yylen;
-  for (yylen = 0; yystr[yylen]; yylen++)
-    continue;
-  return yylen;
-}
-# endif
-#endif
+     -3,     0,     0,     0,     0,     9,     0,    11,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+     -1,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+     -2,     0,     0,     0,     0,     9,     0,    11,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,    15,
+      0,     0,     0,     0,    14,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,    16,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,    20,     0,     0,     0,     0,    19,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,    23,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,    24,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,    25,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,    26,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,    38,    32,     9,    33,     0,    19,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,    34,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,    35,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,    38,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,    38,    32,     9,    33,     0,    19,     0,
+      0,     0,     0,     0,     0,    45,     0,     0,    34,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,    35,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,    63,    57,     0,
+      0,     0,    58,     0,    64,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    65,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,    68,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,    69,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,    70,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,    71,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+     72,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,    38,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,    73,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,    75,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    76,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,    77,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,    78,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,    79,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    80,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,    82,    83,    84,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,    86,    87,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,    89,    90,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,    94,   -80,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,    63,    57,     0,
+      0,     0,    58,     0,    64,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,    63,    57,     0,
+      0,     0,    58,     0,    64,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+     98,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+     99,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,   100,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,   102,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    103,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,    63,    57,     0,
+      0,     0,    58,     0,    64,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,   105,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,   106,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,    38,     0,     0,     0,     0,    19,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,    63,    57,     0,
+      0,     0,    58,     0,    64,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,    63,    57,     0,
+      0,     0,    58,     0,    64,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,    63,    57,     0,
+      0,     0,    58,     0,    64,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,    63,    57,     0,
+      0,     0,    58,     0,    64,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,    63,    57,     0,
+      0,     0,    58,     0,    64,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,    63,    57,     0,
+      0,     0,    58,     0,    64,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,   118,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,   119,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,   120,     0,     0,     0,     0,     0,     0,
+      0,    78,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,   121,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,    63,    57,     0,
+      0,     0,    58,     0,    64,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,    63,    57,     0,
+      0,     0,    58,     0,    64,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    124,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    125,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,    63,    57,     0,
+      0,     0,    58,     0,    64,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,   127,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,   128,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    129,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,   130,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,   131,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,    79,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    80,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,    82,    83,    84,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,    86,    87,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,    89,    90,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,    63,    57,     0,
+      0,     0,    58,     0,    64,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,   135,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,   136,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,   137,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,    63,    57,     0,
+      0,     0,    58,     0,    64,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,    63,    57,     0,
+      0,     0,    58,     0,    64,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,   140,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,    38,     0,     0,     0,     0,    19,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    143,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,   144,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,   145,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,   146,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,    61,     0,     0,     0,     0,    63,    57,     0,
+      0,     0,    58,     0,    64,     0,     0,     0,    59,    60,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+      0,     0,     0,     0,     0,     0,     0,     0,     0,     0
+};
 
-#ifndef yystpcpy
-# if defined __GLIBC__ && defined _STRING_H && defined _GNU_SOURCE
-#  define yystpcpy stpcpy
-# else
-/* Copy YYSRC to YYDEST, returning the address of the terminating '\0' in
-   YYDEST.  */
-static char *
-yystpcpy (char *yydest, const char *yysrc)
+/* YYGOTO[STATE-NUM * YYNNTS + SYMBOL-NUM - YYNTOKENS] -- The state after
+   reducing to a nonterminal  */
+static const short yygoto[] =
 {
-  char *yyd = yydest;
-  const char *yys = yysrc;
-
-  while ((*yyd++ = *yys++) != '\0')
-    continue;
+     -1,     1,     2,     3,     6,    -1,    -1,    -1,    -1,    -1,
+      4,    -1,     7,    -1,     9,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,     5,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    12,     6,    -1,    -1,    -1,    -1,    -1,
+      4,    -1,     7,    -1,     9,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,     5,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     16,    -1,    -1,    20,    -1,    -1,    -1,    -1,    17,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    21,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     38,    -1,    -1,    36,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    35,    26,    27,    28,    -1,    29,    -1,
+     -1,    30,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    39,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    40,    41,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    42,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    43,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     38,    -1,    -1,    36,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    35,    -1,    -1,    45,    -1,    29,    -1,
+     -1,    30,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    46,    47,    48,    49,    50,    -1,    51,    -1,
+     52,    -1,    53,    55,    54,    -1,    -1,    61,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    65,    -1,    -1,    61,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    66,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    73,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    42,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    80,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    84,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    87,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    90,    -1,    -1,    61,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    91,
+     -1,    -1,    61,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    92,    -1,    -1,    61,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    94,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    95,    48,
+     49,    50,    -1,    51,    -1,    52,    -1,    53,    55,    54,
+     -1,    -1,    61,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    96,    47,    48,
+     49,    50,    -1,    51,    -1,    52,    -1,    53,    55,    54,
+     -1,    -1,    61,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,   100,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,   103,    47,    48,    49,    50,    -1,    51,    -1,
+     52,    -1,    53,    55,    54,    -1,    -1,    61,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     38,    -1,    -1,   110,    -1,    -1,    -1,    -1,    -1,    -1,
+    106,   107,   108,   109,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,   111,    49,    50,    -1,    51,    -1,
+     52,    -1,    53,    55,    54,    -1,    -1,    61,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+    112,    50,    -1,    51,    -1,    52,    -1,    53,    55,    54,
+     -1,    -1,    61,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,   113,    -1,    51,    -1,
+     52,    -1,    53,    55,    54,    -1,    -1,    61,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,   114,    -1,    52,    -1,    53,    55,    54,
+     -1,    -1,    61,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,   115,    -1,    53,    55,    54,
+     -1,    -1,    61,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,   116,    55,    54,    -1,    -1,    61,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,   121,    47,    48,    49,    50,    -1,    51,    -1,
+     52,    -1,    53,    55,    54,    -1,    -1,    61,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,   122,    47,    48,
+     49,    50,    -1,    51,    -1,    52,    -1,    53,    55,    54,
+     -1,    -1,    61,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,   125,    47,    48,
+     49,    50,    -1,    51,    -1,    52,    -1,    53,    55,    54,
+     -1,    -1,    61,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    80,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    84,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    87,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,   133,    47,    48,
+     49,    50,    -1,    51,    -1,    52,    -1,    53,    55,    54,
+     -1,    -1,    61,   131,   132,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,   137,    47,    48,    49,    50,    -1,    51,    -1,
+     52,    -1,    53,    55,    54,    -1,    -1,    61,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,   138,    47,    48,
+     49,    50,    -1,    51,    -1,    52,    -1,    53,    55,    54,
+     -1,    -1,    61,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    38,    -1,    -1,   110,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,   140,   109,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,   141,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,   146,    47,    48,
+     49,    50,    -1,    51,    -1,    52,    -1,    53,    55,    54,
+     -1,    -1,    61,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,    -1,
+     -1,    -1,    -1,    -1,    -1
+};
 
-  return yyd - 1;
-}
-# endif
+#if YYDEBUG
+/* Nonzero means print parse trace.  */
+int yydebug;
 #endif
 
-
-
-static int
-yy_syntax_error_arguments (const yypcontext_t *yyctx,
-                           yysymbol_kind_t yyarg[], int yyargn)
-{
-  /* Actual size of YYARG. */
-  int yycount = 0;
-  /* There are many possibilities here to consider:
-     - If this state is a consistent state with a default action, then
-       the only way this function was invoked is if the default action
-       is an error action.  In that case, don't check for expected
-       tokens because there are none.
-     - The only way there can be no lookahead present (in yychar) is if
-       this state is a consistent state with a default action.  Thus,
-       detecting the absence of a lookahead is sufficient to determine
-       that there is no unexpected or expected token to report.  In that
-       case, just report a simple "syntax error".
-     - Don't assume there isn't a lookahead just because this state is a
-       consistent state with a default action.  There might have been a
-       previous inconsistent state, consistent state with a non-default
-       action, or user semantic action that manipulated yychar.
-     - Of course, the expected token list depends on states to have
-       correct lookahead information, and it depends on the parser not
-       to perform extra reductions after fetching a lookahead from the
-       scanner and before detecting a syntax error.  Thus, state merging
-       (from LALR or IELR) and default reductions corrupt the expected
-       token list.  However, the list is correct for canonical LR with
-       one exception: it will still contain any token that will not be
-       accepted due to an error action in a later state.
-  */
-  if (yyctx->yytoken != YYSYMBOL_YYEMPTY)
-    {
-      int yyn;
-      if (yyarg)
-        yyarg[yycount] = yyctx->yytoken;
-      ++yycount;
-      yyn = yypcontext_expected_tokens (yyctx,
-                                        yyarg ? yyarg + 1 : yyarg, yyargn - 1);
-      if (yyn == YYENOMEM)
-        return YYENOMEM;
-      else
-        yycount += yyn;
-    }
-  return yycount;
-}
-
-/* Copy into *YYMSG, which is of size *YYMSG_ALLOC, an error message
-   about the unexpected token YYTOKEN for the state stack whose top is
-   YYSSP.
-
-   Return 0 if *YYMSG was successfully written.  Return -1 if *YYMSG is
-   not large enough to hold the message.  In that case, also set
-   *YYMSG_ALLOC to the required number of bytes.  Return YYENOMEM if the
-   required number of bytes is too large to store.  */
-static int
-yysyntax_error (YYPTRDIFF_T *yymsg_alloc, char **yymsg,
-                const yypcontext_t *yyctx)
+/* Report a syntax error in STATE on token YYTOKEN the way bison does with
+   verbose errors: the unexpected token and up to four expected ones.  */
+static void
+yysyntax_error (char *yymsg, int yystate, int yytoken)
 {
-  enum { YYARGS_MAX = 5 };
-  /* Internationalized format string. */
-  const char *yyformat = YY_NULLPTR;
-  /* Arguments of yyformat: reported tokens (one for the "unexpected",
-     one per "expected"). */
-  yysymbol_kind_t yyarg[YYARGS_MAX];
-  /* Cumulated lengths of YYARG.  */
-  YYPTRDIFF_T yysize = 0;
-
-  /* Actual size of YYARG. */
-  int yycount = yy_syntax_error_arguments (yyctx, yyarg, YYARGS_MAX);
-  if (yycount == YYENOMEM)
-    return YYENOMEM;
-
-  switch (yycount)
-    {
-#define YYCASE_(N, S)                       \
-      case N:                               \
-        yyformat = S;                       \
-        break
-    default: /* Avoid compiler warnings. */
-      YYCASE_(0, YY_("syntax error"));
-      YYCASE_(1, YY_("syntax error, unexpected %s"));
-      YYCASE_(2, YY_("syntax error, unexpected %s, expecting %s"));
-      YYCASE_(3, YY_("syntax error, unexpected %s, expecting %s or %s"));
-      YYCASE_(4, YY_("syntax error, unexpected %s, expecting %s or %s or %s"));
-      YYCASE_(5, YY_("syntax error, unexpected %s, expecting %s or %s or %s or %s"));
-#undef YYCASE_
-    }
-
-  /* Compute error message size.  Don't count the "%s"s, but reserve
-     room for the terminator.  */
-  yysize = yystrlen (yyformat) - 2 * yycount + 1;
-  {
-    int yyi;
-    for (yyi = 0; yyi < yycount; ++yyi)
+  const char *yyformats[] = {
+    "syntax error, unexpected %s",
+    "syntax error, unexpected %s, expecting %s",
+    "syntax error, unexpected %s, expecting %s or %s",
+    "syntax error, unexpected %s, expecting %s or %s or %s",
+    "syntax error, unexpected %s, expecting %s or %s or %s or %s"
+  };
+  const char *yyarg[5];
+  int yycount = 1;
+  int yyx;
+  yyarg[0] = yytname[yytoken];
+  for (yyx = 0; yyx < YYNTOKENS; yyx++)
+    if (yyx != 1 && yyaction[yystate * YYNTOKENS + yyx] != 0)
       {
-        YYPTRDIFF_T yysize1
-          = yysize + yystrlen (yysymbol_name (yyarg[yyi]));
-        if (yysize <= yysize1 && yysize1 <= YYSTACK_ALLOC_MAXIMUM)
-          yysize = yysize1;
-        else
-          return YYENOMEM;
+        if (yycount == 5)
+          {
+            yycount = 1;
+            break;
+          }
+        yyarg[yycount++] = yytname[yyx];
       }
-  }
-
-  if (*yymsg_alloc < yysize)
-    {
-      *yymsg_alloc = 2 * yysize;
-      if (! (yysize <= *yymsg_alloc
-             && *yymsg_alloc <= YYSTACK_ALLOC_MAXIMUM))
-        *yymsg_alloc = YYSTACK_ALLOC_MAXIMUM;
-      return -1;
-    }
-
-  /* Avoid sprintf, as that infringes on the user's name space.
-     Don't have undefined behavior even if the translation
-     produced a string with the wrong number of "%s"s.  */
-  {
-    char *yyp = *yymsg;
-    int yyi = 0;
-    while ((*yyp = *yyformat) != '\0')
-      if (*yyp == '%' && yyformat[1] == 's' && yyi < yycount)
-        {
-          yyp = yystpcpy (yyp, yysymbol_name (yyarg[yyi++]));
-          yyformat += 2;
-        }
-      else
-        {
-          ++yyp;
-          ++yyformat;
-        }
-  }
-  return 0;
+  sprintf (yymsg, yyformats[yycount - 1], yyarg[0], yyarg[1], yyarg[2], yyarg[3], yyarg[4]);
 }
 
-
-/*-----------------------------------------------.
-| Release the memory associated to this symbol.  |
-`-----------------------------------------------*/
-
-static void
-yydestruct (const char *yymsg,
-            yysymbol_kind_t yykind, YYSTYPE *yyvaluep, YYLTYPE *yylocationp, NodeList **funcdecls, int* errorCount, SymTable* symtable, yyscan_t scanner)
-{
-  YYUSE (yyvaluep);
-  YYUSE (yylocationp);
-  YYUSE (funcdecls);
-  YYUSE (errorCount);
-  YYUSE (symtable);
-  YYUSE (scanner);
-  if (!yymsg)
-    yymsg = "Deleting";
-  YY_SYMBOL_PRINT (yymsg, yykind, yyvaluep, yylocationp);
-
-  YY_IGNORE_MAYBE_UNINITIALIZED_BEGIN
-  YYUSE (yykind);
-  YY_IGNORE_MAYBE_UNINITIALIZED_END
-}
-
-
-
-
-
-
-/*----------.
-| yyparse.  |
-`----------*/
-
 int
-yyparse (NodeList **funcdecls, int* errorCount, SymTable* symtable, yyscan_t scanner)
+yyparse (NodeList** funcdecls, int* errorCount, SymTable* symtable, yyscan_t scanner)
 {
-/* Lookahead token kind.  */
-int yychar;
-
-
-/* The semantic value of the lookahead symbol.  */
-/* Default value used for initialization, for pacifying older GCCs
-   or non-GCC compilers.  */
-YY_INITIAL_VALUE (static YYSTYPE yyval_default;)
-YYSTYPE yylval YY_INITIAL_VALUE (= yyval_default);
-
-/* Location data for the lookahead symbol.  */
-static YYLTYPE yyloc_default
-# if defined YYLTYPE_IS_TRIVIAL && YYLTYPE_IS_TRIVIAL
-  = { 1, 1, 1, 1 }
-# endif
-;
-YYLTYPE yylloc = yyloc_default;
-
-    /* Number of syntax errors so far.  */
-    int yynerrs = 0;
-
-    yy_state_fast_t yystate = 0;
-    /* Number of tokens to shift before error messages enabled.  */
-    int yyerrstatus = 0;
-
-    /* Refer to the stacks through separate pointers, to allow yyoverflow
-       to reallocate them elsewhere.  */
-
-    /* Their size.  */
-    YYPTRDIFF_T yystacksize = YYINITDEPTH;
-
-    /* The state stack: array, bottom, top.  */
-    yy_state_t yyssa[YYINITDEPTH];
-    yy_state_t *yyss = yyssa;
-    yy_state_t *yyssp = yyss;
-
-    /* The semantic value stack: array, bottom, top.  */
-    YYSTYPE yyvsa[YYINITDEPTH];
-    YYSTYPE *yyvs = yyvsa;
-    YYSTYPE *yyvsp = yyvs;
-
-    /* The location stack: array, bottom, top.  */
-    YYLTYPE yylsa[YYINITDEPTH];
-    YYLTYPE *yyls = yylsa;
-    YYLTYPE *yylsp = yyls;
-
+  /* The lookahead token, its semantic value and location.  */
+  int yychar = YYEMPTY;
+  YYSTYPE yylval;
+  YYLTYPE yylloc = { 1, 1, 1, 1 };
+
+  /* The state, value and location stacks, and their tops.  */
+  int yystacksize = YYINITDEPTH;
+  short *yyss = malloc (yystacksize * sizeof *yyss);
+  YYSTYPE *yyvs = malloc (yystacksize * sizeof *yyvs);
+  YYLTYPE *yyls = malloc (yystacksize * sizeof *yyls);
+  short *yyssp = yyss;
+  YYSTYPE *yyvsp = yyvs;
+  YYLTYPE *yylsp = yyls;
+
+  int yystate = 0;
+  int yytoken = 0;
   int yyn;
-  /* The return value of yyparse.  */
+  int yylen;
   int yyresult;
-  /* Lookahead symbol kind.  */
-  yysymbol_kind_t yytoken = YYSYMBOL_YYEMPTY;
-  /* The variables used to return semantic value and location from the
-     action routines.  */
+  char yymsg[512];
+
+  /* The value and location of the symbol a rule reduces to.  */
   YYSTYPE yyval;
   YYLTYPE yyloc;
 
-  /* The locations where the error started and ended.  */
-  YYLTYPE yyerror_range[3];
-
-  /* Buffer for error messages, and its allocated size.  */
-  char yymsgbuf[128];
-  char *yymsg = yymsgbuf;
-  YYPTRDIFF_T yymsg_alloc = sizeof yymsgbuf;
-
-#define YYPOPSTACK(N)   (yyvsp -= (N), yyssp -= (N), yylsp -= (N))
-
-  /* The number of symbols on the RHS of the reduced rule.
-     Keep to zero when no symbol should be popped.  */
-  int yylen = 0;
-
-  YYDPRINTF ((stderr, "Starting parse\n"));
-
-  yychar = YYEMPTY; /* Cause a token to be read.  */
-  yylsp[0] = yylloc;
-  goto yysetstate;
-
+  if (!yyss || !yyvs || !yyls)
+    goto yyexhaustedlab;
+  *yyssp = 0;
+  *yylsp = yylloc;
 
-/*------------------------------------------------------------.
-| yynewstate -- push a new state, which is found in yystate.  |
-`------------------------------------------------------------*/
 yynewstate:
-  /* In all cases, when you get here, the value and location stacks
-     have just been pushed.  So pushing a state here evens the stacks.  */
-  yyssp++;
-
-
-/*--------------------------------------------------------------------.
-| yysetstate -- set current state (the top of the stack) to yystate.  |
-`--------------------------------------------------------------------*/
-yysetstate:
-  YYDPRINTF ((stderr, "Entering state %d\n", yystate));
-  YY_ASSERT (0 <= yystate && yystate < YYNSTATES);
-  YY_IGNORE_USELESS_CAST_BEGIN
-  *yyssp = YY_CAST (yy_state_t, yystate);
-  YY_IGNORE_USELESS_CAST_END
-  YY_STACK_PRINT (yyss, yyssp);
-
-  if (yyss + yystacksize - 1 <= yyssp)
-#if !defined yyoverflow && !defined YYSTACK_RELOCATE
-    goto yyexhaustedlab;
-#else
+  if (yyssp - yyss + 1 >= yystacksize)
     {
-      /* Get the current used size of the three stacks, in elements.  */
-      YYPTRDIFF_T yysize = yyssp - yyss + 1;
-
-# if defined yyoverflow
-      {
-        /* Give user a chance to reallocate the stack.  Use copies of
-           these so that the &'s don't force the real ones into
-           memory.  */
-        yy_state_t *yyss1 = yyss;
-        YYSTYPE *yyvs1 = yyvs;
-        YYLTYPE *yyls1 = yyls;
-
-        /* Each stack pointer address is followed by the size of the
-           data in use in that stack, in bytes.  This used to be a
-           conditional around just the two extra args, but that might
-           be undefined if yyoverflow is a macro.  */
-        yyoverflow (YY_("memory exhausted"),
-                    &yyss1, yysize * YYSIZEOF (*yyssp),
-                    &yyvs1, yysize * YYSIZEOF (*yyvsp),
-                    &yyls1, yysize * YYSIZEOF (*yylsp),
-                    &yystacksize);
-        yyss = yyss1;
-        yyvs = yyvs1;
-        yyls = yyls1;
-      }
-# else /* defined YYSTACK_RELOCATE */
-      /* Extend the stack our own way.  */
+      long yysize = yyssp - yyss + 1;
       if (YYMAXDEPTH <= yystacksize)
         goto yyexhaustedlab;
       yystacksize *= 2;
       if (YYMAXDEPTH < yystacksize)
         yystacksize = YYMAXDEPTH;
-
-      {
-        yy_state_t *yyss1 = yyss;
-        union yyalloc *yyptr =
-          YY_CAST (union yyalloc *,
-                   YYSTACK_ALLOC (YY_CAST (YYSIZE_T, YYSTACK_BYTES (yystacksize))));
-        if (! yyptr)
-          goto yyexhaustedlab;
-        YYSTACK_RELOCATE (yyss_alloc, yyss);
-        YYSTACK_RELOCATE (yyvs_alloc, yyvs);
-        YYSTACK_RELOCATE (yyls_alloc, yyls);
-#  undef YYSTACK_RELOCATE
-        if (yyss1 != yyssa)
-          YYSTACK_FREE (yyss1);
-      }
-# endif
-
+      yyss = realloc (yyss, yystacksize * sizeof *yyss);
+      yyvs = realloc (yyvs, yystacksize * sizeof *yyvs);
+      yyls = realloc (yyls, yystacksize * sizeof *yyls);
+      if (!yyss || !yyvs || !yyls)
+        goto yyexhaustedlab;
       yyssp = yyss + yysize - 1;
       yyvsp = yyvs + yysize - 1;
       yylsp = yyls + yysize - 1;
-
-      YY_IGNORE_USELESS_CAST_BEGIN
-      YYDPRINTF ((stderr, "Stack size increased to %ld\n",
-                  YY_CAST (long, yystacksize)));
-      YY_IGNORE_USELESS_CAST_END
-
-      if (yyss + yystacksize - 1 <= yyssp)
-        YYABORT;
     }
-#endif /* !defined yyoverflow && !defined YYSTACK_RELOCATE */
 
-  if (yystate == YYFINAL)
-    YYACCEPT;
-
-  goto yybackup;
-
-
-/*-----------.
-| yybackup.  |
-`-----------*/
-yybackup:
-  /* Do appropriate processing given the current state.  Read a
-     lookahead token if we need one and don't already have one.  */
-
-  /* First try to decide what to do without reference to lookahead token.  */
-  yyn = yypact[yystate];
-  if (yypact_value_is_default (yyn))
-    goto yydefault;
-
-  /* Not known => get a lookahead token if don't already have one.  */
-
-  /* YYCHAR is either empty, or end-of-input, or a valid lookahead.  */
-  if (yychar == YYEMPTY)
+  /* Some states reduce whatever comes next, without reading it.  */
+  if (yyconsistent[yystate])
     {
-      YYDPRINTF ((stderr, "Reading a token\n"));
-      yychar = yylex (&yylval, &yylloc, scanner);
+      yyn = yydefact[yystate];
+      goto yyreduce;
     }
 
+  if (yychar == YYEMPTY)
+    yychar = yylex (&yylval, &yylloc, scanner);
+
   if (yychar <= YYEOF)
     {
       yychar = YYEOF;
-      yytoken = YYSYMBOL_YYEOF;
-      YYDPRINTF ((stderr, "Now at end of input.\n"));
+      yytoken = 0;
     }
   else if (yychar == YYerror)
     {
-      /* The scanner already issued an error message, process directly
-         to error recovery.  But do not keep the error token as
-         lookahead, it is too special and may lead us to an endless
-         loop in error recovery. */
+      /* The scanner already issued an error message.  */
       yychar = YYUNDEF;
-      yytoken = YYSYMBOL_YYerror;
-      yyerror_range[1] = yylloc;
-      goto yyerrlab1;
+      goto yyabortlab;
     }
   else
-    {
-      yytoken = YYTRANSLATE (yychar);
-      YY_SYMBOL_PRINT ("Next token is", yytoken, &yylval, &yylloc);
-    }
+    yytoken = YYTRANSLATE (yychar);
 
-  /* If the proper action on seeing token YYTOKEN is to reduce or to
-     detect an error, take that action.  */
-  yyn += yytoken;
-  if (yyn < 0 || YYLAST < yyn || yycheck[yyn] != yytoken)
-    goto yydefault;
-  yyn = yytable[yyn];
-  if (yyn <= 0)
+  yyn = yyaction[yystate * YYNTOKENS + yytoken];
+  if (yyn == 0)
     {
-      if (yytable_value_is_error (yyn))
+      yyn = yydefact[yystate];
+      if (yyn == 0)
         goto yyerrlab;
-      yyn = -yyn;
       goto yyreduce;
     }
-
-  /* Count tokens shifted since error; after three, turn off error
-     status.  */
-  if (yyerrstatus)
-    yyerrstatus--;
+  if (yyn == -1)
+    goto yyacceptlab;
+  if (yyn < 0)
+    {
+      yyn = -yyn - 1;
+      goto yyreduce;
+    }
 
   /* Shift the lookahead token.  */
-  YY_SYMBOL_PRINT ("Shifting", yytoken, &yylval, &yylloc);
-  yystate = yyn;
-  YY_IGNORE_MAYBE_UNINITIALIZED_BEGIN
+  yystate = yyn - 1;
+  *++yyssp = yystate;
   *++yyvsp = yylval;
-  YY_IGNORE_MAYBE_UNINITIALIZED_END
   *++yylsp = yylloc;
-
-  /* Discard the shifted token.  */
   yychar = YYEMPTY;
   goto yynewstate;
 
+yyreduce:
+  yylen = yyr2[yyn];
 
-/*-----------------------------------------------------------.
-| yydefault -- do the default action for the current state.  |
-`-----------------------------------------------------------*/
-yydefault:
-  yyn = yydefact[yystate];
-  if (yyn == 0)
-    goto yyerrlab;
-  goto yyreduce;
+  /* Default value of the rule: the value of its first symbol.  */
+  yyval = yyvsp[1 - yylen];
+  YYLLOC_DEFAULT (yyloc, (yylsp - yylen), yylen);
 
+  switch (yyn)
+    {
+  case 1: /* Program: FunctionDecls */
+#line 217 "Parser.y"
+    {
+  *funcdecls = (yyvsp[(1) - (1)].nodelist);
+}
+#line 1681 "Parser.c"
+    break;
 
-/*-----------------------------.
-| yyreduce -- do a reduction.  |
-`-----------------------------*/
-yyreduce:
-  /* yyn is the number of a rule to reduce with.  */
-  yylen = yyr2[yyn];
+  case 2: /* Program: %empty */
+#line 220 "Parser.y"
+    {
+  *funcdecls = NULL;
+}
+#line 1689 "Parser.c"
+    break;
+
+  case 3: /* FunctionDecls: TopLevelDecl */
+#line 225 "Parser.y"
+    {
+  // A struct declaration leaves nothing in the tree
+  (yyval.nodelist) = (yyvsp[(1) - (1)].node) ? CreateNodeList((yyvsp[(1) - (1)].node), NULL) : NULL;
+}
+#line 1698 "Parser.c"
+    break;
+
+  case 4: /* FunctionDecls: FunctionDecls TopLevelDecl */
+#line 229 "Parser.y"
+    {
+  if ((yyvsp[(2) - (2)].node) == NULL || (yyvsp[(1) - (2)].nodelist) == NULL) {
+    (yyval.nodelist) = (yyvsp[(1) - (2)].nodelist) ? (yyvsp[(1) - (2)].nodelist) : ((yyvsp[(2) - (2)].node) ? CreateNodeList((yyvsp[(2) - (2)].node), NULL) : NULL);
+  } else {
+    // Find last node in PS
+    NodeList* last = (yyvsp[(1) - (2)].nodelist);
+    while (last->next != NULL) last = last->next;
+    NodeList* newNodeList = CreateNodeList((yyvsp[(2) - (2)].node), NULL);
+    last->next = newNodeList;
+    (yyval.nodelist) = (yyvsp[(1) - (2)].nodelist);
+  }
+}
+#line 1715 "Parser.c"
+    break;
 
-  /* If YYLEN is nonzero, implement the default value of the action:
-     '$$ = $1'.
+  case 5: /* TopLevelDecl: FunctionDecl */
+#line 243 "Parser.y"
+    {
+  (yyval.node) = (yyvsp[(1) - (1)].node);
+}
+#line 1723 "Parser.c"
+    break;
+
+  case 6: /* TopLevelDecl: ConstDecl */
+#line 246 "Parser.y"
+    {
+  (yyval.node) = (yyvsp[(1) - (1)].node);
+}
+#line 1731 "Parser.c"
+    break;
+
+  case 7: /* TopLevelDecl: StructDecl */
+#line 249 "Parser.y"
+    {
+  (yyval.node) = NULL;
+}
+#line 1739 "Parser.c"
+    break;
+
+  case 8: /* StructDecl: Linkage StructName TOKEN_LBRACE Fields TOKEN_RBRACE TOKEN_SEMI */
+#line 256 "Parser.y"
+    {
+  char* tag = StructTag((yyvsp[(2) - (6)].str));
+  if ((yyvsp[(1) - (6)].value) == LINKAGE_STATIC || LookupScope(symtable, tag)) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, (yyvsp[(1) - (6)].value) == LINKAGE_STATIC ? "\t'static' is not allowed on '%s'\n" :
+                                          "\tredefinition of '%s'\n", tag);
+    (*errorCount)++;
+    YYABORT;
+  }
+  free((yyvsp[(2) - (6)].str));
+  Insert(symtable, CreateStructType(tag, (yyvsp[(4) - (6)].nodelist)), STRUCT_TYPE);
+}
+#line 1756 "Parser.c"
+    break;
+
+  case 9: /* StructName: TOKEN_STRUCT TOKEN_ID */
+#line 270 "Parser.y"
+    {
+  (yyval.str) = (yyvsp[(2) - (2)].str);
+}
+#line 1764 "Parser.c"
+    break;
+
+  case 10: /* Fields: Field */
+#line 275 "Parser.y"
+    {
+  (yyval.nodelist) = CreateNodeList((yyvsp[(1) - (1)].node), NULL);
+}
+#line 1772 "Parser.c"
+    break;
+
+  case 11: /* Fields: Fields Field */
+#line 278 "Parser.y"
+    {
+  // Find last node in PS, making sure the field name is new
+  NodeList* last = (yyvsp[(1) - (2)].nodelist);
+  for (NodeList* nl = (yyvsp[(1) - (2)].nodelist); nl != NULL; nl = nl->next) {
+    if (strcmp(nl->node->name, (yyvsp[(2) - (2)].node)->name) == 0) {
+      PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+      fprintf(stderr, "\tduplicate member '%s'\n", (yyvsp[(2) - (2)].node)->name);
+      (*errorCount)++;
+      YYABORT;
+    }
+    last = nl;
+  }
+  last->next = CreateNodeList((yyvsp[(2) - (2)].node), NULL);
+  (yyval.nodelist) = (yyvsp[(1) - (2)].nodelist);
+}
+#line 1792 "Parser.c"
+    break;
 
-     Otherwise, the following line sets YYVAL to garbage.
-     This behavior is undocumented and Bison
-     users should not rely upon it.  Assigning to YYVAL
-     unconditionally makes the parser a bit smaller, and it avoids a
-     GCC warning that YYVAL may be used uninitialized.  */
-  yyval = yyvsp[1-yylen];
+  case 12: /* Field: VarType TOKEN_ID TOKEN_SEMI */
+#line 295 "Parser.y"
+    {
+  Node* temp = CreateParameter((yyvsp[(2) - (3)].str));
+  temp->value = (yyvsp[(1) - (3)].value);
+  (yyval.node) = temp;
+}
+#line 1802 "Parser.c"
+    break;
 
-  /* Default location. */
-  YYLLOC_DEFAULT (yyloc, (yylsp - yylen), yylen);
-  yyerror_range[1] = yyloc;
-  YY_REDUCE_PRINT (yyn);
-  switch (yyn)
+  case 13: /* StructType: StructName */
+#line 302 "Parser.y"
     {
-  case 2: /* Program: FunctionDecls  */
-                   {
-  *funcdecls = (yyvsp[0].nodelist);
+  char* tag = StructTag((yyvsp[(1) - (1)].str));
+  list_t* exists = Lookup(symtable, tag);
+  free(tag);
+  if (!exists) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tunknown type 'struct %s'\n", (yyvsp[(1) - (1)].str));
+    (*errorCount)++;
+    YYABORT;
+  }
+  free((yyvsp[(1) - (1)].str));
+  (yyval.node) = exists->node;
 }
+#line 1820 "Parser.c"
     break;
 
-  case 3: /* Program: %empty  */
-  {
-  *funcdecls = NULL;
+  case 14: /* $@1: %empty */
+#line 317 "Parser.y"
+    {
+  // Only one definition per overload. From here on, calls (including
+  // recursive ones) resolve to the definition instead of a prototype.
+  list_t* entry = LookupFunction(symtable, (yyvsp[(1) - (2)].node)->name, NodeListLen((yyvsp[(1) - (2)].node)->arguments));
+  if (entry->node != (yyvsp[(1) - (2)].node)) {
+    if (entry->node->statements != NULL) {
+      PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+      fprintf(stderr, "\tredefinition of '%s' with %d parameters\n",
+              (yyvsp[(1) - (2)].node)->name, NodeListLen((yyvsp[(1) - (2)].node)->arguments));
+      (*errorCount)++;
+      YYABORT;
+    }
+    entry->node = (yyvsp[(1) - (2)].node);
+  }
 }
+#line 1840 "Parser.c"
     break;
 
-  case 4: /* FunctionDecls: FunctionDecl  */
-                 {
-  (yyval.nodelist) = CreateNodeList((yyvsp[0].node), NULL);
+  case 15: /* FunctionDecl: FunctionHead TOKEN_LBRACE $@1 FunctionDeclTail */
+#line 331 "Parser.y"
+    {
+  (yyvsp[(1) - (4)].node)->statements = (yyvsp[(4) - (4)].nodelist);
+  ClearSymTable(symtable);
+  DecrScope(symtable);
+  (yyval.node) = (yyvsp[(1) - (4)].node);
 }
+#line 1851 "Parser.c"
     break;
 
-  case 5: /* FunctionDecls: FunctionDecls FunctionDecl  */
-                                      {
-  // Find last node in PS
-  NodeList* last = (yyvsp[-1].nodelist);
-  while (last->next != NULL) last = last->next;
-  NodeList* newNodeList = CreateNodeList((yyvsp[0].node), NULL);
-  last->next = newNodeList;
-  (yyval.nodelist) = (yyvsp[-1].nodelist);
+  case 16: /* FunctionDecl: FunctionHead TOKEN_SEMI */
+#line 337 "Parser.y"
+    {
+  // A prototype, so the function can be called before it is defined,
+  // or from another object file when it is never defined here
+  ClearSymTable(symtable);
+  DecrScope(symtable);
+  (yyval.node) = (yyvsp[(1) - (2)].node);
 }
+#line 1863 "Parser.c"
     break;
 
-  case 6: /* @1: %empty  */
-                            {
+  case 17: /* $@2: %empty */
+#line 346 "Parser.y"
+    {
   // First add the function scope
-  // But before that, make sure a function with the same name does not exist
-  list_t* exists = LookupScope(symtable, (yyvsp[0].str));
-  if (exists) {
+  // But before that, make sure the name is not already taken by something else.
+  // Functions may share a name as long as their parameter counts differ.
+  list_t* exists = LookupScope(symtable, (yyvsp[(3) - (3)].str));
+  if (exists && exists->st_type != FUNCTION_TYPE) {
     PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
-    fprintf(stderr, "\tredefinition of '%s'\n", (yyvsp[0].str));
+    fprintf(stderr, "\tredefinition of '%s'\n", (yyvsp[(3) - (3)].str));
     (*errorCount)++;
     YYABORT;
   }
-  Node* temp = CreateFunctionDecl((yyvsp[0].str), NULL, NULL);
-  Insert(symtable, temp, FUNCTION_TYPE);
+  Node* temp = CreateFunctionDecl((yyvsp[(3) - (3)].str), NULL, NULL);
+  temp->value = (yyvsp[(1) - (3)].value);
+  temp->left = (yyvsp[(2) - (3)].node);
   // Then, increase the scope
   IncrScope(symtable);
   (yyval.node) = temp;
 }
+#line 1886 "Parser.c"
     break;
 
-  case 7: /* FunctionDecl: "long" "variable name" @1 "(" ParametersOptional ")" FunctionDeclTail  */
-                                                                          {
-  (yyvsp[-4].node)->arguments = (yyvsp[-2].nodelist);
-  (yyvsp[-4].node)->statements = (yyvsp[0].nodelist);
-  ClearSymTable(symtable);
-  DecrScope(symtable);
-  (yyval.node) = (yyvsp[-4].node);
+  case 18: /* FunctionHead: Linkage ReturnType TOKEN_ID $@2 TOKEN_LPAREN ParametersOptional TOKEN_RPAREN */
+#line 363 "Parser.y"
+    {
+  // The parameter count tells which overload this declares. It is known
+  // before the body, so recursive calls can be checked.
+  (yyvsp[(4) - (7)].node)->arguments = (yyvsp[(6) - (7)].nodelist);
+  list_t* entry = LookupFunction(symtable, (yyvsp[(3) - (7)].str), NodeListLen((yyvsp[(6) - (7)].nodelist)));
+  if (!entry) {
+    DecrScope(symtable);
+    Insert(symtable, (yyvsp[(4) - (7)].node), FUNCTION_TYPE);
+    IncrScope(symtable);
+  }
+  else if (entry->node->left != (yyvsp[(2) - (7)].node)) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tconflicting return types for '%s'\n", (yyvsp[(3) - (7)].str));
+    (*errorCount)++;
+    YYABORT;
+  }
+  // Like C, a function stays static once declared so
+  else if (entry->node->value == LINKAGE_STATIC) {
+    (yyvsp[(4) - (7)].node)->value = LINKAGE_STATIC;
+  }
+  else if ((yyvsp[(1) - (7)].value) == LINKAGE_STATIC) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tstatic declaration of '%s' follows a non-static declaration\n", (yyvsp[(3) - (7)].str));
+    (*errorCount)++;
+    YYABORT;
+  }
+  symtable->cur_function = (yyvsp[(4) - (7)].node);
+  (yyval.node) = (yyvsp[(4) - (7)].node);
 }
+#line 1920 "Parser.c"
     break;
 
-  case 8: /* ParametersOptional: Parameters  */
-              {
-  (yyval.nodelist) = (yyvsp[0].nodelist);
+  case 19: /* ReturnType: TOKEN_TYPE_LONG */
+#line 394 "Parser.y"
+    {
+  (yyval.node) = NULL;
 }
+#line 1928 "Parser.c"
     break;
 
-  case 9: /* ParametersOptional: %empty  */
-  {
+  case 20: /* ReturnType: StructType */
+#line 397 "Parser.y"
+    {
+  // Only what fits in %rax and %rdx
+  if (NodeListLen((yyvsp[(1) - (1)].node)->arguments) > 2) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\t'%s' is larger than 16 bytes and can not be returned by value\n", (yyvsp[(1) - (1)].node)->name);
+    (*errorCount)++;
+    YYABORT;
+  }
+  (yyval.node) = (yyvsp[(1) - (1)].node);
+}
+#line 1943 "Parser.c"
+    break;
+
+  case 21: /* Linkage: TOKEN_STATIC */
+#line 409 "Parser.y"
+    {
+  (yyval.value) = LINKAGE_STATIC;
+}
+#line 1951 "Parser.c"
+    break;
+
+  case 22: /* Linkage: %empty */
+#line 412 "Parser.y"
+    {
+  (yyval.value) = LINKAGE_EXTERNAL;
+}
+#line 1959 "Parser.c"
+    break;
+
+  case 23: /* ParametersOptional: Parameters */
+#line 417 "Parser.y"
+    {
+  (yyval.nodelist) = (yyvsp[(1) - (1)].nodelist);
+}
+#line 1967 "Parser.c"
+    break;
+
+  case 24: /* ParametersOptional: %empty */
+#line 420 "Parser.y"
+    {
   (yyval.nodelist) = NULL;
 }
+#line 1975 "Parser.c"
     break;
 
-  case 10: /* Parameters: Parameter  */
-             {
-  (yyval.nodelist) = CreateNodeList((yyvsp[0].node), NULL);
+  case 25: /* Parameters: Parameter */
+#line 425 "Parser.y"
+    {
+  (yyval.nodelist) = (yyvsp[(1) - (1)].nodelist);
 }
+#line 1983 "Parser.c"
     break;
 
-  case 11: /* Parameters: Parameters "," Parameter  */
-                                          {
+  case 26: /* Parameters: Parameters TOKEN_COMMA Parameter */
+#line 428 "Parser.y"
+    {
   // Find last node in PS
-  NodeList* last = (yyvsp[-2].nodelist);
+  NodeList* last = (yyvsp[(1) - (3)].nodelist);
   while (last->next != NULL) last = last->next;
-  NodeList* newNode = CreateNodeList((yyvsp[0].node), NULL);
-  last->next = newNode;
-  (yyval.nodelist) = (yyvsp[-2].nodelist);
+  last->next = (yyvsp[(3) - (3)].nodelist);
+  (yyval.nodelist) = (yyvsp[(1) - (3)].nodelist);
 }
+#line 1995 "Parser.c"
     break;
 
-  case 12: /* Parameter: "long" "variable name"  */
-                            {
+  case 27: /* Parameter: VarType TOKEN_ID */
+#line 437 "Parser.y"
+    {
   // Declaring a new parameter
   // Make sure that the variable does not exist in the current scope
-  list_t* exists = LookupScope(symtable, (yyvsp[0].str));
+  list_t* exists = LookupScope(symtable, (yyvsp[(2) - (2)].str));
   if (exists) {
     PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
-    fprintf(stderr, "\tredefinition of '%s'\n", (yyvsp[0].str));
+    fprintf(stderr, "\tredefinition of '%s'\n", (yyvsp[(2) - (2)].str));
     (*errorCount)++;
     //symtab_dump(symtable, NULL);
     YYABORT;
   }
-  
-  Node* temp = CreateParameter((yyvsp[0].str));
+
+  Node* temp = CreateParameter((yyvsp[(2) - (2)].str));
+  temp->value = (yyvsp[(1) - (2)].value);
   Insert(symtable, temp, LONG_TYPE);
-  (yyval.node) = temp;
+  (yyval.nodelist) = CreateNodeList(temp, NULL);
+}
+#line 2017 "Parser.c"
+    break;
+
+  case 28: /* Parameter: StructType TOKEN_ID */
+#line 454 "Parser.y"
+    {
+  // Declaring a struct parameter
+  // Make sure that the variable does not exist in the current scope
+  list_t* exists = LookupScope(symtable, (yyvsp[(2) - (2)].str));
+  if (exists || NodeListLen((yyvsp[(1) - (2)].node)->arguments) > 2) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    if (exists) fprintf(stderr, "\tredefinition of '%s'\n", (yyvsp[(2) - (2)].str));
+    else fprintf(stderr, "\t'%s' is larger than 16 bytes and can not be passed by value\n", (yyvsp[(1) - (2)].node)->name);
+    (*errorCount)++;
+    YYABORT;
+  }
+  Insert(symtable, CreateVariable((yyvsp[(2) - (2)].str), (yyvsp[(1) - (2)].node)), STRUCT_VAR);
+
+  // One parameter per field, so the fields take the next free registers
+  NodeList* head = NULL;
+  NodeList** tail = &head;
+  for (NodeList* field = (yyvsp[(1) - (2)].node)->arguments; field != NULL; field = field->next) {
+    Node* temp = CreateParameter(FieldName((yyvsp[(2) - (2)].str), field->node->name));
+    temp->value = field->node->value;
+    Insert(symtable, temp, LONG_TYPE);
+    *tail = CreateNodeList(temp, NULL);
+    tail = &(*tail)->next;
+  }
+  (yyval.nodelist) = head;
+}
+#line 2047 "Parser.c"
+    break;
+
+  case 29: /* VarType: TOKEN_TYPE_LONG */
+#line 481 "Parser.y"
+    {
+  (yyval.value) = DECL_LONG;
+}
+#line 2055 "Parser.c"
+    break;
+
+  case 30: /* VarType: TOKEN_TYPE_LONG TOKEN_STAR */
+#line 484 "Parser.y"
+    {
+  (yyval.value) = DECL_POINTER;
 }
+#line 2063 "Parser.c"
     break;
 
-  case 13: /* FunctionDeclTail: "{" Statements "}"  */
-                                        {
-  (yyval.nodelist) = (yyvsp[-1].nodelist);
+  case 31: /* FunctionDeclTail: Statements TOKEN_RBRACE */
+#line 489 "Parser.y"
+    {
+  (yyval.nodelist) = (yyvsp[(1) - (2)].nodelist);
 }
+#line 2071 "Parser.c"
     break;
 
-  case 14: /* Statements: Statement  */
-             {
-  (yyval.nodelist) = CreateNodeList((yyvsp[0].node), NULL);
+  case 32: /* Statements: Statement */
+#line 494 "Parser.y"
+    {
+  (yyval.nodelist) = (yyvsp[(1) - (1)].nodelist);
 }
+#line 2079 "Parser.c"
     break;
 
-  case 15: /* Statements: Statements Statement  */
-                              {
+  case 33: /* Statements: Statements Statement */
+#line 497 "Parser.y"
+    {
   // Find last node in PS
-  NodeList* last = (yyvsp[-1].nodelist);
+  NodeList* last = (yyvsp[(1) - (2)].nodelist);
   while (last->next != NULL) last = last->next;
-  NodeList* newNode = CreateNodeList((yyvsp[0].node), NULL);
-  last->next = newNode;
-  (yyval.nodelist) = (yyvsp[-1].nodelist);
+  last->next = (yyvsp[(2) - (2)].nodelist);
+  (yyval.nodelist) = (yyvsp[(1) - (2)].nodelist);
+}
+#line 2091 "Parser.c"
+    break;
+
+  case 34: /* Statement: Assignment */
+#line 506 "Parser.y"
+    {
+  (yyval.nodelist) = (yyvsp[(1) - (1)].nodelist);
+}
+#line 2099 "Parser.c"
+    break;
+
+  case 35: /* Statement: ConstDecl */
+#line 509 "Parser.y"
+    {
+  (yyval.nodelist) = CreateNodeList((yyvsp[(1) - (1)].node), NULL);
+}
+#line 2107 "Parser.c"
+    break;
+
+  case 36: /* Statement: TOKEN_RETURN Expression TOKEN_SEMI */
+#line 512 "Parser.y"
+    {
+  // The returned value must have the type the function returns
+  Node* type = symtable->cur_function->left;
+  if (StructOf((yyvsp[(2) - (3)].node)) != type) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\treturning '%s' from a function returning '%s'\n",
+            TypeName(StructOf((yyvsp[(2) - (3)].node))), TypeName(type));
+    (*errorCount)++;
+    YYABORT;
+  }
+  Node* temp;
+  if (type != NULL && (yyvsp[(2) - (3)].node)->exprCode == VARIABLE) {
+    temp = CreateReturn(FieldVariable(symtable, (yyvsp[(2) - (3)].node)->name, type->arguments->node));
+    if (type->arguments->next != NULL) {
+      temp->right = FieldVariable(symtable, (yyvsp[(2) - (3)].node)->name, type->arguments->next->node);
+    }
+    FreeVariable((yyvsp[(2) - (3)].node));
+  } else {
+    temp = CreateReturn((yyvsp[(2) - (3)].node));
+    // The call leaves the second field in %rdx, where it is returned
+    if (type != NULL && type->arguments->next != NULL) {
+      temp->right = CreateUnaryOperation(RESULTHIGH, NULL);
+    }
+  }
+  (yyval.nodelist) = CreateNodeList(temp, NULL);
 }
+#line 2138 "Parser.c"
     break;
 
-  case 16: /* Statement: Assignment  */
-              {
-  (yyval.node) = (yyvsp[0].node);
+  case 37: /* Statement: TOKEN_ASSERT TOKEN_LPAREN Expression TOKEN_RPAREN TOKEN_SEMI */
+#line 538 "Parser.y"
+    {
+  REQUIRE_LONG((yyvsp[(3) - (5)].node));
+  (yyval.nodelist) = CreateNodeList(CreateAssert((yyvsp[(3) - (5)].node), (yylsp[(1) - (5)]).first_line), NULL);
 }
+#line 2147 "Parser.c"
     break;
 
-  case 17: /* Statement: "return" Expression ";"  */
-                                        {
-  (yyval.node) = CreateReturn((yyvsp[-1].node));
+  case 38: /* Statement: TOKEN_STAR Operand TOKEN_ASSIGN Expression TOKEN_SEMI */
+#line 542 "Parser.y"
+    {
+  if (!IsPointer((yyvsp[(2) - (5)].node))) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tstore through a value of type 'long', expected 'long*'\n");
+    (*errorCount)++;
+    YYABORT;
+  }
+  REQUIRE_LONG((yyvsp[(4) - (5)].node));
+  (yyval.nodelist) = CreateNodeList(CreateStore((yyvsp[(2) - (5)].node), (yyvsp[(4) - (5)].node)), NULL);
 }
+#line 2162 "Parser.c"
     break;
 
-  case 18: /* @2: %empty  */
-            {
+  case 39: /* $@3: %empty */
+#line 554 "Parser.y"
+    {
   // Must make sure that lvalue variable exists
-  list_t* exists = Lookup(symtable, (yyvsp[0].str));
+  list_t* exists = Lookup(symtable, (yyvsp[(1) - (1)].str));
   if (!exists) {
-    printf("Variable %s is not declared yet :( \n", (yyvsp[0].str));
+    printf("Variable %s is not declared yet :( \n", (yyvsp[(1) - (1)].str));
     YYABORT;
   } else if (exists->st_type == FUNCTION_TYPE) {
     PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
-    fprintf(stderr, "\tillegal assignment to the function '%s'\n", (yyvsp[0].str));
+    fprintf(stderr, "\tillegal assignment to the function '%s'\n", (yyvsp[(1) - (1)].str));
     (*errorCount)++;
     //symtab_dump(symtable, NULL);
     YYABORT;
+  } else if (exists->st_type == CONST_TYPE) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tcannot assign to the constant '%s'\n", (yyvsp[(1) - (1)].str));
+    (*errorCount)++;
+    YYABORT;
   }
   (yyval.symel) = exists;
 }
+#line 2187 "Parser.c"
     break;
 
-  case 19: /* Assignment: "variable name" @2 "=" Expression ";"  */
-                                            {
-  // Since $N exists, create an assignment and upate the symtable
-  Node* temp = CreateAssign((yyvsp[-4].str), (yyvsp[-1].node));
-  (yyvsp[-3].symel)->node = temp;
-  (yyval.node) = temp;
+  case 40: /* Assignment: TOKEN_ID $@3 TOKEN_ASSIGN Expression TOKEN_SEMI */
+#line 573 "Parser.y"
+    {
+  if ((yyvsp[(2) - (5)].symel)->st_type == STRUCT_VAR) {
+    // A struct is assigned field by field, from a struct of the same type
+    Node* type = (yyvsp[(2) - (5)].symel)->node->left;
+    if (StructOf((yyvsp[(4) - (5)].node)) != type) {
+      PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+      fprintf(stderr, "\tassigning '%s' to a variable of type '%s'\n",
+              TypeName(StructOf((yyvsp[(4) - (5)].node))), type->name);
+      (*errorCount)++;
+      YYABORT;
+    }
+    (yyval.nodelist) = AssignStruct(symtable, (yyvsp[(1) - (5)].str), type, (yyvsp[(4) - (5)].node), 0);
+    free((yyvsp[(1) - (5)].str));
+  } else {
+    REQUIRE_LONG((yyvsp[(4) - (5)].node));
+    // Since $N exists, create an assignment and upate the symtable
+    Node* temp = CreateAssign((yyvsp[(1) - (5)].str), (yyvsp[(4) - (5)].node));
+    // The variable keeps the type it was declared with
+    temp->value = (yyvsp[(2) - (5)].symel)->node->value;
+    (yyvsp[(2) - (5)].symel)->node = temp;
+    (yyval.nodelist) = CreateNodeList(temp, NULL);
+  }
+}
+#line 2215 "Parser.c"
+    break;
+
+  case 41: /* Assignment: TOKEN_ID TOKEN_DOT TOKEN_ID TOKEN_ASSIGN Expression TOKEN_SEMI */
+#line 596 "Parser.y"
+    {
+  // Fields are variables of their own, named after the struct variable
+  char* name = FieldName((yyvsp[(1) - (6)].str), (yyvsp[(3) - (6)].str));
+  list_t* exists = Lookup(symtable, name);
+  if (!exists) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\t'%s' has no member named '%s'\n", (yyvsp[(1) - (6)].str), (yyvsp[(3) - (6)].str));
+    (*errorCount)++;
+    YYABORT;
+  }
+  REQUIRE_LONG((yyvsp[(5) - (6)].node));
+  Node* temp = CreateAssign(name, (yyvsp[(5) - (6)].node));
+  temp->value = exists->node->value;
+  exists->node = temp;
+  free((yyvsp[(1) - (6)].str));
+  free((yyvsp[(3) - (6)].str));
+  (yyval.nodelist) = CreateNodeList(temp, NULL);
 }
+#line 2238 "Parser.c"
     break;
 
-  case 20: /* $@3: %empty  */
-                              {
+  case 42: /* $@4: %empty */
+#line 614 "Parser.y"
+    {
   // Declaring a new variable
   // Make sure that the variable does not exist in the current scope
-  list_t* exists = LookupScope(symtable, (yyvsp[0].str));
+  list_t* exists = LookupScope(symtable, (yyvsp[(2) - (2)].str));
   if (exists) {
     PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
-    fprintf(stderr, "\tredefinition of '%s'\n", (yyvsp[0].str));
+    fprintf(stderr, "\tredefinition of '%s'\n", (yyvsp[(2) - (2)].str));
     (*errorCount)++;
     YYABORT;
   }
 }
+#line 2254 "Parser.c"
     break;
 
-  case 21: /* Assignment: "long" "variable name" $@3 "=" Expression ";"  */
-                                        {
+  case 43: /* Assignment: VarType TOKEN_ID $@4 TOKEN_ASSIGN Expression TOKEN_SEMI */
+#line 624 "Parser.y"
+    {
+  REQUIRE_LONG((yyvsp[(5) - (6)].node));
   // Now we can create an assignment for a new variable:
-  Node* temp = CreateAssign((yyvsp[-4].str), (yyvsp[-1].node));
+  Node* temp = CreateAssign((yyvsp[(2) - (6)].str), (yyvsp[(5) - (6)].node));
+  temp->value = (yyvsp[(1) - (6)].value);
   Insert(symtable, temp, LONG_TYPE);
+  (yyval.nodelist) = CreateNodeList(temp, NULL);
+}
+#line 2267 "Parser.c"
+    break;
+
+  case 44: /* Assignment: StructType TOKEN_ID TOKEN_SEMI */
+#line 632 "Parser.y"
+    {
+  // Declaring a new struct variable, with every field set to 0
+  if (LookupScope(symtable, (yyvsp[(2) - (3)].str))) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tredefinition of '%s'\n", (yyvsp[(2) - (3)].str));
+    (*errorCount)++;
+    YYABORT;
+  }
+  Insert(symtable, CreateVariable((yyvsp[(2) - (3)].str), (yyvsp[(1) - (3)].node)), STRUCT_VAR);
+  (yyval.nodelist) = AssignStruct(symtable, (yyvsp[(2) - (3)].str), (yyvsp[(1) - (3)].node), NULL, 1);
+}
+#line 2283 "Parser.c"
+    break;
+
+  case 45: /* Assignment: StructType TOKEN_ID TOKEN_ASSIGN Expression TOKEN_SEMI */
+#line 643 "Parser.y"
+    {
+  // Declaring a new struct variable, initialized from another struct
+  if (LookupScope(symtable, (yyvsp[(2) - (5)].str)) || StructOf((yyvsp[(4) - (5)].node)) != (yyvsp[(1) - (5)].node)) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    if (LookupScope(symtable, (yyvsp[(2) - (5)].str))) fprintf(stderr, "\tredefinition of '%s'\n", (yyvsp[(2) - (5)].str));
+    else fprintf(stderr, "\tinitializing '%s' with a value of type '%s'\n",
+                 (yyvsp[(1) - (5)].node)->name, TypeName(StructOf((yyvsp[(4) - (5)].node))));
+    (*errorCount)++;
+    YYABORT;
+  }
+  Insert(symtable, CreateVariable((yyvsp[(2) - (5)].str), (yyvsp[(1) - (5)].node)), STRUCT_VAR);
+  (yyval.nodelist) = AssignStruct(symtable, (yyvsp[(2) - (5)].str), (yyvsp[(1) - (5)].node), (yyvsp[(4) - (5)].node), 1);
+}
+#line 2301 "Parser.c"
+    break;
+
+  case 46: /* $@5: %empty */
+#line 658 "Parser.y"
+    {
+  // Declaring a new constant, at file or function scope
+  list_t* exists = LookupScope(symtable, (yyvsp[(3) - (3)].str));
+  if (exists) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tredefinition of '%s'\n", (yyvsp[(3) - (3)].str));
+    (*errorCount)++;
+    YYABORT;
+  }
+}
+#line 2316 "Parser.c"
+    break;
+
+  case 47: /* ConstDecl: TOKEN_CONST TOKEN_TYPE_LONG TOKEN_ID $@5 TOKEN_ASSIGN Expression TOKEN_SEMI */
+#line 667 "Parser.y"
+    {
+  REQUIRE_LONG((yyvsp[(6) - (7)].node));
+  // The initializer is folded by the optimizer, which reports it if it
+  // is not a compile-time constant.
+  Node* temp = CreateConstDecl((yyvsp[(3) - (7)].str), (yyvsp[(6) - (7)].node));
+  Insert(symtable, temp, CONST_TYPE);
   (yyval.node) = temp;
 }
+#line 2329 "Parser.c"
     break;
 
-  case 22: /* Expression: BorExpression  */
-                 {
-  (yyval.node) = (yyvsp[0].node);
+  case 48: /* Expression: BorExpression */
+#line 677 "Parser.y"
+    {
+  (yyval.node) = (yyvsp[(1) - (1)].node);
 }
+#line 2337 "Parser.c"
+    break;
+
+  case 49: /* BorExpression: BxorExpression */
+#line 682 "Parser.y"
+    { (yyval.node) = (yyvsp[(1) - (1)].node); }
+#line 2343 "Parser.c"
+    break;
+
+  case 50: /* BorExpression: BorExpression TOKEN_BOR BxorExpression */
+#line 684 "Parser.y"
+    { REQUIRE_LONG((yyvsp[(1) - (3)].node)); REQUIRE_LONG((yyvsp[(3) - (3)].node)); (yyval.node) = CreateBinaryOperation(BOR, (yyvsp[(1) - (3)].node), (yyvsp[(3) - (3)].node)); }
+#line 2349 "Parser.c"
+    break;
+
+  case 51: /* BxorExpression: BandExpression */
+#line 687 "Parser.y"
+    { (yyval.node) = (yyvsp[(1) - (1)].node); }
+#line 2355 "Parser.c"
     break;
 
-  case 23: /* BorExpression: BxorExpression  */
-                  { (yyval.node) = (yyvsp[0].node); }
+  case 52: /* BxorExpression: BxorExpression TOKEN_BXOR BandExpression */
+#line 689 "Parser.y"
+    { REQUIRE_LONG((yyvsp[(1) - (3)].node)); REQUIRE_LONG((yyvsp[(3) - (3)].node)); (yyval.node) = CreateBinaryOperation(BXOR, (yyvsp[(1) - (3)].node), (yyvsp[(3) - (3)].node)); }
+#line 2361 "Parser.c"
     break;
 
-  case 24: /* BorExpression: BorExpression "|" BxorExpression  */
-  { (yyval.node) = CreateBinaryOperation(BOR, (yyvsp[-2].node), (yyvsp[0].node)); }
+  case 53: /* BandExpression: ShrExpression */
+#line 692 "Parser.y"
+    { (yyval.node) = (yyvsp[(1) - (1)].node); }
+#line 2367 "Parser.c"
     break;
 
-  case 25: /* BxorExpression: BandExpression  */
-                    { (yyval.node) = (yyvsp[0].node); }
+  case 54: /* BandExpression: BandExpression TOKEN_BAND ShrExpression */
+#line 694 "Parser.y"
+    { REQUIRE_LONG((yyvsp[(1) - (3)].node)); REQUIRE_LONG((yyvsp[(3) - (3)].node)); (yyval.node) = CreateBinaryOperation(BAND, (yyvsp[(1) - (3)].node), (yyvsp[(3) - (3)].node)); }
+#line 2373 "Parser.c"
     break;
 
-  case 26: /* BxorExpression: BxorExpression "^" BandExpression  */
-  { (yyval.node) = CreateBinaryOperation(BXOR, (yyvsp[-2].node), (yyvsp[0].node)); }
+  case 55: /* ShrExpression: AddExpression */
+#line 697 "Parser.y"
+    { (yyval.node) = (yyvsp[(1) - (1)].node); }
+#line 2379 "Parser.c"
     break;
 
-  case 27: /* BandExpression: ShrExpression  */
-                   { (yyval.node) = (yyvsp[0].node); }
+  case 56: /* ShrExpression: ShrExpression ShrOperator AddExpression */
+#line 699 "Parser.y"
+    { REQUIRE_LONG((yyvsp[(1) - (3)].node)); REQUIRE_LONG((yyvsp[(3) - (3)].node)); (yyval.node) = CreateBinaryOperation((yyvsp[(2) - (3)].opcode), (yyvsp[(1) - (3)].node), (yyvsp[(3) - (3)].node)); }
+#line 2385 "Parser.c"
     break;
 
-  case 28: /* BandExpression: BandExpression "&" ShrExpression  */
-  { (yyval.node) = CreateBinaryOperation(BAND, (yyvsp[-2].node), (yyvsp[0].node)); }
+  case 57: /* ShrOperator: TOKEN_SHR */
+#line 702 "Parser.y"
+    { (yyval.opcode) = BSHR; }
+#line 2391 "Parser.c"
     break;
 
-  case 29: /* ShrExpression: AddExpression  */
-                    { (yyval.node) = (yyvsp[0].node); }
+  case 58: /* ShrOperator: TOKEN_SHL */
+#line 703 "Parser.y"
+    { (yyval.opcode) = BSHL; }
+#line 2397 "Parser.c"
     break;
 
-  case 30: /* ShrExpression: ShrExpression ShrOperator AddExpression  */
-  { (yyval.node) = CreateBinaryOperation((yyvsp[-1].opcode), (yyvsp[-2].node), (yyvsp[0].node)); }
+  case 59: /* ShrOperator: TOKEN_LSHR */
+#line 704 "Parser.y"
+    { (yyval.opcode) = BLSHR; }
+#line 2403 "Parser.c"
     break;
 
-  case 31: /* ShrOperator: ">>"  */
-            { (yyval.opcode) = BSHR; }
+  case 60: /* AddExpression: MulExpression */
+#line 707 "Parser.y"
+    { (yyval.node) = (yyvsp[(1) - (1)].node); }
+#line 2409 "Parser.c"
     break;
 
-  case 32: /* ShrOperator: "<<"  */
-            { (yyval.opcode) = BSHL; }
+  case 61: /* AddExpression: AddExpression AddOperator MulExpression */
+#line 708 "Parser.y"
+    {
+  REQUIRE_LONG((yyvsp[(1) - (3)].node));
+  REQUIRE_LONG((yyvsp[(3) - (3)].node));
+  (yyval.node) = CreateAddOperation((yyvsp[(2) - (3)].opcode), (yyvsp[(1) - (3)].node), (yyvsp[(3) - (3)].node));
+  if ((yyval.node) == NULL) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tinvalid operands: addition of two pointers\n");
+    (*errorCount)++;
+    YYABORT;
+  }
+}
+#line 2425 "Parser.c"
     break;
 
-  case 33: /* AddExpression: MulExpression  */
-                   { (yyval.node) = (yyvsp[0].node); }
+  case 62: /* AddOperator: TOKEN_PLUS */
+#line 721 "Parser.y"
+    { (yyval.opcode) = ADD; }
+#line 2431 "Parser.c"
     break;
 
-  case 34: /* AddExpression: AddExpression AddOperator MulExpression  */
-  { (yyval.node) = CreateBinaryOperation((yyvsp[-1].opcode), (yyvsp[-2].node), (yyvsp[0].node)); }
+  case 63: /* AddOperator: TOKEN_MINUS */
+#line 722 "Parser.y"
+    { (yyval.opcode) = SUBTRACT; }
+#line 2437 "Parser.c"
     break;
 
-  case 35: /* AddOperator: "+"  */
-             { (yyval.opcode) = ADD; }
+  case 64: /* MulExpression: UnaryExpression */
+#line 725 "Parser.y"
+    { (yyval.node) = (yyvsp[(1) - (1)].node); }
+#line 2443 "Parser.c"
     break;
 
-  case 36: /* AddOperator: "-"  */
-              { (yyval.opcode) = SUBTRACT; }
+  case 65: /* MulExpression: MulExpression MulOperator UnaryExpression */
+#line 727 "Parser.y"
+    { REQUIRE_LONG((yyvsp[(1) - (3)].node)); REQUIRE_LONG((yyvsp[(3) - (3)].node)); (yyval.node) = CreateBinaryOperation((yyvsp[(2) - (3)].opcode), (yyvsp[(1) - (3)].node), (yyvsp[(3) - (3)].node)); }
+#line 2449 "Parser.c"
     break;
 
-  case 37: /* MulExpression: UnaryExpression  */
-                      { (yyval.node) = (yyvsp[0].node); }
+  case 66: /* MulOperator: TOKEN_STAR */
+#line 730 "Parser.y"
+    { (yyval.opcode) = MULTIPLY; }
+#line 2455 "Parser.c"
     break;
 
-  case 38: /* MulExpression: MulExpression MulOperator UnaryExpression  */
-  { (yyval.node) = CreateBinaryOperation((yyvsp[-1].opcode), (yyvsp[-2].node), (yyvsp[0].node)); }
+  case 67: /* MulOperator: TOKEN_DIV */
+#line 731 "Parser.y"
+    { (yyval.opcode) = DIVIDE; }
+#line 2461 "Parser.c"
     break;
 
-  case 39: /* MulOperator: "*"  */
-             { (yyval.opcode) = MULTIPLY; }
+  case 68: /* UnaryExpression: Operand */
+#line 734 "Parser.y"
+    {
+  (yyval.node) = (yyvsp[(1) - (1)].node);
+}
+#line 2469 "Parser.c"
     break;
 
-  case 40: /* MulOperator: "/"  */
-            { (yyval.opcode) = DIVIDE; }
+  case 69: /* UnaryExpression: UnaryOperator Operand */
+#line 737 "Parser.y"
+    {
+  REQUIRE_LONG((yyvsp[(2) - (2)].node));
+  (yyval.node) = CreateUnaryOperation((yyvsp[(1) - (2)].opcode), (yyvsp[(2) - (2)].node));
+}
+#line 2478 "Parser.c"
     break;
 
-  case 41: /* UnaryExpression: Operand  */
-           {
-  (yyval.node) = (yyvsp[0].node);
+  case 70: /* UnaryExpression: TOKEN_STAR Operand */
+#line 741 "Parser.y"
+    {
+  if (!IsPointer((yyvsp[(2) - (2)].node))) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tdereference of a value of type 'long', expected 'long*'\n");
+    (*errorCount)++;
+    YYABORT;
+  }
+  (yyval.node) = CreateUnaryOperation(DEREF, (yyvsp[(2) - (2)].node));
 }
+#line 2492 "Parser.c"
     break;
 
-  case 42: /* UnaryExpression: UnaryOperator Operand  */
-                               {
-  (yyval.node) = CreateUnaryOperation((yyvsp[-1].opcode), (yyvsp[0].node));
+  case 71: /* UnaryExpression: TOKEN_BAND Operand */
+#line 750 "Parser.y"
+    {
+  REQUIRE_LONG((yyvsp[(2) - (2)].node));
+  // Only locals and parameters live in memory
+  if ((yyvsp[(2) - (2)].node)->exprCode != VARIABLE || (yyvsp[(2) - (2)].node)->left->stmtCode == CONSTDECL) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tcan only take the address of a variable\n");
+    (*errorCount)++;
+    YYABORT;
+  }
+  (yyval.node) = CreateUnaryOperation(ADDRESSOF, (yyvsp[(2) - (2)].node));
 }
+#line 2508 "Parser.c"
+    break;
+
+  case 72: /* UnaryOperator: TOKEN_MINUS */
+#line 763 "Parser.y"
+    { (yyval.opcode) = NEGATE; }
+#line 2514 "Parser.c"
     break;
 
-  case 43: /* UnaryOperator: "-"  */
-              { (yyval.opcode) = NEGATE; }
+  case 73: /* UnaryOperator: TOKEN_BNOT */
+#line 764 "Parser.y"
+    { (yyval.opcode) = BNOT; }
+#line 2520 "Parser.c"
     break;
 
-  case 44: /* Operand: "constant value"  */
-             {
-  (yyval.node) = CreateNumber((yyvsp[0].value));
+  case 74: /* Operand: TOKEN_NUMBER */
+#line 767 "Parser.y"
+    {
+  (yyval.node) = CreateNumber((yyvsp[(1) - (1)].value));
 }
+#line 2528 "Parser.c"
     break;
 
-  case 45: /* Operand: "variable name"  */
-              {
+  case 75: /* Operand: TOKEN_ID */
+#line 770 "Parser.y"
+    {
   // Must make sure that rvalue variable exists
-  list_t* exists = Lookup(symtable, (yyvsp[0].str));
+  list_t* exists = Lookup(symtable, (yyvsp[(1) - (1)].str));
   if (!exists) {
     PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
-    fprintf(stderr, "\tuse of undeclared identifier '%s'\n", (yyvsp[0].str));
+    fprintf(stderr, "\tuse of undeclared identifier '%s'\n", (yyvsp[(1) - (1)].str));
     (*errorCount)++;
     YYABORT;
   } else if (exists->st_type == FUNCTION_TYPE) {
     PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
-    fprintf(stderr, "\tinvalid usage: function '%s' used like a variable\n", (yyvsp[0].str));
+    fprintf(stderr, "\tinvalid usage: function '%s' used like a variable\n", (yyvsp[(1) - (1)].str));
+    (*errorCount)++;
+    YYABORT;
+  }
+  // A whole struct variable refers to its type
+  (yyval.node) = CreateVariable((yyvsp[(1) - (1)].str), exists->st_type == STRUCT_VAR ? exists->node->left : exists->node);
+}
+#line 2550 "Parser.c"
+    break;
+
+  case 76: /* Operand: TOKEN_ID TOKEN_DOT TOKEN_ID */
+#line 787 "Parser.y"
+    {
+  char* name = FieldName((yyvsp[(1) - (3)].str), (yyvsp[(3) - (3)].str));
+  list_t* exists = Lookup(symtable, name);
+  if (!exists) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\t'%s' has no member named '%s'\n", (yyvsp[(1) - (3)].str), (yyvsp[(3) - (3)].str));
     (*errorCount)++;
     YYABORT;
   }
-  (yyval.node) = CreateVariable((yyvsp[0].str), exists->node);
+  free((yyvsp[(1) - (3)].str));
+  free((yyvsp[(3) - (3)].str));
+  (yyval.node) = CreateVariable(name, exists->node);
 }
+#line 2568 "Parser.c"
     break;
 
-  case 46: /* Operand: "(" BorExpression ")"  */
-                           {
-  (yyval.node) = (yyvsp[-1].node);
+  case 77: /* Operand: TOKEN_LPAREN BorExpression TOKEN_RPAREN */
+#line 800 "Parser.y"
+    {
+  (yyval.node) = (yyvsp[(2) - (3)].node);
 }
+#line 2576 "Parser.c"
     break;
 
-  case 47: /* Operand: FunctionCall  */
-                   {
-  (yyval.node) = (yyvsp[0].node);
+  case 78: /* Operand: FunctionCall */
+#line 803 "Parser.y"
+    {
+  (yyval.node) = (yyvsp[(1) - (1)].node);
 }
+#line 2584 "Parser.c"
     break;
 
-  case 48: /* @4: %empty  */
-            {
+  case 79: /* $@6: %empty */
+#line 808 "Parser.y"
+    {
   // Check if the called function is declared.
-  list_t* exists = Lookup(symtable, (yyvsp[0].str));
+  list_t* exists = Lookup(symtable, (yyvsp[(1) - (1)].str));
   if (!exists) {
     PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
-    fprintf(stderr, "use of undeclared function '%s'\n", (yyvsp[0].str));
+    fprintf(stderr, "use of undeclared function '%s'\n", (yyvsp[(1) - (1)].str));
     (*errorCount)++;
     YYABORT;
-  } else if (exists->st_type == LONG_TYPE) {
+  } else if (exists->st_type != FUNCTION_TYPE) {
     PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
-    fprintf(stderr, "invalid usage: variable '%s' of type 'long' used like a function\n", (yyvsp[0].str));
+    fprintf(stderr, "invalid usage: variable '%s' used like a function\n", (yyvsp[(1) - (1)].str));
     (*errorCount)++;
     YYABORT;
   }
-  // Also, prevent recursive call please.
-  else if (exists->node->statements == NULL) {
+}
+#line 2604 "Parser.c"
+    break;
+
+  case 80: /* $@7: %empty */
+#line 822 "Parser.y"
+    {
+  // Struct arguments are passed field by field
+  if (!ExpandStructArgs(symtable, (yyvsp[(4) - (4)].nodelist))) {
     PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
-    fprintf(stderr, "use of the function '%s' inside its own declaration.\nRecursive function not supported.\n", (yyvsp[0].str));
+    fprintf(stderr, "a struct returned by a call must be stored in a variable before it is passed\n");
     (*errorCount)++;
     YYABORT;
   }
-  (yyval.symel) = exists;
-}
-    break;
-
-  case 49: /* $@5: %empty  */
-                                       {
-  // Must make sure that function name exists
-  int paramCount = NodeListLen((yyvsp[-2].symel)->node->arguments);
-  int argCount = NodeListLen((yyvsp[0].nodelist));
-  if (paramCount != argCount){
+  // Pick the overload that takes as many parameters as there are arguments
+  int argCount = NodeListLen((yyvsp[(4) - (4)].nodelist));
+  list_t* callee = LookupFunction(symtable, (yyvsp[(1) - (4)].str), argCount);
+  if (!callee) {
     PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
-    fprintf(stderr, "mismatching argument count to function call '%s', expected %d, have %d\n",
-           (yyvsp[-3].str), paramCount, argCount);
+    fprintf(stderr, "no declaration of function '%s' takes %d arguments\n", (yyvsp[(1) - (4)].str), argCount);
     (*errorCount)++;
     YYABORT;
   }
+  (yyval.node) = callee->node;
 }
+#line 2628 "Parser.c"
     break;
 
-  case 50: /* FunctionCall: "variable name" @4 "(" CallParams $@5 ")"  */
-               {
-  free((yyvsp[-5].str));
-  (yyval.node) = CreateFunctionCall((yyvsp[-4].symel)->node, (yyvsp[-2].nodelist));
+  case 81: /* FunctionCall: TOKEN_ID $@6 TOKEN_LPAREN CallParams $@7 TOKEN_RPAREN */
+#line 840 "Parser.y"
+    {
+  free((yyvsp[(1) - (6)].str));
+  (yyval.node) = CreateFunctionCall((yyvsp[(5) - (6)].node), (yyvsp[(4) - (6)].nodelist));
 }
+#line 2637 "Parser.c"
     break;
 
-  case 51: /* CallParams: CallParam  */
-              {
-  (yyval.nodelist) = (yyvsp[0].nodelist);
+  case 82: /* CallParams: CallParam */
+#line 846 "Parser.y"
+    {
+  (yyval.nodelist) = (yyvsp[(1) - (1)].nodelist);
 }
+#line 2645 "Parser.c"
     break;
 
-  case 52: /* CallParams: %empty  */
-  {
+  case 83: /* CallParams: %empty */
+#line 849 "Parser.y"
+    {
   (yyval.nodelist) = NULL;
 }
+#line 2653 "Parser.c"
     break;
 
-  case 53: /* CallParam: Expression  */
-              {
-  (yyval.nodelist) = CreateNodeList((yyvsp[0].node), NULL);
+  case 84: /* CallParam: Expression */
+#line 854 "Parser.y"
+    {
+  (yyval.nodelist) = CreateNodeList((yyvsp[(1) - (1)].node), NULL);
 }
+#line 2661 "Parser.c"
     break;
 
-  case 54: /* CallParam: CallParam "," Expression  */
-                                          {
-  NodeList* last = (yyvsp[-2].nodelist);
+  case 85: /* CallParam: CallParam TOKEN_COMMA Expression */
+#line 857 "Parser.y"
+    {
+  NodeList* last = (yyvsp[(1) - (3)].nodelist);
   while (last->next != NULL) last = last->next;
-  NodeList* newNode = CreateNodeList((yyvsp[0].node), NULL);
+  NodeList* newNode = CreateNodeList((yyvsp[(3) - (3)].node), NULL);
   last->next = newNode;
-  (yyval.nodelist) = (yyvsp[-2].nodelist);
+  (yyval.nodelist) = (yyvsp[(1) - (3)].nodelist);
 }
+#line 2673 "Parser.c"
     break;
 
 
-
       default: break;
     }
-  /* User semantic actions sometimes alter yychar, and that requires
-     that yytoken be updated with the new translation.  We take the
-     approach of translating immediately before every use of yytoken.
-     One alternative is translating here after every semantic action,
-     but that translation would be missed if the semantic action invokes
-     YYABORT, YYACCEPT, or YYERROR immediately after altering yychar or
-     if it invokes YYBACKUP.  In the case of YYABORT or YYACCEPT, an
-     incorrect destructor might then be invoked immediately.  In the
-     case of YYERROR or YYBACKUP, subsequent parser actions might lead
-     to an incorrect destructor call or verbose syntax error message
-     before the lookahead is translated.  */
-  YY_SYMBOL_PRINT ("-> $$ =", YY_CAST (yysymbol_kind_t, yyr1[yyn]), &yyval, &yyloc);
-
-  YYPOPSTACK (yylen);
-  yylen = 0;
 
+  yyssp -= yylen;
+  yyvsp -= yylen;
+  yylsp -= yylen;
   *++yyvsp = yyval;
   *++yylsp = yyloc;
 
-  /* Now 'shift' the result of the reduction.  Determine what state
-     that goes to, based on the state we popped back to and the rule
-     number reduced by.  */
-  {
-    const int yylhs = yyr1[yyn] - YYNTOKENS;
-    const int yyi = yypgoto[yylhs] + *yyssp;
-    yystate = (0 <= yyi && yyi <= YYLAST && yycheck[yyi] == *yyssp
-               ? yytable[yyi]
-               : yydefgoto[yylhs]);
-  }
-
+  /* Go to the state for the nonterminal on top of the uncovered state.  */
+  yystate = yygoto[*yyssp * YYNNTS + yyr1[yyn]];
+  *++yyssp = yystate;
   goto yynewstate;
 
-
-/*--------------------------------------.
-| yyerrlab -- here on detecting error.  |
-`--------------------------------------*/
 yyerrlab:
-  /* Make sure we have latest lookahead translation.  See comments at
-     user semantic actions for why this is necessary.  */
-  yytoken = yychar == YYEMPTY ? YYSYMBOL_YYEMPTY : YYTRANSLATE (yychar);
-  /* If not already recovering from an error, report this error.  */
-  if (!yyerrstatus)
-    {
-      ++yynerrs;
-      {
-        yypcontext_t yyctx
-          = {yyssp, yytoken, &yylloc};
-        char const *yymsgp = YY_("syntax error");
-        int yysyntax_error_status;
-        yysyntax_error_status = yysyntax_error (&yymsg_alloc, &yymsg, &yyctx);
-        if (yysyntax_error_status == 0)
-          yymsgp = yymsg;
-        else if (yysyntax_error_status == -1)
-          {
-            if (yymsg != yymsgbuf)
-              YYSTACK_FREE (yymsg);
-            yymsg = YY_CAST (char *,
-                             YYSTACK_ALLOC (YY_CAST (YYSIZE_T, yymsg_alloc)));
-            if (yymsg)
-              {
-                yysyntax_error_status
-                  = yysyntax_error (&yymsg_alloc, &yymsg, &yyctx);
-                yymsgp = yymsg;
-              }
-            else
-              {
-                yymsg = yymsgbuf;
-                yymsg_alloc = sizeof yymsgbuf;
-                yysyntax_error_status = YYENOMEM;
-              }
-          }
-        yyerror (&yylloc, funcdecls, errorCount, symtable, scanner, yymsgp);
-        if (yysyntax_error_status == YYENOMEM)
-          goto yyexhaustedlab;
-      }
-    }
-
-  yyerror_range[1] = yylloc;
-  if (yyerrstatus == 3)
-    {
-      /* If just tried and failed to reuse lookahead token after an
-         error, discard it.  */
-
-      if (yychar <= YYEOF)
-        {
-          /* Return failure if at end of input.  */
-          if (yychar == YYEOF)
-            YYABORT;
-        }
-      else
-        {
-          yydestruct ("Error: discarding",
-                      yytoken, &yylval, &yylloc, funcdecls, errorCount, symtable, scanner);
-          yychar = YYEMPTY;
-        }
-    }
-
-  /* Else will try to reuse lookahead token after shifting the error
-     token.  */
-  goto yyerrlab1;
-
-
-/*---------------------------------------------------.
-| yyerrorlab -- error raised explicitly by YYERROR.  |
-`---------------------------------------------------*/
-yyerrorlab:
-  /* Pacify compilers when the user code never invokes YYERROR and the
-     label yyerrorlab therefore never appears in user code.  */
-  if (0)
-    YYERROR;
-
-  /* Do not reclaim the symbols of the rule whose action triggered
-     this YYERROR.  */
-  YYPOPSTACK (yylen);
-  yylen = 0;
-  YY_STACK_PRINT (yyss, yyssp);
-  yystate = *yyssp;
-  goto yyerrlab1;
-
-
-/*-------------------------------------------------------------.
-| yyerrlab1 -- common code for both syntax error and YYERROR.  |
-`-------------------------------------------------------------*/
-yyerrlab1:
-  yyerrstatus = 3;      /* Each real token shifted decrements this.  */
-
-  /* Pop stack until we find a state that shifts the error token.  */
-  for (;;)
-    {
-      yyn = yypact[yystate];
-      if (!yypact_value_is_default (yyn))
-        {
-          yyn += YYSYMBOL_YYerror;
-          if (0 <= yyn && yyn <= YYLAST && yycheck[yyn] == YYSYMBOL_YYerror)
-            {
-              yyn = yytable[yyn];
-              if (0 < yyn)
-                break;
-            }
-        }
-
-      /* Pop the current state because it cannot handle the error token.  */
-      if (yyssp == yyss)
-        YYABORT;
-
-      yyerror_range[1] = *yylsp;
-      yydestruct ("Error: popping",
-                  YY_ACCESSING_SYMBOL (yystate), yyvsp, yylsp, funcdecls, errorCount, symtable, scanner);
-      YYPOPSTACK (1);
-      yystate = *yyssp;
-      YY_STACK_PRINT (yyss, yyssp);
-    }
-
-  YY_IGNORE_MAYBE_UNINITIALIZED_BEGIN
-  *++yyvsp = yylval;
-  YY_IGNORE_MAYBE_UNINITIALIZED_END
+  yysyntax_error (yymsg, yystate, yytoken);
+  yyerror (&yylloc, funcdecls, errorCount, symtable, scanner, yymsg);
+  /* The grammar has no error rules, so there is nothing to recover to.  */
+  goto yyabortlab;
 
-  yyerror_range[2] = yylloc;
-  ++yylsp;
-  YYLLOC_DEFAULT (*yylsp, yyerror_range, 2);
-
-  /* Shift the error token.  */
-  YY_SYMBOL_PRINT ("Shifting", YY_ACCESSING_SYMBOL (yyn), yyvsp, yylsp);
-
-  yystate = yyn;
-  goto yynewstate;
-
-
-/*-------------------------------------.
-| yyacceptlab -- YYACCEPT comes here.  |
-`-------------------------------------*/
 yyacceptlab:
   yyresult = 0;
   goto yyreturn;
 
-
-/*-----------------------------------.
-| yyabortlab -- YYABORT comes here.  |
-`-----------------------------------*/
 yyabortlab:
   yyresult = 1;
   goto yyreturn;
 
-
-#if 1
-/*-------------------------------------------------.
-| yyexhaustedlab -- memory exhaustion comes here.  |
-`-------------------------------------------------*/
 yyexhaustedlab:
-  yyerror (&yylloc, funcdecls, errorCount, symtable, scanner, YY_("memory exhausted"));
+  yyerror (&yylloc, funcdecls, errorCount, symtable, scanner, "memory exhausted");
   yyresult = 2;
-  goto yyreturn;
-#endif
-
 
-/*-------------------------------------------------------.
-| yyreturn -- parsing is finished, clean up and return.  |
-`-------------------------------------------------------*/
 yyreturn:
-  if (yychar != YYEMPTY)
-    {
-      /* Make sure we have latest lookahead translation.  See comments at
-         user semantic actions for why this is necessary.  */
-      yytoken = YYTRANSLATE (yychar);
-      yydestruct ("Cleanup: discarding lookahead",
-                  yytoken, &yylval, &yylloc, funcdecls, errorCount, symtable, scanner);
-    }
-  /* Do not reclaim the symbols of the rule whose action triggered
-     this YYABORT or YYACCEPT.  */
-  YYPOPSTACK (yylen);
-  YY_STACK_PRINT (yyss, yyssp);
-  while (yyssp != yyss)
-    {
-      yydestruct ("Cleanup: popping",
-                  YY_ACCESSING_SYMBOL (+*yyssp), yyvsp, yylsp, funcdecls, errorCount, symtable, scanner);
-      YYPOPSTACK (1);
-    }
-#ifndef yyoverflow
-  if (yyss != yyssa)
-    YYSTACK_FREE (yyss);
-#endif
-  if (yymsg != yymsgbuf)
-    YYSTACK_FREE (yymsg);
+  free (yyss);
+  free (yyvs);
+  free (yyls);
   return yyresult;
 }
-
-
@@ -0,0 +1,866 @@
+%{
+#define YYDEBUG 1
+#define YYERROR_VERBOSE 1
+
+#include "Expression.h"
+#include "symtab.h"
+#include "Parser.h"
+#include "Lexer.h"
+#include <stdlib.h>
+#include <string.h>
+
+  int yyerror(YYLTYPE *yylloc, NodeList** funcdecls, int* errorCount,
+              SymTable* symtable, yyscan_t scanner, const char *msg) {
+    (*errorCount)++;
+    fprintf(stderr, "Error: (line %d:%d) near the token '%s'\n", yylloc->first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\t%s\n", msg);
+    return 1;
+  }
+
+  void PrintLocationInfo(unsigned long fl, unsigned long len, char* token) {
+    fprintf(stderr, "Error: (line %ld:%ld) near the token '%s'\n", fl, len, token);
+  }
+
+  // Whether an expression has type long*. A variable knows it from its declaration.
+  int IsPointer(Node* expr) {
+    if (expr->exprCode == VARIABLE) return expr->left->value == DECL_POINTER;
+    if (expr->opCode == ADDRESSOF) return 1;
+    if (expr->opCode == ADD) return IsPointer(expr->left) || IsPointer(expr->right);
+    if (expr->opCode == SUBTRACT) return IsPointer(expr->left) && !IsPointer(expr->right);
+    return 0;
+  }
+
+  // Multiply an index by the size of a long
+  Node* ScaleIndex(Node* index) {
+    if (index->exprCode == CONSTANT) {
+      index->value *= 8;
+      return index;
+    }
+    return CreateBinaryOperation(MULTIPLY, index, CreateNumber(8));
+  }
+
+  /* Build "left op right" for + and -, scaling the integer side when the other
+     side is a pointer, so p + 1 is the next long. The difference of two
+     pointers counts longs. Returns NULL for the sum of two pointers. */
+  Node* CreateAddOperation(OpType op, Node* left, Node* right) {
+    int leftPointer = IsPointer(left);
+    int rightPointer = IsPointer(right);
+    if (leftPointer && rightPointer) {
+      if (op == ADD) return NULL;
+      return CreateBinaryOperation(DIVIDE, CreateBinaryOperation(SUBTRACT, left, right),
+                                   CreateNumber(8));
+    }
+    if (leftPointer) right = ScaleIndex(right);
+    if (rightPointer) left = ScaleIndex(left);
+    return CreateBinaryOperation(op, left, right);
+  }
+
+  // The symbol table name of a struct type, e.g. "struct Point". It can not
+  // clash with a variable or a function, since it has a blank in it.
+  char* StructTag(char* name) {
+    char* tag = malloc(strlen(name) + 8);
+    sprintf(tag, "struct %s", name);
+    return tag;
+  }
+
+  // The name of the variable holding one field of a struct variable, e.g. "p.x"
+  char* FieldName(char* var, char* field) {
+    char* name = malloc(strlen(var) + strlen(field) + 2);
+    sprintf(name, "%s.%s", var, field);
+    return name;
+  }
+
+  // A use of one field of a struct variable
+  Node* FieldVariable(SymTable* symtable, char* var, Node* field) {
+    char* name = FieldName(var, field->name);
+    return CreateVariable(name, Lookup(symtable, name)->node);
+  }
+
+  // The struct type of a struct value, or NULL for a long. A struct variable
+  // refers to its type, and a call to a function that may return one.
+  Node* StructOf(Node* expr) {
+    if (expr->exprCode == VARIABLE && expr->left->type == STRUCTDECL) return expr->left;
+    if (expr->opCode == FUNCTIONCALL) return expr->left->left;
+    return NULL;
+  }
+
+  const char* TypeName(Node* type) {
+    return type ? type->name : "long";
+  }
+
+  /* Split "dest = src" in one assignment per field of the struct type.
+     src is a struct variable, a call returning the struct, or NULL to zero
+     the fields. A declaration adds the fields to the symbol table, otherwise
+     they now refer to the new assignments. */
+  NodeList* AssignStruct(SymTable* symtable, char* dest, Node* type, Node* src, int declare) {
+    NodeList* head = NULL;
+    NodeList** tail = &head;
+    Node* low = NULL;
+    int index = 0;
+    for (NodeList* field = type->arguments; field != NULL; field = field->next, index++) {
+      Node* value;
+      if (src == NULL) value = CreateNumber(0);
+      else if (src->exprCode == VARIABLE) value = FieldVariable(symtable, src->name, field->node);
+      else if (index == 0) value = src;
+      // the call returned the second field in %rdx, tied to the first one
+      else value = CreateUnaryOperation(RESULTHIGH, CreateVariable(strdup(low->name), low));
+
+      Node* temp = CreateAssign(FieldName(dest, field->node->name), value);
+      temp->value = field->node->value;
+      if (index == 0) low = temp;
+      if (declare) Insert(symtable, temp, LONG_TYPE);
+      else Lookup(symtable, temp->name)->node = temp;
+      *tail = CreateNodeList(temp, NULL);
+      tail = &(*tail)->next;
+    }
+    if (src != NULL && src->exprCode == VARIABLE) FreeVariable(src);
+    return head;
+  }
+
+  /* Replace every struct variable among the arguments of a call by its
+     fields, which is how the callee receives it. Returns 0 if a struct comes
+     straight from another call, since only %rax is free to hold it. */
+  int ExpandStructArgs(SymTable* symtable, NodeList* args) {
+    for (NodeList* nl = args; nl != NULL; nl = nl->next) {
+      Node* type = StructOf(nl->node);
+      if (type == NULL) continue;
+      if (nl->node->exprCode != VARIABLE) return 0;
+
+      Node* var = nl->node;
+      NodeList* rest = nl->next;
+      nl->node = FieldVariable(symtable, var->name, type->arguments->node);
+      for (NodeList* field = type->arguments->next; field != NULL; field = field->next) {
+        nl->next = CreateNodeList(FieldVariable(symtable, var->name, field->node), NULL);
+        nl = nl->next;
+      }
+      nl->next = rest;
+      FreeVariable(var);
+    }
+    return 1;
+  }
+
+  // Struct values can only be assigned, passed and returned
+  #define REQUIRE_LONG(expr) \
+    if (StructOf(expr)) { \
+      PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner)); \
+      fprintf(stderr, "\tinvalid use of a value of type '%s'\n", StructOf(expr)->name); \
+      (*errorCount)++; \
+      YYABORT; \
+    }
+%}
+
+%code requires {
+  typedef void* yyscan_t;
+}
+
+%output "Parser.c"
+%defines "Parser.h"
+
+%define api.pure
+%locations
+%lex-param { yyscan_t scanner }
+%parse-param { NodeList** funcdecls }
+%parse-param { int* errorCount }
+%parse-param { SymTable* symtable }
+%parse-param { yyscan_t scanner }
+
+%union {
+    long value;
+    char* str;
+    Node* node;
+    NodeList* nodelist;
+    OpType opcode;
+    list_t* symel;
+}
+
+%token TOKEN_TYPE_LONG     "long"
+%token TOKEN_RETURN        "return"
+%token TOKEN_CONST         "const"
+%token TOKEN_ASSERT        "assert"
+%token TOKEN_STATIC        "static"
+%token TOKEN_STRUCT        "struct"
+%token TOKEN_SEMI          ";"
+%token TOKEN_COMMA         ","
+%token TOKEN_DOT           "."
+%token TOKEN_LPAREN        "("
+%token TOKEN_RPAREN        ")"
+%token TOKEN_LBRACE        "{"
+%token TOKEN_RBRACE        "}"
+%token TOKEN_PLUS          "+"
+%token TOKEN_MINUS         "-"
+%token TOKEN_STAR          "*"
+%token TOKEN_DIV           "/"
+%token TOKEN_ASSIGN        "="
+%token TOKEN_BOR           "|"
+%token TOKEN_BAND          "&"
+%token TOKEN_BXOR          "^"
+%token TOKEN_BNOT          "~"
+%token TOKEN_SHR           ">>"
+%token TOKEN_SHL           "<<"
+%token TOKEN_LSHR          ">>>"
+%token <value> TOKEN_NUMBER "constant value"
+%token <str> TOKEN_ID      "variable name"
+
+%type <nodelist> FunctionDecls ParametersOptional Parameters Parameter FunctionDeclTail
+%type <nodelist> Statements Statement Assignment CallParams CallParam Fields
+%type <node> TopLevelDecl FunctionDecl FunctionHead ConstDecl StructType ReturnType Field
+%type <node> Expression BorExpression BxorExpression BandExpression ShrExpression
+%type <node> AddExpression MulExpression UnaryExpression Operand FunctionCall
+%type <opcode> ShrOperator AddOperator MulOperator UnaryOperator
+%type <value> Linkage VarType
+%type <str> StructName
+
+%start Program
+
+%%
+
+Program : FunctionDecls {
+  *funcdecls = $1;
+}
+| %empty {
+  *funcdecls = NULL;
+}
+;
+
+FunctionDecls : TopLevelDecl {
+  // A struct declaration leaves nothing in the tree
+  $$ = $1 ? CreateNodeList($1, NULL) : NULL;
+}
+| FunctionDecls TopLevelDecl {
+  if ($2 == NULL || $1 == NULL) {
+    $$ = $1 ? $1 : ($2 ? CreateNodeList($2, NULL) : NULL);
+  } else {
+    // Find last node in PS
+    NodeList* last = $1;
+    while (last->next != NULL) last = last->next;
+    NodeList* newNodeList = CreateNodeList($2, NULL);
+    last->next = newNodeList;
+    $$ = $1;
+  }
+}
+;
+
+TopLevelDecl : FunctionDecl {
+  $$ = $1;
+}
+| ConstDecl {
+  $$ = $1;
+}
+| StructDecl {
+  $$ = NULL;
+}
+;
+
+/* A function returning a struct also starts with "struct" and a name, so
+   both go through Linkage before the parser can tell them apart. */
+StructDecl : Linkage StructName "{" Fields "}" ";" {
+  char* tag = StructTag($2);
+  if ($1 == LINKAGE_STATIC || LookupScope(symtable, tag)) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, $1 == LINKAGE_STATIC ? "\t'static' is not allowed on '%s'\n" :
+                                          "\tredefinition of '%s'\n", tag);
+    (*errorCount)++;
+    YYABORT;
+  }
+  free($2);
+  Insert(symtable, CreateStructType(tag, $4), STRUCT_TYPE);
+}
+;
+
+StructName : "struct" "variable name" {
+  $$ = $2;
+}
+;
+
+Fields : Field {
+  $$ = CreateNodeList($1, NULL);
+}
+| Fields Field {
+  // Find last node in PS, making sure the field name is new
+  NodeList* last = $1;
+  for (NodeList* nl = $1; nl != NULL; nl = nl->next) {
+    if (strcmp(nl->node->name, $2->name) == 0) {
+      PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+      fprintf(stderr, "\tduplicate member '%s'\n", $2->name);
+      (*errorCount)++;
+      YYABORT;
+    }
+    last = nl;
+  }
+  last->next = CreateNodeList($2, NULL);
+  $$ = $1;
+}
+;
+
+Field : VarType "variable name" ";" {
+  Node* temp = CreateParameter($2);
+  temp->value = $1;
+  $$ = temp;
+}
+;
+
+StructType : StructName {
+  char* tag = StructTag($1);
+  list_t* exists = Lookup(symtable, tag);
+  free(tag);
+  if (!exists) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tunknown type 'struct %s'\n", $1);
+    (*errorCount)++;
+    YYABORT;
+  }
+  free($1);
+  $$ = exists->node;
+}
+;
+
+FunctionDecl : FunctionHead "{" {
+  // Only one definition per overload. From here on, calls (including
+  // recursive ones) resolve to the definition instead of a prototype.
+  list_t* entry = LookupFunction(symtable, $1->name, NodeListLen($1->arguments));
+  if (entry->node != $1) {
+    if (entry->node->statements != NULL) {
+      PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+      fprintf(stderr, "\tredefinition of '%s' with %d parameters\n",
+              $1->name, NodeListLen($1->arguments));
+      (*errorCount)++;
+      YYABORT;
+    }
+    entry->node = $1;
+  }
+} FunctionDeclTail {
+  $1->statements = $4;
+  ClearSymTable(symtable);
+  DecrScope(symtable);
+  $$ = $1;
+}
+| FunctionHead ";" {
+  // A prototype, so the function can be called before it is defined,
+  // or from another object file when it is never defined here
+  ClearSymTable(symtable);
+  DecrScope(symtable);
+  $$ = $1;
+}
+;
+
+FunctionHead : Linkage ReturnType "variable name" {
+  // First add the function scope
+  // But before that, make sure the name is not already taken by something else.
+  // Functions may share a name as long as their parameter counts differ.
+  list_t* exists = LookupScope(symtable, $3);
+  if (exists && exists->st_type != FUNCTION_TYPE) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tredefinition of '%s'\n", $3);
+    (*errorCount)++;
+    YYABORT;
+  }
+  Node* temp = CreateFunctionDecl($3, NULL, NULL);
+  temp->value = $1;
+  temp->left = $2;
+  // Then, increase the scope
+  IncrScope(symtable);
+  $<node>$ = temp;
+} "(" ParametersOptional ")" {
+  // The parameter count tells which overload this declares. It is known
+  // before the body, so recursive calls can be checked.
+  $<node>4->arguments = $6;
+  list_t* entry = LookupFunction(symtable, $3, NodeListLen($6));
+  if (!entry) {
+    DecrScope(symtable);
+    Insert(symtable, $<node>4, FUNCTION_TYPE);
+    IncrScope(symtable);
+  }
+  else if (entry->node->left != $2) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tconflicting return types for '%s'\n", $3);
+    (*errorCount)++;
+    YYABORT;
+  }
+  // Like C, a function stays static once declared so
+  else if (entry->node->value == LINKAGE_STATIC) {
+    $<node>4->value = LINKAGE_STATIC;
+  }
+  else if ($1 == LINKAGE_STATIC) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tstatic declaration of '%s' follows a non-static declaration\n", $3);
+    (*errorCount)++;
+    YYABORT;
+  }
+  symtable->cur_function = $<node>4;
+  $$ = $<node>4;
+}
+;
+
+ReturnType : "long" {
+  $$ = NULL;
+}
+| StructType {
+  // Only what fits in %rax and %rdx
+  if (NodeListLen($1->arguments) > 2) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\t'%s' is larger than 16 bytes and can not be returned by value\n", $1->name);
+    (*errorCount)++;
+    YYABORT;
+  }
+  $$ = $1;
+}
+;
+
+Linkage : "static" {
+  $$ = LINKAGE_STATIC;
+}
+| %empty {
+  $$ = LINKAGE_EXTERNAL;
+}
+;
+
+ParametersOptional : Parameters {
+  $$ = $1;
+}
+| %empty {
+  $$ = NULL;
+}
+;
+
+Parameters : Parameter {
+  $$ = $1;
+}
+| Parameters "," Parameter {
+  // Find last node in PS
+  NodeList* last = $1;
+  while (last->next != NULL) last = last->next;
+  last->next = $3;
+  $$ = $1;
+}
+;
+
+Parameter : VarType "variable name" {
+  // Declaring a new parameter
+  // Make sure that the variable does not exist in the current scope
+  list_t* exists = LookupScope(symtable, $2);
+  if (exists) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tredefinition of '%s'\n", $2);
+    (*errorCount)++;
+    //symtab_dump(symtable, NULL);
+    YYABORT;
+  }
+
+  Node* temp = CreateParameter($2);
+  temp->value = $1;
+  Insert(symtable, temp, LONG_TYPE);
+  $$ = CreateNodeList(temp, NULL);
+}
+| StructType "variable name" {
+  // Declaring a struct parameter
+  // Make sure that the variable does not exist in the current scope
+  list_t* exists = LookupScope(symtable, $2);
+  if (exists || NodeListLen($1->arguments) > 2) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    if (exists) fprintf(stderr, "\tredefinition of '%s'\n", $2);
+    else fprintf(stderr, "\t'%s' is larger than 16 bytes and can not be passed by value\n", $1->name);
+    (*errorCount)++;
+    YYABORT;
+  }
+  Insert(symtable, CreateVariable($2, $1), STRUCT_VAR);
+
+  // One parameter per field, so the fields take the next free registers
+  NodeList* head = NULL;
+  NodeList** tail = &head;
+  for (NodeList* field = $1->arguments; field != NULL; field = field->next) {
+    Node* temp = CreateParameter(FieldName($2, field->node->name));
+    temp->value = field->node->value;
+    Insert(symtable, temp, LONG_TYPE);
+    *tail = CreateNodeList(temp, NULL);
+    tail = &(*tail)->next;
+  }
+  $$ = head;
+}
+;
+
+VarType : "long" {
+  $$ = DECL_LONG;
+}
+| "long" "*" {
+  $$ = DECL_POINTER;
+}
+;
+
+FunctionDeclTail : Statements "}" {
+  $$ = $1;
+}
+;
+
+Statements : Statement {
+  $$ = $1;
+}
+| Statements Statement {
+  // Find last node in PS
+  NodeList* last = $1;
+  while (last->next != NULL) last = last->next;
+  last->next = $2;
+  $$ = $1;
+}
+;
+
+Statement : Assignment {
+  $$ = $1;
+}
+| ConstDecl {
+  $$ = CreateNodeList($1, NULL);
+}
+| "return" Expression ";" {
+  // The returned value must have the type the function returns
+  Node* type = symtable->cur_function->left;
+  if (StructOf($2) != type) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\treturning '%s' from a function returning '%s'\n",
+            TypeName(StructOf($2)), TypeName(type));
+    (*errorCount)++;
+    YYABORT;
+  }
+  Node* temp;
+  if (type != NULL && $2->exprCode == VARIABLE) {
+    temp = CreateReturn(FieldVariable(symtable, $2->name, type->arguments->node));
+    if (type->arguments->next != NULL) {
+      temp->right = FieldVariable(symtable, $2->name, type->arguments->next->node);
+    }
+    FreeVariable($2);
+  } else {
+    temp = CreateReturn($2);
+    // The call leaves the second field in %rdx, where it is returned
+    if (type != NULL && type->arguments->next != NULL) {
+      temp->right = CreateUnaryOperation(RESULTHIGH, NULL);
+    }
+  }
+  $$ = CreateNodeList(temp, NULL);
+}
+| "assert" "(" Expression ")" ";" {
+  REQUIRE_LONG($3);
+  $$ = CreateNodeList(CreateAssert($3, @1.first_line), NULL);
+}
+| "*" Operand "=" Expression ";" {
+  if (!IsPointer($2)) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tstore through a value of type 'long', expected 'long*'\n");
+    (*errorCount)++;
+    YYABORT;
+  }
+  REQUIRE_LONG($4);
+  $$ = CreateNodeList(CreateStore($2, $4), NULL);
+}
+;
+
+Assignment : "variable name" {
+  // Must make sure that lvalue variable exists
+  list_t* exists = Lookup(symtable, $1);
+  if (!exists) {
+    printf("Variable %s is not declared yet :( \n", $1);
+    YYABORT;
+  } else if (exists->st_type == FUNCTION_TYPE) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tillegal assignment to the function '%s'\n", $1);
+    (*errorCount)++;
+    //symtab_dump(symtable, NULL);
+    YYABORT;
+  } else if (exists->st_type == CONST_TYPE) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tcannot assign to the constant '%s'\n", $1);
+    (*errorCount)++;
+    YYABORT;
+  }
+  $<symel>$ = exists;
+} "=" Expression ";" {
+  if ($<symel>2->st_type == STRUCT_VAR) {
+    // A struct is assigned field by field, from a struct of the same type
+    Node* type = $<symel>2->node->left;
+    if (StructOf($4) != type) {
+      PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+      fprintf(stderr, "\tassigning '%s' to a variable of type '%s'\n",
+              TypeName(StructOf($4)), type->name);
+      (*errorCount)++;
+      YYABORT;
+    }
+    $$ = AssignStruct(symtable, $1, type, $4, 0);
+    free($1);
+  } else {
+    REQUIRE_LONG($4);
+    // Since $N exists, create an assignment and upate the symtable
+    Node* temp = CreateAssign($1, $4);
+    // The variable keeps the type it was declared with
+    temp->value = $<symel>2->node->value;
+    $<symel>2->node = temp;
+    $$ = CreateNodeList(temp, NULL);
+  }
+}
+| "variable name" "." "variable name" "=" Expression ";" {
+  // Fields are variables of their own, named after the struct variable
+  char* name = FieldName($1, $3);
+  list_t* exists = Lookup(symtable, name);
+  if (!exists) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\t'%s' has no member named '%s'\n", $1, $3);
+    (*errorCount)++;
+    YYABORT;
+  }
+  REQUIRE_LONG($5);
+  Node* temp = CreateAssign(name, $5);
+  temp->value = exists->node->value;
+  exists->node = temp;
+  free($1);
+  free($3);
+  $$ = CreateNodeList(temp, NULL);
+}
+| VarType "variable name" {
+  // Declaring a new variable
+  // Make sure that the variable does not exist in the current scope
+  list_t* exists = LookupScope(symtable, $2);
+  if (exists) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tredefinition of '%s'\n", $2);
+    (*errorCount)++;
+    YYABORT;
+  }
+} "=" Expression ";" {
+  REQUIRE_LONG($5);
+  // Now we can create an assignment for a new variable:
+  Node* temp = CreateAssign($2, $5);
+  temp->value = $1;
+  Insert(symtable, temp, LONG_TYPE);
+  $$ = CreateNodeList(temp, NULL);
+}
+| StructType "variable name" ";" {
+  // Declaring a new struct variable, with every field set to 0
+  if (LookupScope(symtable, $2)) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tredefinition of '%s'\n", $2);
+    (*errorCount)++;
+    YYABORT;
+  }
+  Insert(symtable, CreateVariable($2, $1), STRUCT_VAR);
+  $$ = AssignStruct(symtable, $2, $1, NULL, 1);
+}
+| StructType "variable name" "=" Expression ";" {
+  // Declaring a new struct variable, initialized from another struct
+  if (LookupScope(symtable, $2) || StructOf($4) != $1) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    if (LookupScope(symtable, $2)) fprintf(stderr, "\tredefinition of '%s'\n", $2);
+    else fprintf(stderr, "\tinitializing '%s' with a value of type '%s'\n",
+                 $1->name, TypeName(StructOf($4)));
+    (*errorCount)++;
+    YYABORT;
+  }
+  Insert(symtable, CreateVariable($2, $1), STRUCT_VAR);
+  $$ = AssignStruct(symtable, $2, $1, $4, 1);
+}
+;
+
+ConstDecl : "const" "long" "variable name" {
+  // Declaring a new constant, at file or function scope
+  list_t* exists = LookupScope(symtable, $3);
+  if (exists) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tredefinition of '%s'\n", $3);
+    (*errorCount)++;
+    YYABORT;
+  }
+} "=" Expression ";" {
+  REQUIRE_LONG($6);
+  // The initializer is folded by the optimizer, which reports it if it
+  // is not a compile-time constant.
+  Node* temp = CreateConstDecl($3, $6);
+  Insert(symtable, temp, CONST_TYPE);
+  $$ = temp;
+}
+;
+
+Expression : BorExpression {
+  $$ = $1;
+}
+;
+
+BorExpression : BxorExpression { $$ = $1; }
+| BorExpression "|" BxorExpression
+  { REQUIRE_LONG($1); REQUIRE_LONG($3); $$ = CreateBinaryOperation(BOR, $1, $3); }
+;
+
+BxorExpression : BandExpression { $$ = $1; }
+| BxorExpression "^" BandExpression
+  { REQUIRE_LONG($1); REQUIRE_LONG($3); $$ = CreateBinaryOperation(BXOR, $1, $3); }
+;
+
+BandExpression : ShrExpression { $$ = $1; }
+| BandExpression "&" ShrExpression
+  { REQUIRE_LONG($1); REQUIRE_LONG($3); $$ = CreateBinaryOperation(BAND, $1, $3); }
+;
+
+ShrExpression : AddExpression { $$ = $1; }
+| ShrExpression ShrOperator AddExpression
+  { REQUIRE_LONG($1); REQUIRE_LONG($3); $$ = CreateBinaryOperation($2, $1, $3); }
+;
+
+ShrOperator : ">>" { $$ = BSHR; }
+| "<<" { $$ = BSHL; }
+| ">>>" { $$ = BLSHR; }
+;
+
+AddExpression : MulExpression { $$ = $1; }
+| AddExpression AddOperator MulExpression {
+  REQUIRE_LONG($1);
+  REQUIRE_LONG($3);
+  $$ = CreateAddOperation($2, $1, $3);
+  if ($$ == NULL) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tinvalid operands: addition of two pointers\n");
+    (*errorCount)++;
+    YYABORT;
+  }
+}
+;
+
+AddOperator : "+" { $$ = ADD; }
+| "-"  { $$ = SUBTRACT; }
+;
+
+MulExpression : UnaryExpression { $$ = $1; }
+| MulExpression MulOperator UnaryExpression
+  { REQUIRE_LONG($1); REQUIRE_LONG($3); $$ = CreateBinaryOperation($2, $1, $3); }
+;
+
+MulOperator : "*" { $$ = MULTIPLY; }
+| "/" { $$ = DIVIDE; }
+;
+
+UnaryExpression : Operand {
+  $$ = $1;
+}
+| UnaryOperator Operand {
+  REQUIRE_LONG($2);
+  $$ = CreateUnaryOperation($1, $2);
+}
+| "*" Operand {
+  if (!IsPointer($2)) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tdereference of a value of type 'long', expected 'long*'\n");
+    (*errorCount)++;
+    YYABORT;
+  }
+  $$ = CreateUnaryOperation(DEREF, $2);
+}
+| "&" Operand {
+  REQUIRE_LONG($2);
+  // Only locals and parameters live in memory
+  if ($2->exprCode != VARIABLE || $2->left->stmtCode == CONSTDECL) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tcan only take the address of a variable\n");
+    (*errorCount)++;
+    YYABORT;
+  }
+  $$ = CreateUnaryOperation(ADDRESSOF, $2);
+}
+;
+
+UnaryOperator : "-"  { $$ = NEGATE; }
+| "~"  { $$ = BNOT; }
+;
+
+Operand : "constant value" {
+  $$ = CreateNumber($1);
+}
+| "variable name" {
+  // Must make sure that rvalue variable exists
+  list_t* exists = Lookup(symtable, $1);
+  if (!exists) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tuse of undeclared identifier '%s'\n", $1);
+    (*errorCount)++;
+    YYABORT;
+  } else if (exists->st_type == FUNCTION_TYPE) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\tinvalid usage: function '%s' used like a variable\n", $1);
+    (*errorCount)++;
+    YYABORT;
+  }
+  // A whole struct variable refers to its type
+  $$ = CreateVariable($1, exists->st_type == STRUCT_VAR ? exists->node->left : exists->node);
+}
+| "variable name" "." "variable name" {
+  char* name = FieldName($1, $3);
+  list_t* exists = Lookup(symtable, name);
+  if (!exists) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "\t'%s' has no member named '%s'\n", $1, $3);
+    (*errorCount)++;
+    YYABORT;
+  }
+  free($1);
+  free($3);
+  $$ = CreateVariable(name, exists->node);
+}
+| "(" BorExpression ")" {
+  $$ = $2;
+}
+| FunctionCall {
+  $$ = $1;
+}
+;
+
+FunctionCall : "variable name" {
+  // Check if the called function is declared.
+  list_t* exists = Lookup(symtable, $1);
+  if (!exists) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "use of undeclared function '%s'\n", $1);
+    (*errorCount)++;
+    YYABORT;
+  } else if (exists->st_type != FUNCTION_TYPE) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "invalid usage: variable '%s' used like a function\n", $1);
+    (*errorCount)++;
+    YYABORT;
+  }
+} "(" CallParams {
+  // Struct arguments are passed field by field
+  if (!ExpandStructArgs(symtable, $4)) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "a struct returned by a call must be stored in a variable before it is passed\n");
+    (*errorCount)++;
+    YYABORT;
+  }
+  // Pick the overload that takes as many parameters as there are arguments
+  int argCount = NodeListLen($4);
+  list_t* callee = LookupFunction(symtable, $1, argCount);
+  if (!callee) {
+    PrintLocationInfo(yylloc.first_line, yyget_leng(scanner), yyget_text(scanner));
+    fprintf(stderr, "no declaration of function '%s' takes %d arguments\n", $1, argCount);
+    (*errorCount)++;
+    YYABORT;
+  }
+  $<node>$ = callee->node;
+} ")" {
+  free($1);
+  $$ = CreateFunctionCall($<node>5, $4);
+}
+;
+
+CallParams : CallParam {
+  $$ = $1;
+}
+| %empty {
+  $$ = NULL;
+}
+;
+
+CallParam : Expression {
+  $$ = CreateNodeList($1, NULL);
+}
+| CallParam "," Expression {
+  NodeList* last = $1;
+  while (last->next != NULL) last = last->next;
+  NodeList* newNode = CreateNodeList($3, NULL);
+  last->next = newNode;
+  $$ = $1;
+}
+;
+
+%%
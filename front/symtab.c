@@ -31,11 +31,28 @@ void Insert(SymTable* symtable, Node* node, int type){
 }
 
 list_t *Lookup(SymTable* symtable, char *name){
-  /* return symbol if found or NULL if not found */
+  /* return the symbol of the innermost scope if found or NULL if not found */
+  unsigned int hashval = hash(name);
+  list_t *found = NULL;
+  for (list_t *l = symtable->hash_table[hashval]; l != NULL; l = l->next) {
+    if (strcmp(name,l->node->name) != 0) continue;
+    /* a function overload can be inserted after the parameters that shadow it */
+    if (found == NULL || l->scope > found->scope) found = l;
+  }
+  return found; // NULL is not found
+}
+
+list_t *LookupFunction(SymTable* symtable, char *name, int arity){
+  /* return the overload if found or NULL if not found */
   unsigned int hashval = hash(name);
   list_t *l = symtable->hash_table[hashval];
-  while ((l != NULL) && (strcmp(name,l->node->name) != 0)) l = l->next;
-  return l; // NULL is not found
+  while ((l != NULL) &&
+         ((strcmp(name,l->node->name) != 0) ||
+          l->st_type != FUNCTION_TYPE ||
+          NodeListLen(l->node->arguments) != arity)) {
+    l = l->next;
+  }
+  return l; // NULL if not found
 }
 
 list_t *LookupScope(SymTable* symtable, char *name){
@@ -120,6 +137,9 @@ void symtab_dump(SymTable* symtable, FILE * of){
         printf("%6d ", l->scope);
         if (l->st_type == LONG_TYPE) printf("%-7s","long");
         else if (l->st_type == FUNCTION_TYPE) printf("%-7s", "function");
+        else if (l->st_type == CONST_TYPE) printf("%-7s", "const");
+        else if (l->st_type == STRUCT_TYPE) printf("%-7s", "type");
+        else if (l->st_type == STRUCT_VAR) printf("%-7s", "struct");
         else printf("%-7s","undef"); // if UNDEF or 0
         printf("\n");
         l = l->next;
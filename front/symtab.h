@@ -14,6 +14,9 @@
 #define LONG_TYPE 1
 #define LOGIC_TYPE 2
 #define FUNCTION_TYPE 3
+#define CONST_TYPE 4
+#define STRUCT_TYPE 5
+#define STRUCT_VAR 6
 
 // struct that represents a list node
 typedef struct list_t{
@@ -31,6 +34,8 @@ typedef struct list_t{
 typedef struct _symtable {
   list_t **hash_table;
   int cur_scope;
+  // the function being parsed, so return statements know its return type
+  Node* cur_function;
 } SymTable;
 
 // Function Declarations
@@ -44,6 +49,8 @@ void Insert(SymTable* symtable, Node* node, int type);
 list_t *Lookup(SymTable* symtable, char *name);
 // search for entry in the current scope
 list_t *LookupScope(SymTable* symtable, char *name);
+// search for the function overload with the given parameter count
+list_t *LookupFunction(SymTable* symtable, char *name, int arity);
 // Clear symbol table for the next function
 void ClearSymTable(SymTable* symtable);
 // Completely delete the symbol table
@@ -10,17 +10,15 @@
 int yyparse(NodeList** funcdecls, int* errorCount, SymTable* symtable,
             yyscan_t scanner);
 
-int main(int argc, char *argv[]) {
-  if (argc != 2) {
-    printf("Usage: %s <source file>\n", argv[0]);
-    exit(0);
-  }
-  
+/* Parse one source file into its list of top level declarations.
+   Every file gets its own symbol table, so it must declare (with prototypes)
+   the functions it calls from the other files. Returns 0 on success. */
+int ParseFile(char* path, NodeList** funcdecls) {
   // Open file to read
-  FILE* f = fopen(argv[1], "r");
+  FILE* f = fopen(path, "r");
   if (!f) {
-    printf("Cannot find file: %s\n", argv[1]);
-    exit(0);
+    printf("Cannot find file: %s\n", path);
+    return -1;
   }
   
   fseek(f, 0, SEEK_END);
@@ -33,15 +31,19 @@ int main(int argc, char *argv[]) {
   //printf("Input source file\n");
   //printf("%s\n", source_content);
 
-  NodeList* funcdecls;
   YY_BUFFER_STATE state;
 
   yyscan_t scanner;
-  if (yylex_init(&scanner)) return -1;
+  if (yylex_init(&scanner)) {
+    free(source_content);
+    return -1;
+  }
   
   SymTable* symtable = init_hash_table();
   if (!symtable) {
     printf("Error creating symbolic table\n");
+    yylex_destroy(scanner);
+    free(source_content);
     return -1;
   }
   
@@ -49,10 +51,13 @@ int main(int argc, char *argv[]) {
   state = yy_scan_string(source_content, scanner);
   // Parse the source file
   int errorCount = 0;
-  int stat = yyparse(&funcdecls, &errorCount, symtable, scanner);
+  int stat = yyparse(funcdecls, &errorCount, symtable, scanner);
   if (errorCount > 0) {
-    printf("%d error(s) encounter while parsing.\n", errorCount);
-    return -1;
+    printf("%s: %d error(s) encounter while parsing.\n", path, errorCount);
+  }
+  // Some errors, such as a bad integer literal, abort without being counted
+  else if (stat != 0) {
+    printf("%s: Parsing failed.\n", path);
   }
   
   // Delete buffer;
@@ -63,17 +68,96 @@ int main(int argc, char *argv[]) {
 
   // Delete symbol table now
   DeleteSymTable(symtable);
+
+  // The AST keeps its own copies of the names
+  free(source_content);
+  return (errorCount > 0 || stat != 0) ? -1 : 0;
+}
+
+/* Two declarations are of the same function if name and parameter count match. */
+int SameFunction(Node* a, Node* b) {
+  return strcmp(a->name, b->name) == 0 &&
+         NodeListLen(a->arguments) == NodeListLen(b->arguments);
+}
+
+/* Check the modules against each other before they become one program:
+   a non-static function may only be defined once across all files, and a
+   static function whose name is also used in another file is renamed to
+   "name.N", N being the number of its file, since all of them end up in one
+   assembly file. Calls point at the declaration nodes, so they follow the
+   new name. Returns 0 on success. */
+int LinkModules(NodeList** modules, char** paths, int count) {
+  for (int m = 0; m < count; m++) {
+    for (NodeList* nl = modules[m]; nl != NULL; nl = nl->next) {
+      Node* func = nl->node;
+      if (func->type != FUNCTIONDECL) continue;
+
+      int clash = 0;
+      for (int o = 0; o < count; o++) {
+        if (o == m) continue;
+        for (NodeList* ol = modules[o]; ol != NULL; ol = ol->next) {
+          Node* other = ol->node;
+          if (other->type != FUNCTIONDECL || strcmp(func->name, other->name) != 0) continue;
+          clash = 1;
+          // report each pair once, from the first of the two files
+          if (o > m && SameFunction(func, other) && func->statements != NULL &&
+              other->statements != NULL && func->value != LINKAGE_STATIC &&
+              other->value != LINKAGE_STATIC) {
+            printf("Error: function '%s' with %d parameters is defined in both %s and %s\n",
+                   func->name, NodeListLen(func->arguments), paths[m], paths[o]);
+            return -1;
+          }
+        }
+      }
+
+      if (clash && func->value == LINKAGE_STATIC) {
+        char* local = malloc(strlen(func->name) + 16);
+        sprintf(local, "%s.%d", func->name, m + 1);
+        free(func->name);
+        func->name = local;
+      }
+    }
+  }
+  return 0;
+}
+
+int main(int argc, char *argv[]) {
+  if (argc < 2) {
+    printf("Usage: %s <source file>...\n", argv[0]);
+    exit(0);
+  }
+
+  int count = argc - 1;
+  NodeList** modules = calloc(count, sizeof(NodeList*));
+  int status = 0;
+  for (int m = 0; m < count && status == 0; m++) {
+    status = ParseFile(argv[m + 1], &modules[m]);
+  }
+  if (status == 0) status = LinkModules(modules, argv + 1, count);
+  if (status != 0) {
+    // Free the files parsed so far; a failed one has no tree
+    for (int m = 0; m < count; m++) FreeProgram(modules[m]);
+    free(modules);
+    return -1;
+  }
+
+  // Compile all the files as one program, into one assembly file
+  NodeList* funcdecls = NULL;
+  NodeList** tail = &funcdecls;
+  for (int m = 0; m < count; m++) {
+    *tail = modules[m];
+    while (*tail != NULL) tail = &(*tail)->next;
+  }
+  free(modules);
   
   /************************************************************
    * THIS IS WHERE WE DO STUFF
    * Essentially, the variable funcdecls contain the AST of function declaration.
    * And the function declaration is what we have to compile.
    *************************************************************/
-  rust_mod(funcdecls); 
+  status = rust_mod(funcdecls);
   
   // Finish.
-  free(source_content);
   FreeProgram(funcdecls);
-  return 0;
+  return status == 0 ? 0 : -1;
 }
-
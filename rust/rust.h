@@ -1,6 +1,7 @@
 #ifndef RUST_H
 #define RUST_H
 
-void rust_mod(void* funcdecls);
+// Returns non-zero if the program was rejected by the backend.
+int rust_mod(void* funcdecls);
 
 #endif // RUST_H
@@ -0,0 +1,268 @@
+/*
+***********************************************************************
+  CFG.RS : CONTROL-FLOW GRAPH OF A FUNCTION
+************************************************************************
+*/
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+use crate::expression::*;
+use crate::tac::*;
+
+pub type BlockId = usize;
+
+/*
+*************************************************************************************
+  A BASIC BLOCK IS A RUN OF STATEMENTS THAT EXECUTE ONE AFTER THE OTHER: CONTROL ONLY
+  ENTERS AT ITS FIRST STATEMENT AND ONLY LEAVES AFTER ITS LAST ONE. A BLOCK DOES NOT
+  OWN ITS STATEMENTS, IT NAMES THEIR POSITIONS IN THE FUNCTION'S STATEMENT LIST.
+**************************************************************************************
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct BasicBlock {
+    pub id: BlockId,
+    // position of the first statement in the function's statement list
+    pub start: usize,
+    // number of statements in the block
+    pub len: usize,
+    pub preds: Vec<BlockId>,
+    pub succs: Vec<BlockId>,
+}
+
+impl BasicBlock {
+    fn new(id: BlockId, start: usize, len: usize) -> Self {
+        BasicBlock {
+            id,
+            start,
+            len,
+            preds: Vec::new(),
+            succs: Vec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // The positions of the block's statements in the function's statement list
+    pub fn positions(&self) -> std::ops::Range<usize> {
+        self.start..self.start + self.len
+    }
+}
+
+/*
+*************************************************************************************
+  THE CONTROL-FLOW GRAPH OF ONE FUNCTION. BLOCK 0 IS THE ENTRY, AND THE LAST BLOCK IS
+  AN EMPTY EXIT THAT EVERY RETURN FLOWS INTO, SO BACKWARD ANALYSES HAVE ONE START.
+  THE GRAPH ONLY KNOWS POSITIONS: IT MUST BE BUILT AGAIN AFTER A PASS INSERTS OR
+  DELETES STATEMENTS, BUT STAYS VALID WHILE STATEMENTS ARE REWRITTEN IN PLACE.
+**************************************************************************************
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cfg {
+    blocks: Vec<BasicBlock>,
+}
+
+// A statement after which control does not simply go on with the next one:
+// a return leaves the function and an assert may abort the program
+fn EndsBlock(stmt: &RNode) -> bool {
+    stmt.stmtCode == StmtType::RETURN || stmt.stmtCode == StmtType::ASSERT
+}
+
+// What ends a block in three-address code, as EndsBlock does for statements
+fn EndsTacBlock(instr: &Instr) -> Option<bool> {
+    match instr {
+        Instr::Ret { .. } => Some(true),
+        Instr::Assert { .. } => Some(false),
+        _ => None,
+    }
+}
+
+impl Cfg {
+    pub fn build(func: &RNode) -> Cfg {
+        Cfg::split(
+            func.statements
+                .iter()
+                .flat_map(|list| list.iter())
+                .map(|stmt| if EndsBlock(stmt) { Some(stmt.stmtCode == StmtType::RETURN) } else { None }),
+        )
+    }
+
+    // The same graph over the instructions of a function lowered to three-address code
+    pub fn build_tac(func: &TacFunction) -> Cfg {
+        Cfg::split(func.body.iter().map(EndsTacBlock))
+    }
+
+    // Cut a list of instructions into blocks. Each item tells whether its instruction
+    // ends a block and, if so, whether it is a return.
+    fn split(ends: impl Iterator<Item = Option<bool>>) -> Cfg {
+        let mut blocks: Vec<BasicBlock> = Vec::new();
+        // whether each block ends with a return
+        let mut returns: Vec<bool> = Vec::new();
+        let mut start: usize = 0;
+        let mut count: usize = 0;
+
+        for (pos, end) in ends.enumerate() {
+            count = pos + 1;
+            if let Some(is_return) = end {
+                blocks.push(BasicBlock::new(blocks.len(), start, count - start));
+                returns.push(is_return);
+                start = count;
+            }
+        }
+        if start < count || blocks.is_empty() {
+            blocks.push(BasicBlock::new(blocks.len(), start, count - start));
+            returns.push(false);
+        }
+
+        let exit = blocks.len();
+        blocks.push(BasicBlock::new(exit, count, 0));
+        for id in 0..exit {
+            // without a return, control falls through to the next block
+            let succ = if returns[id] { exit } else { id + 1 };
+            blocks[id].succs.push(succ);
+            blocks[succ].preds.push(id);
+        }
+        Cfg { blocks }
+    }
+
+    // A graph of any shape: block i holds the next sizes[i] instructions, and the
+    // last block is the exit. The source language has no branches yet, so only
+    // this constructor can make joins of several paths.
+    pub fn from_edges(sizes: &[usize], edges: &[(BlockId, BlockId)]) -> Cfg {
+        let mut blocks: Vec<BasicBlock> = Vec::new();
+        let mut start: usize = 0;
+        for &len in sizes {
+            blocks.push(BasicBlock::new(blocks.len(), start, len));
+            start += len;
+        }
+        for &(from, to) in edges {
+            blocks[from].succs.push(to);
+            blocks[to].preds.push(from);
+        }
+        Cfg { blocks }
+    }
+
+    pub fn entry(&self) -> BlockId {
+        0
+    }
+
+    pub fn exit(&self) -> BlockId {
+        self.blocks.len() - 1
+    }
+
+    pub fn len(&self) -> usize {
+        self.blocks.len()
+    }
+
+    pub fn block(&self, id: BlockId) -> &BasicBlock {
+        &self.blocks[id]
+    }
+
+    pub fn blocks(&self) -> std::slice::Iter<'_, BasicBlock> {
+        self.blocks.iter()
+    }
+
+    // The blocks reachable from the entry, each one before its successors
+    // except along loops. Forward analyses converge fastest in this order.
+    pub fn reverse_postorder(&self) -> Vec<BlockId> {
+        let mut visited = vec![false; self.blocks.len()];
+        let mut postorder: Vec<BlockId> = Vec::new();
+        // each entry is a block and how many of its successors were visited
+        let mut stack: Vec<(BlockId, usize)> = vec![(self.entry(), 0)];
+        visited[self.entry()] = true;
+
+        while let Some((id, next)) = stack.pop() {
+            if let Some(&succ) = self.blocks[id].succs.get(next) {
+                stack.push((id, next + 1));
+                if !visited[succ] {
+                    visited[succ] = true;
+                    stack.push((succ, 0));
+                }
+            } else {
+                postorder.push(id);
+            }
+        }
+        postorder.reverse();
+        postorder
+    }
+
+    // The instructions of a block, in order. items are the statements of the function
+    // the graph was built from, as Statements collects them, or its three-address code.
+    pub fn instructions<'a, T>(&self, id: BlockId, items: &'a [T]) -> &'a [T] {
+        &items[self.blocks[id].positions()]
+    }
+
+    pub fn instructions_mut<'a, T>(&self, id: BlockId, items: &'a mut [T]) -> &'a mut [T] {
+        &mut items[self.blocks[id].positions()]
+    }
+}
+
+// The statements of a function in order, collected once so that each block
+// can look its own up by position
+pub fn Statements(func: &RNode) -> Vec<&RNode> {
+    func.statements.iter().flat_map(|list| list.iter()).collect()
+}
+
+pub fn StatementsMut(func: &mut RNode) -> Vec<&mut RNode> {
+    func.statements.iter_mut().flat_map(|list| list.iter_mut()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cfg, Statements, StatementsMut};
+    use crate::expression::build::*;
+    use crate::expression::*;
+
+    #[test]
+    fn returns_and_asserts_end_blocks() {
+        // long foo(long a) { long x = a; assert(x); long y = x; return y; long z = 1; }
+        let foo = func(
+            "foo",
+            vec!["a"],
+            vec![
+                assign("x", param_ref("a")),
+                assert_at(1, var("x")),
+                assign("y", var("x")),
+                ret(var("y")),
+                assign("z", constant(1)),
+            ],
+        );
+
+        let cfg = Cfg::build(&foo);
+
+        let shape: Vec<(usize, usize, Vec<usize>, Vec<usize>)> = cfg
+            .blocks()
+            .map(|b| (b.start, b.len, b.preds.clone(), b.succs.clone()))
+            .collect();
+        assert_eq!(
+            shape,
+            vec![
+                (0, 2, vec![], vec![1]),
+                (2, 2, vec![0], vec![3]),
+                (4, 1, vec![], vec![3]),
+                (5, 0, vec![1, 2], vec![]),
+            ]
+        );
+        // the statement after the return is never reached
+        assert_eq!(cfg.reverse_postorder(), vec![0, 1, 3]);
+        let stmts = Statements(&foo);
+        let names: Vec<&str> = cfg.instructions(1, &stmts).iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["y", ""]);
+    }
+
+    #[test]
+    fn instructions_can_be_rewritten_in_place() {
+        let mut program = program(vec![func("foo", vec![], vec![assign("x", constant(1)), ret(var("x"))])]);
+        let foo = program.node.as_mut().unwrap();
+        let cfg = Cfg::build(foo);
+
+        let mut stmts = StatementsMut(foo);
+        for stmt in cfg.instructions_mut(cfg.entry(), &mut stmts) {
+            stmt.name.push('0');
+        }
+
+        let names: Vec<&str> = body(&program, 0).iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["x0", "0"]);
+    }
+}
@@ -8,11 +8,23 @@
 #![allow(dead_code)]
 extern crate libc;
 use crate::expression::*;
+use crate::liveness::{AddressTaken, LiveVariables};
+use crate::tac::*;
+use lazy_static::lazy_static;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs::File;
 use std::io::prelude::*;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
 
 pub const INVAL: i64 = -999;
 
+lazy_static! {
+    // Numbers the local labels emitted in the whole file
+    static ref labelCounter: AtomicI64 = AtomicI64::new(0);
+    // Set once an assert is emitted, so the trap routine gets written
+    static ref usesAssert: AtomicBool = AtomicBool::new(false);
+}
+
 /*
 *************************************************************************************
  USE A STRUCTURE TO STORE GLOBAL VARIABLES
@@ -35,212 +47,6 @@ impl globals {
         }
     }
 }
-/*
-*************************************************************************************
-     THE REGINFO LIST TRACKS IF REGISTERS ARE AVAILABLE FOR USE
-**************************************************************************************
-*/
-
-#[derive(Clone)]
-struct regInfo {
-    name: String,
-    avail: i8,
-    next: LinkInfo,
-}
-
-type LinkInfo = Option<Box<regInfo>>;
-
-impl Default for regInfo {
-    fn default() -> regInfo {
-        regInfo {
-            name: "".to_string(),
-            avail: 0,
-            next: None,
-        }
-    }
-}
-
-struct regList {
-    pub head: LinkInfo,
-}
-
-impl regList {
-    fn new() -> Self {
-        regList { head: None }
-    }
-
-    /*
-    ***********************************************************************
-      FUNCTION TO ADD NEW REGISTER INFORMATION TO THE REGISTER INFO LIST
-    ************************************************************************
-    */
-    fn add_reg(&mut self, name: &str, avail: i8) {
-        let new_node = Box::new(regInfo {
-            name: name.to_string(),
-            avail: avail,
-            next: None,
-        });
-
-        if !self.head.is_none() {
-            let mut current = &mut self.head;
-            loop {
-                if let Some(node) = current.as_mut() {
-                    if node.next.is_none() {
-                        node.next = Some(new_node);
-                        break;
-                    }
-                    current = &mut node.next;
-                } else {
-                    break;
-                }
-            }
-        } else {
-            self.head = Some(new_node);
-        }
-    }
-
-    /*
-    ***********************************************************************
-      FUNCTION TO UPDATE THE AVAILIBILITY OF REGISTERS IN THE REG INFO LIST
-    ************************************************************************
-    */
-    fn update_reg_info(&mut self, name: String, avail: i8) {
-        let mut current = &mut self.head;
-        loop {
-            if let Some(node) = current.as_mut() {
-                if node.name == name {
-                    node.avail = avail;
-                }
-                current = &mut node.next;
-            } else {
-                break;
-            }
-        }
-    }
-
-    /*
-    ***********************************************************************
-      FUNCTION TO RETURN THE NEXT AVAILABLE REGISTER
-    ************************************************************************
-    */
-    fn get_next_avail_reg(&self, noAcc: bool) -> String {
-        let mut current = &self.head;
-
-        if current.is_none() {
-            println!("List is empty");
-        }
-
-        loop {
-            if let Some(node) = current.as_ref() {
-                if node.avail == 1 {
-                    if !noAcc {
-                        return node.name.clone();
-                    }
-                    // if not rax and dont return accumulator set to true, return the other reg
-                    // if rax and noAcc == true, skip to next avail
-                    if noAcc && !(node.name == "%rax") {
-                        return node.name.clone();
-                    }
-                }
-                current = &node.next;
-            } else {
-                break;
-            }
-        }
-        return "NoReg".to_string();
-    }
-
-    /*
-    ***********************************************************************
-      FUNCTION TO DETERMINE IF ANY REGISTER APART FROM OR INCLUDING
-      THE ACCUMULATOR(RAX) IS AVAILABLE
-    ************************************************************************
-    */
-    fn if_avail_reg(&self, noAcc: bool) -> usize {
-        let mut current = &self.head;
-
-        if current.is_none() {
-            println!("Empty reglist");
-        }
-
-        loop {
-            if let Some(node) = current.as_ref() {
-                if node.avail == 1 {
-                    // registers available
-                    if !noAcc {
-                        return 1;
-                    } else if noAcc && !(node.name == "%rax") {
-                        return 1;
-                    }
-                }
-                current = &node.next;
-            } else {
-                break;
-            }
-        }
-        return 0;
-    }
-
-    /*
-    ***********************************************************************
-      FUNCTION TO DETERMINE IF A SPECIFIC REGISTER IS AVAILABLE
-    ************************************************************************
-    */
-    fn is_avail_reg(&self, name: String) -> bool {
-        let mut current = &self.head;
-
-        if current.is_none() {
-            println!("Empty reglist");
-        }
-
-        loop {
-            if let Some(node) = current.as_ref() {
-                if node.name == name {
-                    if node.avail == 1 {
-                        return true;
-                    }
-                }
-                current = &node.next;
-            } else {
-                break;
-            }
-        }
-        return false;
-    }
-
-    /*
-    ***********************************************************************
-      FUNCTION TO FREE REGISTER INFORMATION LIST
-    ************************************************************************
-    */
-    fn free_list(&mut self) {
-        while !self.head.is_none() {
-            self.head.take().map(|node| {
-                self.head = node.next;
-            });
-        }
-    }
-
-    fn print_list(&self) {
-        let mut current = &self.head;
-
-        if current.is_none() {
-            println!("Empty reglist");
-        }
-
-        loop {
-            if let Some(node) = current.as_ref() {
-                print!("\t {} : {} -> ", node.name, node.avail);
-
-                current = &node.next;
-            } else {
-                break;
-            }
-        }
-        println!();
-    }
-}
-
 /*
 *************************************************************************************
      THE VARSTOREINFO LIST TRACKS A VARIABLE NAME, VALUE AND WHERE IT IS STORED
@@ -314,14 +120,12 @@ impl varStList {
 
         loop {
             if let Some(node) = current.as_ref() {
-                if node.is_const == true {
-                    if node.value == val {
-                        return node.location.clone();
-                    }
-                } else {
-                    if node.name == name {
-                        return node.location.clone();
-                    }
+                // a named variable is found by name, even when it holds a constant
+                if !node.name.is_empty() && node.name == name {
+                    return node.location.clone();
+                }
+                if node.is_const && val != INVAL && node.value == val {
+                    return node.location.clone();
                 }
                 current = &node.next;
             } else {
@@ -337,7 +141,7 @@ impl varStList {
     ************************************************************************
     */
     fn update_var_info(&mut self, varName: String, location: String, val: i64, is_const: bool) {
-        if self.lookup_var_info(varName.clone(), val) == "".to_string() {
+        if self.lookup_var_info(varName.clone(), INVAL).is_empty() {
             self.add_var_info(varName.clone(), location, val, is_const);
         } else {
             let mut current = &mut self.head;
@@ -406,9 +210,11 @@ impl varStList {
 **************************************************************************************
 */
 #[no_mangle]
-fn init_asm(fileptr: &mut File, funcName: String) {
+fn init_asm(fileptr: &mut File, funcName: String, isStatic: bool) {
+    // static functions are not visible to the linker
+    let linkage = if isStatic { ".local" } else { ".globl" };
     fileptr
-        .write_all(format!("\n.globl {}", funcName).as_bytes())
+        .write_all(format!("\n{} {}", linkage, funcName).as_bytes())
         .expect("Unable to write data");
     fileptr
         .write_all(format!("\n{}:", funcName).as_bytes())
@@ -438,6 +244,14 @@ fn ret_asm(fileptr: &mut File) {
         .expect("Unable to write data");
 }
 
+// Free the frame before returning, wherever the function returns from
+fn leave_asm(fileptr: &mut File, required_space: i64) {
+    fileptr
+        .write_all(format!("\naddq ${}, %rsp", required_space).as_bytes())
+        .expect("Unable to write data");
+    ret_asm(fileptr);
+}
+
 /*
 ***************************************************************************
   FUNCTION TO CONVERT OFFSET FROM LONG TO CHAR STRING
@@ -462,56 +276,127 @@ fn long_to_char_offset(glb: &mut globals) {
   FUNCTION TO SAVE VALUE IN ACCUMULATOR (RAX)
 ****************************************************************************
 */
-#[no_mangle]
-fn save_val_rax(
-    fileptr: &mut File,
-    name: String,
-    glb: &mut globals,
-    var_list: &mut varStList,
-    reg_list: &mut regList,
-) {
-    let temp_reg = reg_list.get_next_avail_reg(true);
-
-    if temp_reg == "NoReg" {
-        long_to_char_offset(glb);
+/*
+***************************************************************************
+  FUNCTION TO BUILD THE INSTRUCTIONS THAT LOAD AN IMMEDIATE INTO A REGISTER
+  OR STACK LOCATION. movq ONLY TAKES A SIGN-EXTENDED 32-BIT IMMEDIATE, SO
+  WIDER VALUES GO THROUGH movabsq, WHICH CAN ONLY TARGET A REGISTER. %r11 IS
+  NEVER USED FOR ANYTHING ELSE, SO IT SERVES AS THE SCRATCH.
+****************************************************************************
+*/
+fn imm_to(val: i64, dest: &str) -> String {
+    if i32::try_from(val).is_ok() {
+        format!("\nmovq ${}, {}", val, dest)
+    } else if dest.starts_with('%') {
+        format!("\nmovabsq ${}, {}", val, dest)
+    } else {
+        format!("\nmovabsq ${}, %r11\nmovq %r11, {}", val, dest)
+    }
+}
 
+/*
+***************************************************************************
+  FUNCTIONS TO MOVE A VALUE BETWEEN ITS STACK SLOT AND A REGISTER. A
+  CONSTANT HAS NO SLOT, IT IS LOADED AS AN IMMEDIATE.
+****************************************************************************
+*/
+fn load_value(fileptr: &mut File, value: &Value, dest: &str, var_list: &varStList) {
+    if let Value::Const(val) = value {
         fileptr
-            .write_all(format!("\n movq %rax, {}", glb.last_offset_used).as_bytes())
+            .write_all(imm_to(*val, dest).as_bytes())
             .expect("Unable to write data");
-
-        var_list.update_var_info(name, glb.last_offset_used.clone(), INVAL, false);
-        reg_list.update_reg_info("%rax".to_string(), 1);
     } else {
+        let addy: String = var_list.lookup_var_info(value.to_string(), INVAL);
         fileptr
-            .write_all(format!("\nmovq %rax, {}", temp_reg).as_bytes())
+            .write_all(format!("\nmovq {}, {}", addy, dest).as_bytes())
             .expect("Unable to write data");
-
-        reg_list.update_reg_info(temp_reg.clone(), 0);
-        var_list.update_var_info(name, temp_reg, INVAL, false);
-        reg_list.update_reg_info("%rax".to_string(), 1);
     }
 }
-#[no_mangle]
-fn create_reg_list(reg_list: &mut regList) {
-    // Create the initial reglist which can be used to store variables.
-    // 4 general purpose registers : AX, BX, CX, DX
-    // 4 special purpose : SP, BP, SI , DI.
-    // Other registers: r8, r9
-    // You need to decide which registers you will add in the register list
-    // use. Can you use all of the above registers?
-    /*
-     ****************************************
-              TODO : YOUR CODE HERE
-     ***************************************
-    */
-    reg_list.add_reg("%rax",1);
-    reg_list.add_reg("%rbx",1);
-    reg_list.add_reg("%r9",1);
-    reg_list.add_reg("%r8",1);
-    reg_list.add_reg("%rcx",1);
-    reg_list.add_reg("%rdx",1);
-    reg_list.add_reg("%rsi",1);
-    reg_list.add_reg("%rdi",1);
+
+fn save_rax(fileptr: &mut File, dest: &Value, var_list: &varStList) {
+    let addy: String = var_list.lookup_var_info(dest.to_string(), INVAL);
+    fileptr
+        .write_all(format!("\nmovq %rax, {}", addy).as_bytes())
+        .expect("Unable to write data");
+}
+
+// The slot holding the second field of the struct returned by the call assigned to low
+fn high_slot_name(low: &Value) -> String {
+    format!("high({})", low)
+}
+
+/*
+***************************************************************************
+  FUNCTION TO GIVE EVERY PARAMETER, VARIABLE AND TEMPORARY OF A FUNCTION AN
+  8-BYTE STACK SLOT. TWO VALUES SHARE A SLOT WHEN NEITHER IS LIVE WHERE THE
+  OTHER IS WRITTEN. A VARIABLE WHOSE ADDRESS IS TAKEN, AND THE SECOND FIELD
+  OF A RETURNED STRUCT THAT IS READ LATER ON, KEEP A SLOT OF THEIR OWN.
+****************************************************************************
+*/
+fn new_slot(name: String, glb: &mut globals, var_list: &mut varStList) -> String {
+    long_to_char_offset(glb);
+    var_list.add_var_info(name, glb.last_offset_used.clone(), INVAL, false);
+    glb.last_offset_used.clone()
+}
+
+fn assign_slots(func: &TacFunction, glb: &mut globals, var_list: &mut varStList) {
+    let live = LiveVariables::compute_tac(func);
+    let escaped: BTreeSet<String> = func.body.iter().filter_map(AddressTaken).collect();
+
+    // the values in the order they are written, and those live where each one is.
+    // Parameters, and anything read before being written, are written on entry.
+    let mut order: Vec<String> = Vec::new();
+    let mut conflicts: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    let mut entry: BTreeSet<String> = func.params.iter().map(|param| Value::Var(param.clone()).to_string()).collect();
+    if !func.body.is_empty() {
+        entry.extend(live.live_in(0).iter().cloned());
+    }
+    let mut writes: Vec<(String, &BTreeSet<String>)> = entry.iter().map(|name| (name.clone(), &entry)).collect();
+    for (pos, instr) in func.body.iter().enumerate() {
+        if let Some(dest) = instr.dest() {
+            writes.push((dest.to_string(), live.live_out(pos)));
+        }
+    }
+    for (name, live_there) in writes {
+        for other in live_there.iter().filter(|other| **other != name) {
+            conflicts.entry(name.clone()).or_default().insert(other.clone());
+            conflicts.entry(other.clone()).or_default().insert(name.clone());
+        }
+        if !order.contains(&name) {
+            order.push(name);
+        }
+    }
+
+    // each value takes the first shared slot none of its conflicts holds
+    for name in escaped.iter() {
+        new_slot(name.clone(), glb, var_list);
+    }
+    let mut shared: Vec<String> = Vec::new();
+    let mut holders: Vec<BTreeSet<String>> = Vec::new();
+    for name in order.into_iter().filter(|name| !escaped.contains(name)) {
+        let clash = conflicts.get(&name);
+        let free = holders
+            .iter()
+            .position(|holder| clash.is_none_or(|clash| holder.is_disjoint(clash)));
+        let index = match free {
+            Some(index) => index,
+            None => {
+                shared.push(new_slot(name.clone(), glb, var_list));
+                holders.push(BTreeSet::new());
+                shared.len() - 1
+            }
+        };
+        var_list.update_var_info(name.clone(), shared[index].clone(), INVAL, false);
+        holders[index].insert(name);
+    }
+
+    for instr in func.body.iter() {
+        if let Instr::ResultHigh { low: Some(low), .. } = instr {
+            if var_list.lookup_var_info(high_slot_name(low), INVAL).is_empty() {
+                new_slot(high_slot_name(low), glb, var_list);
+            }
+        }
+    }
 }
 
 /*
@@ -520,482 +405,346 @@ fn create_reg_list(reg_list: &mut regList) {
 ************************************************************************
 */
 #[no_mangle]
-fn push_arg_on_stack(
-    fileptr: &mut File,
-    arguments: &RList,
-    glb: &mut globals,
-    var_list: &mut varStList,
-    reg_list: &mut regList,
-) {
-    /*
-     ****************************************
-              TODO : YOUR CODE HERE
-     ****************************************
-    */
+fn push_arg_on_stack(fileptr: &mut File, params: &[String], var_list: &varStList) {
+    for (param, src) in params.iter().zip(ARG_REGISTERS) {
+        let addy: String = var_list.lookup_var_info(Value::Var(param.clone()).to_string(), INVAL);
+        fileptr
+            .write_all(format!("\nmovq {}, {}", src, addy).as_bytes())
+            .expect("Unable to write data");
+    }
+}
 
-    let mut args = arguments;
-    let mut counter: i64 = 0;
-    let mut src: String = "%rdi".to_string();
-    loop {
-        /*
-        ***********************************************************************
-                 TODO : YOUR CODE HERE
-         THINK ABOUT WHERE EACH ARGUMENT COMES FROM. EXAMPLE WHERE IS THE
-         FIRST ARGUMENT OF A FUNCTION STORED.
-        ************************************************************************
-        */
-        if let Some(node) = args.node.as_ref() {
-            counter += 1;
-            if counter == 2 {
-                src = "%rsi".to_string();
-            }
-            else if counter == 3 {
-                src = "%rdx".to_string();
-            }
-            else if counter == 4 {
-                src = "%rcx".to_string();
-            }
-            else if counter == 5 {
-                src = "%r8".to_string();
-            }
-            else if counter == 6 {
-                src = "%r9".to_string();
-            }
-            else {
-                // nothing happens
-            }
-            long_to_char_offset(glb);
-            reg_list.add_reg(&glb.last_offset_used.clone(), 0);
-            var_list.add_var_info(node.name.clone(), glb.last_offset_used.clone(), INVAL, false);
-            fileptr
-                .write_all(format!("\nmovq {}, {}", src.clone(), glb.last_offset_used).as_bytes())
-                .expect("Unable to write data");
-        } else {
-            break;
-        }
+const ARG_REGISTERS: [&str; 6] = ["%rdi", "%rsi", "%rdx", "%rcx", "%r8", "%r9"];
 
-        if let Some(next) = args.next.as_ref() {
-            args = next;
-        } else {
-            break;
-        }
+/*
+***********************************************************************
+ THIS FUNCTION GENERATES A CALL. EVERY ARGUMENT IS ALREADY IN A SLOT OR
+ AN IMMEDIATE, SO IT GOES STRAIGHT TO ITS REGISTER, AND THE FRAME IS
+ 16-BYTE ALIGNED SO THE CALL NEEDS NO PADDING.
+************************************************************************
+*/
+fn process_call(fileptr: &mut File, func: &str, args: &[Value], var_list: &varStList) {
+    for (arg, dest) in args.iter().zip(ARG_REGISTERS) {
+        load_value(fileptr, arg, dest, var_list);
     }
+    fileptr
+        .write_all(format!("\ncall {}", func).as_bytes())
+        .expect("Unable to write data");
 }
 
 /*
-*************************************************************************
-  THIS FUNCTION IS MEANT TO GET THE FUNCTION ARGUMENTS FROM THE  STACK
-**************************************************************************
+***********************************************************************
+ STRENGTH REDUCTION: A MULTIPLY OR DIVIDE BY A CONSTANT THAT HAS A CHEAPER
+ FORM. RETURNS THE OTHER OPERAND, WHICH IS LEFT IN RAX, AND THE CODE THAT
+ TURNS IT INTO THE RESULT. RCX IS FREE, AS THE CONSTANT NEVER GETS LOADED,
+ AND RDX IS CLOBBERED BY A DIVIDE EITHER WAY.
+************************************************************************
 */
-#[no_mangle]
-fn pop_arg_from_stack(
-    fileptr: &mut File,
-    arguments: &RList,
-    glb: &mut globals,
-    var_list: &mut varStList,
-    reg_list: &mut regList,
-) {
-    let mut args = arguments;
-    /*
-     ****************************************
-              TODO : YOUR CODE HERE
-     ****************************************
-    */
+fn reduce_by_constant(op: OpType, value: i64) -> Option<String> {
+    let shift = value.unsigned_abs().trailing_zeros();
+    let power_of_two = value != i64::MIN && value.unsigned_abs().is_power_of_two();
+    match op {
+        OpType::MULTIPLY if value > 0 && power_of_two => Some(format!("\nsalq ${}, %rax", shift)),
+        OpType::MULTIPLY if value == 3 || value == 5 || value == 9 => {
+            Some(format!("\nleaq (%rax,%rax,{}), %rax", value - 1))
+        }
+        OpType::DIVIDE if value.unsigned_abs() == 1 => {
+            Some(if value < 0 { "\nnegq %rax".to_string() } else { String::new() })
+        }
+        OpType::DIVIDE if power_of_two => {
+            // an arithmetic shift rounds toward minus infinity, so a negative
+            // dividend first gets 2^k - 1 added to round toward zero instead
+            let mut code = format!(
+                "\nmovq %rax, %rcx\nsarq $63, %rcx\nshrq ${}, %rcx\naddq %rcx, %rax\nsarq ${}, %rax",
+                64 - shift,
+                shift
+            );
+            if value < 0 {
+                code.push_str("\nnegq %rax");
+            }
+            Some(code)
+        }
+        OpType::DIVIDE if value != 0 => Some(divide_by_magic(value)),
+        _ => None,
+    }
+}
 
+/*
+***********************************************************************
+ SIGNED DIVISION BY A CONSTANT d, |d| >= 2, AS A MULTIPLY BY A MAGIC NUMBER
+ M ~ 2^(64+s)/d KEEPING THE HIGH HALF OF THE PRODUCT, FROM GRANLUND AND
+ MONTGOMERY (HACKER'S DELIGHT, 10-1). M AND s ARE THE SMALLEST THAT GIVE
+ THE EXACT QUOTIENT FOR EVERY 64-BIT DIVIDEND.
+************************************************************************
+*/
+fn division_magic(divisor: i64) -> (i64, u32) {
+    let two63: u64 = 1 << 63;
+    let ad = divisor.unsigned_abs();
+    let t = two63 + ((divisor as u64) >> 63);
+    // the largest dividend magnitude whose remainder is d - 1
+    let anc = t - 1 - t % ad;
+    let mut p: u32 = 63;
+    let (mut q1, mut r1) = (two63 / anc, two63 % anc);
+    let (mut q2, mut r2) = (two63 / ad, two63 % ad);
     loop {
-        /*
-        ***********************************************************************
-                 TODO : YOUR CODE HERE
-         THINK ABOUT WHERE EACH ARGUMENT COMES FROM. EXAMPLE WHERE IS THE
-         FIRST ARGUMENT OF A FUNCTION STORED AND WHERE SHOULD IT BE EXTRACTED
-         FROM AND STORED TO..
-        ************************************************************************
-        */
-        if let Some(node) = args.node.as_ref() {
-            // unimplemented!();
-        } else {
+        p += 1;
+        q1 = q1.wrapping_mul(2);
+        r1 = r1.wrapping_mul(2);
+        if r1 >= anc {
+            q1 = q1.wrapping_add(1);
+            r1 = r1.wrapping_sub(anc);
+        }
+        q2 = q2.wrapping_mul(2);
+        r2 = r2.wrapping_mul(2);
+        if r2 >= ad {
+            q2 = q2.wrapping_add(1);
+            r2 = r2.wrapping_sub(ad);
+        }
+        let delta = ad - r2;
+        if !(q1 < delta || (q1 == delta && r1 == 0)) {
             break;
         }
+    }
+    let magic = q2.wrapping_add(1) as i64;
+    (if divisor < 0 { magic.wrapping_neg() } else { magic }, p - 64)
+}
 
-        if let Some(next) = args.next.as_ref() {
-            args = next;
-        } else {
-            break;
+fn divide_by_magic(divisor: i64) -> String {
+    let (magic, shift) = division_magic(divisor);
+    // the high half of n * M, in rdx
+    let mut code = format!("\nmovq %rax, %rcx{}\nimulq %rdx", imm_to(magic, "%rdx"));
+    // M did not fit in 64 bits and wrapped to the other sign: correct by n
+    if divisor > 0 && magic < 0 {
+        code.push_str("\naddq %rcx, %rdx");
+    } else if divisor < 0 && magic > 0 {
+        code.push_str("\nsubq %rcx, %rdx");
+    }
+    if shift > 0 {
+        code.push_str(&format!("\nsarq ${}, %rdx", shift));
+    }
+    // that rounds toward minus infinity: add one to a negative quotient
+    code.push_str("\nmovq %rdx, %rax\nshrq $63, %rax\naddq %rdx, %rax");
+    code
+}
+
+fn strength_reduced<'a>(op: OpType, left: &'a Value, right: &'a Value) -> Option<(&'a Value, String)> {
+    if let Value::Const(value) = right {
+        if let Some(code) = reduce_by_constant(op, *value) {
+            return Some((left, code));
         }
     }
+    match left {
+        Value::Const(value) if op == OpType::MULTIPLY => reduce_by_constant(op, *value).map(|code| (right, code)),
+        _ => None,
+    }
 }
-/*
-***************************************************************************
-  FUNCTION TO CONVERT CONSTANT VALUE TO CHAR STRING
-****************************************************************************
-*/
-#[no_mangle]
-fn process_constant(
-    fileptr: &mut File,
-    op_node: &RNode,
-    glb: &mut globals,
-    var_list: &mut varStList,
-) {
-    long_to_char_offset(glb);
 
-    let mut value = format!("{}", op_node.value);
-    if value.len() > 10 {
-        value.truncate(10);
+// The code that combines rax and rcx into rax
+fn binary_asm(op: OpType) -> &'static str {
+    match op {
+        OpType::MULTIPLY => "\nimulq %rcx, %rax",
+        OpType::DIVIDE => "\ncqto\nidivq %rcx",
+        OpType::ADD => "\naddq %rcx, %rax",
+        OpType::SUBTRACT => "\nsubq %rcx, %rax",
+        OpType::BOR => "\norq %rcx, %rax",
+        OpType::BAND => "\nandq %rcx, %rax",
+        OpType::BXOR => "\nxorq %rcx, %rax",
+        OpType::BSHR => "\nsarq %cl, %rax",
+        OpType::BSHL => "\nsalq %cl, %rax",
+        OpType::BLSHR => "\nshrq %cl, %rax",
+        // this shouldn't happen
+        _ => "",
     }
+}
 
-    let mut offset = format!("{}", glb.last_used_offset);
-    if offset.len() > 100 {
-        offset.truncate(100);
+fn unary_asm(op: OpType) -> &'static str {
+    match op {
+        OpType::NEGATE => "\nnegq %rax",
+        OpType::BNOT => "\nnotq %rax",
+        OpType::DEREF => "\nmovq (%rax), %rax",
+        // this shouldn't happen
+        _ => "",
     }
-    offset.push_str("(%rbp)");
+}
 
-    var_list.add_var_info("".to_string(), offset.clone(), op_node.value, true);
+/*
+***********************************************************************
+ THIS FUNCTION GENERATES A RUNTIME ASSERT: THE CONDITION IS LOADED
+ AND, IF IT IS ZERO, THE TRAP ROUTINE IS CALLED WITH THE SOURCE LINE
+************************************************************************
+*/
+fn process_assert(fileptr: &mut File, cond: &Value, line: i64, var_list: &varStList) {
+    load_value(fileptr, cond, "%rax", var_list);
 
+    let label = labelCounter.fetch_add(1, Ordering::Relaxed);
+    usesAssert.store(true, Ordering::Relaxed);
+    fileptr
+        .write_all(format!("\ntestq %rax, %rax\njne .Lassert{}", label).as_bytes())
+        .expect("Unable to write data");
     fileptr
-        .write_all(format!("\nmovq ${}, {}", value, offset).as_bytes())
+        .write_all(imm_to(line, "%rdi").as_bytes())
+        .expect("Unable to write data");
+    fileptr
+        .write_all(format!("\ncall __toy_assert_fail\n.Lassert{}:", label).as_bytes())
+        .expect("Unable to write data");
+}
+
+/*
+***************************************************************************
+  FUNCTION TO WRITE THE ROUTINE FAILED ASSERTS JUMP TO. IT PRINTS THE LINE
+  PASSED IN %rdi TO STDERR AND ABORTS, SO IT NEVER RETURNS.
+****************************************************************************
+*/
+fn assert_trap_asm(fileptr: &mut File) {
+    fileptr
+        .write_all(
+            "\n__toy_assert_fail:\
+             \npushq %rbp\
+             \nmovq %rsp, %rbp\
+             \nandq $-16, %rsp\
+             \nmovq %rdi, %rdx\
+             \nleaq .Lassert_msg(%rip), %rsi\
+             \nmovq stderr@GOTPCREL(%rip), %rdi\
+             \nmovq (%rdi), %rdi\
+             \nxorl %eax, %eax\
+             \ncall fprintf@PLT\
+             \ncall abort@PLT\
+             \n.section .rodata\
+             \n.Lassert_msg:\
+             \n.string \"Assertion failed at line %ld\\n\"\n"
+                .as_bytes(),
+        )
         .expect("Unable to write data");
 }
 
 /*
 ***********************************************************************
- THIS FUNCTION IS MEANT TO PROCESS EACH CODE STATEMENT AND GENERATE
- ASSEMBLY FOR IT.
- TIP: YOU CAN MODULARIZE BETTER AND ADD NEW SMALLER FUNCTIONS IF YOU
- WANT THAT CAN BE CALLED FROM HERE.
+ THIS FUNCTION IS MEANT TO PROCESS EACH THREE-ADDRESS INSTRUCTION AND
+ GENERATE ASSEMBLY FOR IT. OPERANDS ARE LOADED INTO RAX AND RCX, AND
+ THE RESULT IS SAVED FROM RAX TO THE SLOT OF THE DESTINATION.
 ************************************************************************
 */
 #[no_mangle]
-fn process_statements(
-    fileptr: &mut File,
-    statements: &RList,
-    glb: &mut globals,
-    var_list: &mut varStList,
-    reg_list: &mut regList,
-) {
-    let mut stmt = statements;
-    /*
-     ****************************************
-              TODO : YOUR CODE HERE
-     ****************************************
-    */
-
-    loop {
-        if let Some(node) = stmt.node.as_ref() {
-            /*
-             ****************************************
-                      TODO : YOUR CODE HERE
-             ****************************************
-            */
-            // if ASSIGN
-            if node.stmtCode == StmtType::ASSIGN {
-                if let Some(rightNode) = node.right.as_ref() {
-                    // if assigning variable to CONSTANT
-                    if rightNode.exprCode == ExprType::CONSTANT {
-                        let val = rightNode.value;
-                        long_to_char_offset(glb);
-                        reg_list.add_reg(&glb.last_offset_used.clone(), 0);
-                        var_list.add_var_info(node.name.clone(), glb.last_offset_used.clone(), val, true);
-                        fileptr
-                            .write_all(format!("\nmovq ${}, {}", val, glb.last_offset_used).as_bytes())
-                            .expect("Unable to write data");
-                    }
-
-                    // if assigning variable to VARIABLE OR PARAMETER
-                    else if rightNode.exprCode == ExprType::VARIABLE || rightNode.exprCode == ExprType::PARAMETER {
-                        let addy: String = var_list.lookup_var_info(rightNode.name.clone(), INVAL);
-                        long_to_char_offset(glb);
-                        reg_list.add_reg(&glb.last_offset_used.clone(), 0);
-                        var_list.add_var_info(node.name.clone(), glb.last_offset_used.clone(), INVAL, false);
-                        fileptr
-                            .write_all(format!("\nmovq {}, {}", addy, glb.last_offset_used).as_bytes())
-                            .expect("Unable to write data");
-                    }
-
-                    // if OPERATION
-                    else if rightNode.exprCode == ExprType::OPERATION {
-                        // if FUNCTIONCALL
-                        if rightNode.opCode == OpType::FUNCTIONCALL {
-                            let mut new_arg_counter: i64 = 0;
-                            if let Some(mut args) = rightNode.arguments.as_ref() {
-                                loop {
-                                    if let Some(arg) = args.node.as_ref() {
-                                        let mut dest: String = "%rdi".to_string();
-                                        new_arg_counter += 1;
-                                        if new_arg_counter == 2 {
-                                            dest = "%rsi".to_string();
-                                        }
-                                        else if new_arg_counter == 3 {
-                                            dest = "%rdx".to_string();
-                                        }
-                                        else if new_arg_counter == 4 {
-                                            dest = "%rcx".to_string();
-                                        }
-                                        else if new_arg_counter == 5 {
-                                            dest = "%r8".to_string();
-                                        }
-                                        else if new_arg_counter == 6 {
-                                            dest = "%r9".to_string();
-                                        }
-                                        else {
-                                            // this shouldn't happen
-                                        }
-
-                                        reg_list.update_reg_info(dest.clone(), 0);
-
-                                        if arg.exprCode == ExprType::CONSTANT {
-                                            let val = arg.value;
-                                            fileptr
-                                                .write_all(format!("\nmovq ${}, {}", val, dest.clone()).as_bytes())
-                                                .expect("Unable to write data");
-                                        }
-                                        else if arg.exprCode == ExprType::VARIABLE || arg.exprCode == ExprType::PARAMETER {
-                                            let addy: String = var_list.lookup_var_info(arg.name.clone(), INVAL);
-                                            fileptr
-                                                .write_all(format!("\nmovq {}, {}", addy, dest.clone()).as_bytes())
-                                                .expect("Unable to write data");
-                                        }
-                                        else {
-                                            // this shouldn't happen
-                                        }
-                                    }
-                                    else {
-                                        break;
-                                    }
-
-                                    if let Some(next_arg) = args.next.as_ref() {
-                                        args = next_arg;
-                                    }
-                                    else {
-                                        break;
-                                    }
-                                }
-                                // call the function
-                                if let Some(funcDecl) = rightNode.left.as_ref() {
-                                    fileptr
-                                        .write_all(format!("\ncall {}", funcDecl.name.clone()).as_bytes())
-                                        .expect("Unable to write data");
-                                }
-                                else {
-                                    // this shouldn't happen
-                                }
-                            }
-                        }
-
-                        // if not FUNCTION CALL
-                        else if let Some(leftOperand) = rightNode.left.as_ref() {
-                            if leftOperand.exprCode == ExprType::CONSTANT {
-                                let val = leftOperand.value;
-                                reg_list.update_reg_info("%rax".to_string(),0);
-                                fileptr
-                                    .write_all(format!("\nmovq ${}, %rax", val).as_bytes())
-                                    .expect("Unable to write data");
-                            }
-                            else if leftOperand.exprCode == ExprType::VARIABLE || leftOperand.exprCode == ExprType::PARAMETER {
-                                let addy: String = var_list.lookup_var_info(leftOperand.name.clone(), INVAL);
-                                reg_list.update_reg_info("%rax".to_string(),0);
-                                fileptr
-                                    .write_all(format!("\nmovq {}, %rax", addy).as_bytes())
-                                    .expect("Unable to write data");
-                            }
-                            else {
-                                // this shouldn't happen
-                            }
-
-                            if let Some(rightOperand) = rightNode.right.as_ref() {
-                                if rightOperand.exprCode == ExprType::CONSTANT {
-                                    let val = rightOperand.value;
-                                    reg_list.update_reg_info("%rcx".to_string(), 0);
-                                    fileptr
-                                        .write_all(format!("\nmovq ${}, %rcx", val).as_bytes())
-                                        .expect("Unable to write data");
-                                }
-                                else if rightOperand.exprCode == ExprType::VARIABLE || rightOperand.exprCode == ExprType::PARAMETER {
-                                    let addy: String = var_list.lookup_var_info(rightOperand.name.clone(), INVAL);
-                                    reg_list.update_reg_info("%rax".to_string(),0);
-                                    fileptr
-                                        .write_all(format!("\nmovq {}, %rcx", addy).as_bytes())
-                                        .expect("Unable to write data");
-                                }
-                                else {
-                                    // this shouldn't happen
-                                }
-                                
-                                // binary operation
-                                if rightNode.opCode == OpType::MULTIPLY {
-                                    fileptr
-                                        .write_all("\nimulq %rcx, %rax".as_bytes())
-                                        .expect("Unable to write data");
-                                }
-                                else if rightNode.opCode == OpType::DIVIDE {
-                                    fileptr
-                                        .write_all("\ncqto".as_bytes())
-                                        .expect("Unable to write data");
-                                    fileptr
-                                        .write_all("\nidivq %rcx".as_bytes())
-                                        .expect("Unable to write data");
-                                }
-                                else if rightNode.opCode == OpType::ADD {
-                                    fileptr
-                                        .write_all("\naddq %rcx, %rax".as_bytes())
-                                        .expect("Unable to write data");
-                                }
-                                else if rightNode.opCode == OpType::SUBTRACT {
-                                    fileptr
-                                        .write_all("\nsubq %rcx, %rax".as_bytes())
-                                        .expect("Unable to write data");
-                                }
-                                else if rightNode.opCode == OpType::BOR {
-                                    fileptr
-                                        .write_all("\norq %rcx, %rax".as_bytes())
-                                        .expect("Unable to write data");
-                                }
-                                else if rightNode.opCode == OpType::BAND {
-                                    fileptr
-                                        .write_all("\nandq %rcx, %rax".as_bytes())
-                                        .expect("Unable to write data");
-                                }
-                                else if rightNode.opCode == OpType::BXOR {
-                                    fileptr
-                                        .write_all("\nxorq %rcx, %rax".as_bytes())
-                                        .expect("Unable to write data");
-                                }
-                                else if rightNode.opCode == OpType::BSHR {
-                                    fileptr
-                                        .write_all("\nsarq %cl, %rax".as_bytes())
-                                        .expect("Unable to write data");
-                                }
-                                else if rightNode.opCode == OpType::BSHL {
-                                    fileptr
-                                        .write_all("\nsalq %cl, %rax".as_bytes())
-                                        .expect("Unable to write data");
-                                }
-                                else {
-                                    // this shouldn't happen
-                                }
-                            }
-
-                            // unary operation
-                            if rightNode.opCode == OpType::NEGATE {
-                                fileptr
-                                    .write_all("\nnegq %rax".as_bytes())
-                                    .expect("Unable to write data");
-                            }
-                        }
-
-                        // save val rax
-                        long_to_char_offset(glb);
-                        reg_list.add_reg(&glb.last_offset_used.clone(), 0);
-                        var_list.add_var_info(node.name.clone(), glb.last_offset_used.clone(), INVAL, false);
-                        fileptr
-                            .write_all(format!("\nmovq %rax, {}",glb.last_offset_used).as_bytes())
-                            .expect("Unable to write data");
-                    }
-                    
-                    else {
-                        // this shouldn't happen
-                    }
-                }
-                else {
-                    // this shouldn't happen
+fn process_instructions(fileptr: &mut File, func: &TacFunction, required_space: i64, var_list: &varStList) {
+    for instr in func.body.iter() {
+        match instr {
+            Instr::Copy { dest, src } => {
+                load_value(fileptr, src, "%rax", var_list);
+                save_rax(fileptr, dest, var_list);
+            }
+            Instr::Binary { dest, op, left, right } => {
+                if let Some((operand, code)) = strength_reduced(*op, left, right) {
+                    load_value(fileptr, operand, "%rax", var_list);
+                    fileptr
+                        .write_all(code.as_bytes())
+                        .expect("Unable to write data");
+                } else {
+                    load_value(fileptr, left, "%rax", var_list);
+                    load_value(fileptr, right, "%rcx", var_list);
+                    fileptr
+                        .write_all(binary_asm(*op).as_bytes())
+                        .expect("Unable to write data");
                 }
+                save_rax(fileptr, dest, var_list);
             }
-            else if node.stmtCode == StmtType::RETURN {
-                if let Some(leftNode) = node.left.as_ref() {
-                    if leftNode.exprCode == ExprType::CONSTANT {
-                        let val = leftNode.value;
-                        fileptr
-                            .write_all(format!("\nmovq ${}, %rax", val).as_bytes())
-                            .expect("Unable to write data");
-                    }
-
-                    else if leftNode.exprCode == ExprType::VARIABLE || leftNode.exprCode == ExprType::PARAMETER {
-                        let addy: String = var_list.lookup_var_info(leftNode.name.clone(), INVAL);
+            Instr::Unary { dest, op: OpType::ADDRESSOF, src } => {
+                // only variables and parameters can have their address taken
+                let addy: String = var_list.lookup_var_info(src.to_string(), INVAL);
+                fileptr
+                    .write_all(format!("\nleaq {}, %rax", addy).as_bytes())
+                    .expect("Unable to write data");
+                save_rax(fileptr, dest, var_list);
+            }
+            Instr::Unary { dest, op, src } => {
+                load_value(fileptr, src, "%rax", var_list);
+                fileptr
+                    .write_all(unary_asm(*op).as_bytes())
+                    .expect("Unable to write data");
+                save_rax(fileptr, dest, var_list);
+            }
+            Instr::Call { dest, func: callee, args } => {
+                process_call(fileptr, callee, args, var_list);
+                if let Some(dest) = dest {
+                    save_rax(fileptr, dest, var_list);
+                    // save the second field of a returned struct before anything
+                    // else can clobber %rdx, if it is read later on
+                    let high_slot: String = var_list.lookup_var_info(high_slot_name(dest), INVAL);
+                    if !high_slot.is_empty() {
                         fileptr
-                            .write_all(format!("\nmovq {}, %rax", addy).as_bytes())
+                            .write_all(format!("\nmovq %rdx, {}", high_slot).as_bytes())
                             .expect("Unable to write data");
                     }
-
-                    else {
-                        // this shouldn't happen
-                    }
                 }
             }
-        } else {
-            break;
-        }
-        if let Some(next) = stmt.next.as_ref() {
-            stmt = next;
-        } else {
-            break;
+            Instr::ResultHigh { dest, low } => {
+                // without a variable, the call is the instruction just before
+                let slot: String = match low {
+                    Some(low) => var_list.lookup_var_info(high_slot_name(low), INVAL),
+                    None => "%rdx".to_string(),
+                };
+                fileptr
+                    .write_all(format!("\nmovq {}, %rax", slot).as_bytes())
+                    .expect("Unable to write data");
+                save_rax(fileptr, dest, var_list);
+            }
+            Instr::Store { addr, src } => {
+                load_value(fileptr, addr, "%rax", var_list);
+                load_value(fileptr, src, "%rcx", var_list);
+                fileptr
+                    .write_all("\nmovq %rcx, (%rax)".as_bytes())
+                    .expect("Unable to write data");
+            }
+            Instr::Assert { cond, line } => {
+                process_assert(fileptr, cond, *line, var_list);
+            }
+            Instr::Ret { value, high } => {
+                // a struct of two fields is returned in %rax and %rdx
+                if let Some(high) = high {
+                    load_value(fileptr, high, "%rdx", var_list);
+                }
+                load_value(fileptr, value, "%rax", var_list);
+                leave_asm(fileptr, required_space);
+            }
         }
     }
+
+    // a function may also end without a return
+    if !matches!(func.body.last(), Some(Instr::Ret { .. })) {
+        leave_asm(fileptr, required_space);
+    }
 }
 
 /*
  ***********************************************************************
-  THIS FUNCTION IS MEANT TO DO CODEGEN FOR ALL THE FUNCTIONS IN THE FILE
+  THIS FUNCTION IS MEANT TO DO CODEGEN FOR ALL THE FUNCTIONS IN THE FILE,
+  ONCE THEY ARE LOWERED TO THREE-ADDRESS CODE
  ************************************************************************
 */
 #[no_mangle]
-pub fn Codegen(mut worklist: &mut RList) {
-    /*
-     ****************************************
-              TODO : YOUR CODE HERE
-     ****************************************
-    */
-
+pub fn Codegen(program: &TacProgram) {
     let mut fileptr = File::create("assembly.s").expect("Unable to create assembly file");
+    labelCounter.store(0, Ordering::Relaxed);
+    usesAssert.store(false, Ordering::Relaxed);
 
-    // create a register list, set everything to available
-    let mut rlist = regList::new();
-    create_reg_list(&mut rlist);
-
-    // create an empty variable list
-    let mut vlist = varStList::new();
-    loop {
-        /*
-        ****************************************
-                 TODO : YOUR CODE HERE
-        ****************************************
-        */
-        // create a globals struct
+    for func in program.functions.iter() {
+        // create a globals struct and an empty variable list: slots are local to their function
         let mut glbls = globals::new();
-       
-        if let Some(mut node) = worklist.node.as_mut() {
+        let mut vlist = varStList::new();
+        assign_slots(func, &mut glbls, &mut vlist);
 
-            init_asm(&mut fileptr, node.name.clone());
-            // subtract from rsp
-            let required_space: i64 = calculate_required_space(node);
-            // decrement stack pointer, making space for arguments and local variables
-            fileptr
-                .write_all(format!("\nsubq ${}, %rsp", required_space).as_bytes())
-                .expect("Unable to write data");
-
-
-            // push arguments onto stack
-            if let Some(mut arguments) = node.arguments.as_mut() {
-                push_arg_on_stack(&mut fileptr, arguments, &mut glbls, &mut vlist, &mut rlist);
-            }
-
-            if let Some(mut statements) = node.statements.as_mut() {
-                process_statements(&mut fileptr, statements, &mut glbls, &mut vlist, &mut rlist);
-            }
-
-            fileptr
-                .write_all(format!("\naddq ${}, %rsp", required_space).as_bytes())
-                .expect("Unable to write data");
-            
-            ret_asm(&mut fileptr);
+        init_asm(&mut fileptr, func.name.clone(), func.is_static);
+        // decrement stack pointer, making space for arguments and local variables
+        let required_space: i64 = calculate_required_space(&glbls);
+        fileptr
+            .write_all(format!("\nsubq ${}, %rsp", required_space).as_bytes())
+            .expect("Unable to write data");
 
-        } else {
-            break;
-        }
+        push_arg_on_stack(&mut fileptr, &func.params, &vlist);
+        process_instructions(&mut fileptr, func, required_space, &vlist);
+    }
 
-        if let Some(next) = worklist.next.as_mut() {
-            worklist = next;
-        } else {
-            break;
-        }
+    if usesAssert.load(Ordering::Relaxed) {
+        assert_trap_asm(&mut fileptr);
     }
 }
 
@@ -1004,53 +753,110 @@ pub fn Codegen(mut worklist: &mut RList) {
  YOU CAN MAKE ADD AUXILLIARY FUNCTIONS BELOW THIS LINE. DO NOT FORGET TO DECLARE THEM IN THE HEADER
 **********************************************************************************************************************************
 */
-fn calculate_required_space(mut funcNode: &mut RNode) -> i64 {
-    // find number of arguments
-    let mut argument_counter: i64 = 0;
-    if let Some(mut args) = funcNode.arguments.as_mut() {
-        loop {
-            if let Some(arg) = args.node.as_mut() {
-                argument_counter += 1;
-            }
-            else {
-                break;
-            }
+// Every slot handed out is 8 bytes below the last one
+fn calculate_required_space(glb: &globals) -> i64 {
+    // keep the stack pointer 16-byte aligned for calls
+    (-glb.last_used_offset + 15) & !15
+}
+/*
+**********************************************************************************************************************************
+ YOU CAN MAKE ADD AUXILLIARY FUNCTIONS ABOVE THIS LINE. DO NOT FORGET TO DECLARE THEM IN THE HEADER
+**********************************************************************************************************************************
+*/
 
-            if let Some(next_arg) = args.next.as_mut() {
-                args = next_arg;
-            }
-            else {
-                break;
-            }
-        }
+#[cfg(test)]
+mod tests {
+    use super::{assign_slots, calculate_required_space, globals, imm_to, reduce_by_constant, varStList, INVAL};
+    use crate::expression::OpType;
+    use crate::tac::ParseTac;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn wide_immediates_use_movabsq() {
+        assert_eq!(imm_to(-2147483648, "%rax"), "\nmovq $-2147483648, %rax");
+        assert_eq!(imm_to(2147483648, "%rcx"), "\nmovabsq $2147483648, %rcx");
+        assert_eq!(
+            imm_to(i64::MIN, "-8(%rbp)"),
+            "\nmovabsq $-9223372036854775808, %r11\nmovq %r11, -8(%rbp)"
+        );
     }
 
-    // find number of local variables
-    let mut assign_counter: i64 = 0;
-    if let Some(mut stmts) = funcNode.statements.as_mut() {
-        loop {
-            if let Some(stmt) = stmts.node.as_mut() {
-                if stmt.stmtCode == StmtType::ASSIGN {
-                    assign_counter += 1;
+    #[test]
+    fn constant_multiplies_and_divides_get_cheaper() {
+        assert_eq!(reduce_by_constant(OpType::MULTIPLY, 8), Some("\nsalq $3, %rax".to_string()));
+        assert_eq!(reduce_by_constant(OpType::MULTIPLY, 9), Some("\nleaq (%rax,%rax,8), %rax".to_string()));
+        assert_eq!(
+            reduce_by_constant(OpType::DIVIDE, -4),
+            Some("\nmovq %rax, %rcx\nsarq $63, %rcx\nshrq $62, %rcx\naddq %rcx, %rax\nsarq $2, %rax\nnegq %rax".to_string())
+        );
+        assert_eq!(reduce_by_constant(OpType::MULTIPLY, 6), None);
+    }
+
+    // Run the generated code for n / d on the registers it uses
+    fn run(code: &str, n: i64) -> i64 {
+        let mut regs: BTreeMap<&str, i64> = BTreeMap::from([("%rax", n)]);
+        for line in code.lines().filter(|line| !line.is_empty()) {
+            let (instr, operands) = line.split_once(' ').unwrap();
+            let operands: Vec<&str> = operands.split(", ").collect();
+            let value = |regs: &BTreeMap<&str, i64>, operand: &str| match operand.strip_prefix('$') {
+                Some(imm) => imm.parse::<i64>().unwrap(),
+                None => regs[operand],
+            };
+            let dest = *operands.last().unwrap();
+            let result = match instr {
+                "movq" | "movabsq" => value(&regs, operands[0]),
+                "addq" => regs[dest].wrapping_add(value(&regs, operands[0])),
+                "subq" => regs[dest].wrapping_sub(value(&regs, operands[0])),
+                "sarq" => regs[dest] >> value(&regs, operands[0]),
+                "shrq" => ((regs[dest] as u64) >> value(&regs, operands[0])) as i64,
+                "negq" => regs[dest].wrapping_neg(),
+                "imulq" => {
+                    let product = regs["%rax"] as i128 * regs[dest] as i128;
+                    regs.insert("%rax", product as i64);
+                    (product >> 64) as i64
                 }
-            }
-            else {
-                break;
-            }
+                _ => panic!("unexpected {}", line),
+            };
+            regs.insert(if instr == "imulq" { "%rdx" } else { dest }, result);
+        }
+        regs["%rax"]
+    }
 
-            if let Some(next_stmt) = stmts.next.as_mut() {
-                stmts = next_stmt;
-            }
-            else {
-                break;
+    #[test]
+    fn constant_divides_match_idivq() {
+        let mut divisors: Vec<i64> = vec![i64::MIN, i64::MIN + 1, i64::MAX, -1, 1, 3, -3, 5, 6, 7, -7, 10, 641, -1000];
+        divisors.extend((1..63).flat_map(|k| [1i64 << k, -(1i64 << k), (1i64 << k) + 1, (1i64 << k) - 1]));
+        let dividends = [i64::MIN, i64::MIN + 1, -1000000007, -7, -1, 0, 1, 6, 7, 1000000007, i64::MAX - 1, i64::MAX];
+        for d in divisors {
+            let code = reduce_by_constant(OpType::DIVIDE, d).unwrap();
+            let around_d = [d, d.wrapping_add(1), d.wrapping_sub(1), d.wrapping_neg(), d.wrapping_mul(3)];
+            for n in dividends.iter().chain(around_d.iter()) {
+                // i64::MIN / -1 traps in idivq; the generated code wraps
+                assert_eq!(run(&code, *n), n.wrapping_div(d), "{} / {}", n, d);
             }
         }
     }
 
-    return (assign_counter + argument_counter) * 8;
+    #[test]
+    fn values_never_live_together_share_a_slot() {
+        let tac = ParseTac(
+            "func foo(a) {\n\
+             \tr.x = call mk(a)\n\
+             \tr.y = high(r.x)\n\
+             \tt1 = r.x + 3\n\
+             \tret t1, r.y\n\
+             }\n",
+        )
+        .unwrap();
+        let mut glb = globals::new();
+        let mut vlist = varStList::new();
+
+        assign_slots(&tac.functions[0], &mut glb, &mut vlist);
+
+        let slot = |name: &str| vlist.lookup_var_info(name.to_string(), INVAL);
+        assert_eq!([slot("a"), slot("r.x"), slot("t1")], ["-8(%rbp)", "-8(%rbp)", "-8(%rbp)"]);
+        assert_eq!(slot("r.y"), "-16(%rbp)");
+        assert_eq!(slot("high(r.x)"), "-24(%rbp)");
+        assert_eq!(calculate_required_space(&glb), 32);
+    }
 }
-/*
-**********************************************************************************************************************************
- YOU CAN MAKE ADD AUXILLIARY FUNCTIONS ABOVE THIS LINE. DO NOT FORGET TO DECLARE THEM IN THE HEADER
-**********************************************************************************************************************************
-*/
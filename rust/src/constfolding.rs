@@ -10,6 +10,7 @@ lazy_static! {
     static ref madeChange: AtomicBool = AtomicBool::new(false);
 }
 
+use crate::dataflow::ExprKey;
 use crate::expression::*;
 
 /*
@@ -22,116 +23,127 @@ use crate::expression::*;
 
 /*
 ******************************************************************************************
-FUNCTION TO CALCULATE THE CONSTANT EXPRESSION VALUE
-OBSERVE THAT THIS IMPLEMENTATION CONSIDERS ADDITIONAL OPTIMIZATIONS SUCH AS:
-1.  IDENTITY MULTIPLY = 1 * ANY_VALUE = ANY_VALUE - AVOID MULTIPLICATION CYCLE IN THIS CASE
-2.  ZERO MULTIPLY = 0 * ANY_VALUE = 0 - AVOID MULTIPLICATION CYCLE
-3.  DIVIDE BY ONE = ORIGINAL_VALUE - AVOID DIVISION CYCLE
-4.  SUBTRACT BY ZERO = ORIGINAL_VALUE - AVOID SUBTRACTION
-5.  MULTIPLICATION BY 2 = ADDITION BY SAME VALUE [STRENGTH REDUCTION]
+FUNCTION TO CALCULATE THE VALUE OF ONE OPERATION ON CONSTANT OPERANDS, BIT FOR BIT AS THE
+GENERATED CODE COMPUTES IT AT RUNTIME:
+1.  ADDITION, SUBTRACTION, MULTIPLICATION AND NEGATION WRAP AROUND, LIKE addq, subq, imulq
+    AND negq
+2.  SHIFT COUNTS ARE TAKEN MODULO 64, LIKE salq, sarq AND shrq DO WITH A COUNT IN %cl
+3.  A DIVISION THAT TRAPS IN idivq, BY ZERO OR OF i64::MIN BY -1, HAS NO VALUE AND IS AN
+    ERROR, AS IS AN OPERATION THAT IS ONLY KNOWN AT RUNTIME
 ******************************************************************************************
 */
+pub fn CalcExprValue(op: OpType, left: i64, right: Option<i64>) -> Result<i64, String> {
+    match (op, right) {
+        (OpType::NEGATE, None) => Ok(left.wrapping_neg()),
+        (OpType::BNOT, None) => Ok(!left),
+        (OpType::ADD, Some(right)) => Ok(left.wrapping_add(right)),
+        (OpType::SUBTRACT, Some(right)) => Ok(left.wrapping_sub(right)),
+        (OpType::MULTIPLY, Some(right)) => Ok(left.wrapping_mul(right)),
+        (OpType::DIVIDE, Some(0)) => Err(format!("division of {} by zero", left)),
+        (OpType::DIVIDE, Some(-1)) if left == i64::MIN => Err(format!("division of {} by -1 overflows", left)),
+        (OpType::DIVIDE, Some(right)) => Ok(left / right),
+        // wrapping shifts keep the low six bits of the count
+        (OpType::BSHL, Some(right)) => Ok(left.wrapping_shl(right as u32)),
+        (OpType::BSHR, Some(right)) => Ok(left.wrapping_shr(right as u32)),
+        (OpType::BLSHR, Some(right)) => Ok((left as u64).wrapping_shr(right as u32) as i64),
+        (OpType::BAND, Some(right)) => Ok(left & right),
+        (OpType::BOR, Some(right)) => Ok(left | right),
+        (OpType::BXOR, Some(right)) => Ok(left ^ right),
+        _ => Err(format!("{:?} is only known at runtime", op)),
+    }
+}
 
-#[no_mangle]
-fn CalcExprValue(node: &mut RNode) -> i64 {
-    if let Some(leftNode) = node.left.as_mut() {
-        match node.opCode {
-            OpType::MULTIPLY => {
-                if let Some(rightNode) = node.right.as_mut() {
-                    if leftNode.value == 1 {
-                        return rightNode.value;
-                    } else if rightNode.value == 1 {
-                        return leftNode.value;
-                    } else if leftNode.value == 0 || rightNode.value == 0 {
-                        return 0;
-                    } else if leftNode.value == 2 {
-                        return rightNode.value + rightNode.value;
-                    } else if rightNode.value == 2 {
-                        return leftNode.value + leftNode.value;
-                    } else {
-                        return leftNode.value * rightNode.value;
-                    }
-                } else {
-                    return -1;
-                }
-            }
-            OpType::DIVIDE => {
-                if let Some(rightNode) = node.right.as_mut() {
-                    if rightNode.value == 1 {
-                        return leftNode.value;
-                    } else {
-                        return leftNode.value / rightNode.value;
-                    }
-                } else {
-                    return -1;
-                }
-            }
-            OpType::ADD => {
-                if let Some(rightNode) = node.right.as_mut() {
-                    return leftNode.value + rightNode.value;
-                } else {
-                    return -1;
-                }
-            }
-            OpType::SUBTRACT => {
-                if let Some(rightNode) = node.right.as_mut() {
-                    return leftNode.value - rightNode.value;
-                } else {
-                    return -1;
-                }
-            } 
-            OpType::NEGATE => {
-                return -leftNode.value;
-            }
-            OpType::BSHR => {
-                if let Some(rightNode) = node.right.as_mut() {
-                    return leftNode.value >> rightNode.value;
-                } else {
-                    return -1;
-                }
-            }
-            OpType::BSHL => {
-                if let Some(rightNode) = node.right.as_mut() {
-                    return leftNode.value << rightNode.value;
-                } else {
-                    return -1;
-                }
-            }
-            OpType::BAND => {
-                if let Some(rightNode) = node.right.as_mut() {
-                    return leftNode.value & rightNode.value;
-                } else {
-                    return -1;
-                }
-            }
-            OpType::BOR => {
-                if let Some(rightNode) = node.right.as_mut() {
-                    return leftNode.value | rightNode.value;
-                } else {
-                    return -1;
-                }
-            }
-            OpType::BXOR => {
-                if let Some(rightNode) = node.right.as_mut() {
-                    return leftNode.value ^ rightNode.value;
-                } else {
-                    return -1;
-                }
+/*
+**********************************************************************************************************************************
+// YOU CAN MAKE CHANGES/ADD AUXILLIARY FUNCTIONS BELOW THIS LINE
+**********************************************************************************************************************************
+*/
+
+/*
+*****************************************************************************************************
+FUNCTION TO BUILD A FRESH CONSTANT EXPRESSION NODE HOLDING THE GIVEN VALUE
+******************************************************************************************************
+*/
+pub fn ConstNode(value: i64) -> Box<RNode> {
+    Box::new(RNode::new(
+        "".to_string(),
+        value,
+        NodeType::EXPRESSION,
+        ExprType::CONSTANT,
+        OpType::O_NONE,
+        StmtType::S_NONE,
+        None,
+        None,
+        None,
+        None,
+    ))
+}
+
+/*
+*****************************************************************************************************
+THIS FUNCTION EVALUATES A WHOLE EXPRESSION TREE AT COMPILE TIME, AS REQUIRED FOR `const` INITIALIZERS.
+LEAVES MUST BE CONSTANTS OR REFERENCES TO OTHER `const` DECLARATIONS, AND EVERY OPERATION IS REDUCED
+WITH CalcExprValue. RETURNS NONE AS SOON AS THE TREE READS A VARIABLE OR A PARAMETER, OR CALLS A FUNCTION.
+******************************************************************************************************
+*/
+pub fn EvalConstExpr(node: &RNode) -> Option<i64> {
+    match node.exprCode {
+        ExprType::CONSTANT => Some(node.value),
+        ExprType::VARIABLE => {
+            // a variable node links back to its declaration
+            let decl = node.left.as_ref()?;
+            if decl.stmtCode != StmtType::CONSTDECL {
+                return None;
             }
-            _ => {
-                return 0;
+            EvalConstExpr(decl.right.as_ref()?)
+        }
+        ExprType::OPERATION => {
+            if node.opCode == OpType::FUNCTIONCALL
+                || node.opCode == OpType::O_NONE
+                || node.opCode == OpType::DEREF
+                || node.opCode == OpType::ADDRESSOF
+            {
+                return None;
             }
+            let left = EvalConstExpr(node.left.as_ref()?)?;
+            let right = match node.right.as_ref() {
+                Some(right_node) => Some(EvalConstExpr(right_node)?),
+                None => None,
+            };
+            FoldOperation(node.opCode, left, right)
         }
-    } else {
-        return -1;
+        _ => None,
     }
 }
 
 /*
-**********************************************************************************************************************************
-// YOU CAN MAKE CHANGES/ADD AUXILLIARY FUNCTIONS BELOW THIS LINE
-**********************************************************************************************************************************
+*****************************************************************************************************
+THIS FUNCTION APPLIES ONE OPERATION TO CONSTANT OPERANDS WITH CalcExprValue. IT RETURNS NONE FOR WHAT
+MUST BE LEFT TO RUNTIME: OPERATIONS ON MEMORY OR CALLS, AND DIVISIONS THAT TRAP.
+******************************************************************************************************
 */
+pub fn FoldOperation(op: OpType, left: i64, right: Option<i64>) -> Option<i64> {
+    CalcExprValue(op, left, right).ok()
+}
+
+/*
+*****************************************************************************************************
+THIS FUNCTION FINDS A DIVISION OF CONSTANTS IN AN EXPRESSION THAT CANNOT BE FOLDED BECAUSE IT TRAPS,
+AND SAYS WHY
+******************************************************************************************************
+*/
+pub fn ConstantTrap(node: &RNode) -> Option<String> {
+    if node.exprCode == ExprType::OPERATION && node.opCode == OpType::DIVIDE {
+        let left = node.left.as_ref().and_then(|left| EvalConstExpr(left));
+        let right = node.right.as_ref().and_then(|right| EvalConstExpr(right));
+        if let (Some(left), Some(right)) = (left, right) {
+            if let Err(msg) = CalcExprValue(node.opCode, left, Some(right)) {
+                return Some(msg);
+            }
+        }
+    }
+    node.subexpressions().into_iter().find_map(ConstantTrap)
+}
 
 /*
 *****************************************************************************************************
@@ -149,8 +161,8 @@ fn ConstFoldPerStatement(stmtNodeRight: &mut RNode) {
     if let Some(left_node) = stmtNodeRight.left.as_mut() {
         if let Some(right_node) = stmtNodeRight.right.as_mut() {
             if left_node.exprCode == ExprType::CONSTANT && right_node.exprCode == ExprType::CONSTANT {
-                let result: i64 = CalcExprValue(stmtNodeRight);
-                if result != -1 {
+                let folded = CalcExprValue(stmtNodeRight.opCode, left_node.value, Some(right_node.value));
+                if let Ok(result) = folded {
                     stmtNodeRight.name = "".to_string();
                     stmtNodeRight.value = result;
                     
@@ -170,8 +182,8 @@ fn ConstFoldPerStatement(stmtNodeRight: &mut RNode) {
         }
         else {
             if left_node.exprCode == ExprType::CONSTANT {
-                let result: i64 = CalcExprValue(stmtNodeRight);
-                if result != -1 {
+                let folded = CalcExprValue(stmtNodeRight.opCode, left_node.value, None);
+                if let Ok(result) = folded {
                     stmtNodeRight.name = "".to_string();
                     stmtNodeRight.value = result;
                     
@@ -193,6 +205,132 @@ fn ConstFoldPerStatement(stmtNodeRight: &mut RNode) {
 
 }
 
+/*
+*****************************************************************************************************
+FUNCTION TO TELL WHETHER AN ASSERT IS ALREADY DECIDED AT COMPILE TIME: SOME(TRUE) IF ITS CONDITION
+FOLDED TO A NON-ZERO CONSTANT, SOME(FALSE) IF IT FOLDED TO ZERO AND NONE IF IT IS ONLY KNOWN AT RUNTIME
+******************************************************************************************************
+*/
+fn AssertOutcome(assertNode: &RNode) -> Option<bool> {
+    let cond = assertNode.left.as_ref()?;
+    if cond.exprCode == ExprType::CONSTANT {
+        Some(cond.value != 0)
+    } else {
+        None
+    }
+}
+
+/*
+*****************************************************************************************************
+WHAT AN ALGEBRAIC IDENTITY REPLACES AN OPERATION WITH: ONE OF ITS OPERANDS, THE OPERAND OF ITS
+OPERAND (FOR --x AND ~~x), OR A CONSTANT
+******************************************************************************************************
+*/
+enum Rewrite {
+    Left,
+    Right,
+    Inner,
+    Const(i64),
+}
+
+fn IsConst(node: Option<&RNode>, value: i64) -> bool {
+    node.is_some_and(|node| node.exprCode == ExprType::CONSTANT && node.value == value)
+}
+
+// An operand can only be dropped when evaluating it has no effect
+fn CanDrop(node: Option<&RNode>) -> bool {
+    node.is_some_and(|node| !node.contains_op(OpType::FUNCTIONCALL))
+}
+
+// Whether two operands always read the same value
+fn SameValue(left: Option<&RNode>, right: Option<&RNode>) -> bool {
+    match (left, right) {
+        (Some(left), Some(right)) => {
+            CanDrop(Some(left)) && CanDrop(Some(right)) && ExprKey(left) == ExprKey(right)
+        }
+        _ => false,
+    }
+}
+
+// Whether an operand can never be zero: a non-zero constant, or anything ORed with one
+fn KnownNonZero(node: Option<&RNode>) -> bool {
+    node.is_some_and(|node| match (node.exprCode, node.opCode) {
+        (ExprType::CONSTANT, _) => node.value != 0,
+        (ExprType::OPERATION, OpType::BOR) => KnownNonZero(node.left.as_deref()) || KnownNonZero(node.right.as_deref()),
+        _ => false,
+    })
+}
+
+/*
+*****************************************************************************************************
+THE ALGEBRAIC IDENTITIES THAT HOLD WHATEVER THE VALUE OF THE OTHER OPERAND:
+x*1, x+0, x-0, x|0, x^0, x<<0, x>>0, x>>>0 AND x/1 ARE x; x*0 AND x&0 ARE 0; x&x AND x|x ARE x;
+x-x AND x^x ARE 0; --x AND ~~x ARE x; 0/x IS 0 WHEN x CANNOT BE ZERO
+******************************************************************************************************
+*/
+fn SimplifyRule(node: &RNode) -> Option<Rewrite> {
+    let (left, right) = (node.left.as_deref(), node.right.as_deref());
+    match node.opCode {
+        OpType::MULTIPLY if IsConst(right, 1) => Some(Rewrite::Left),
+        OpType::MULTIPLY if IsConst(left, 1) => Some(Rewrite::Right),
+        OpType::MULTIPLY | OpType::BAND if (IsConst(right, 0) && CanDrop(left)) || (IsConst(left, 0) && CanDrop(right)) => {
+            Some(Rewrite::Const(0))
+        }
+        OpType::ADD | OpType::BOR | OpType::BXOR if IsConst(left, 0) => Some(Rewrite::Right),
+        OpType::ADD | OpType::SUBTRACT | OpType::BOR | OpType::BXOR | OpType::BSHL | OpType::BSHR | OpType::BLSHR
+            if IsConst(right, 0) =>
+        {
+            Some(Rewrite::Left)
+        }
+        OpType::DIVIDE if IsConst(right, 1) => Some(Rewrite::Left),
+        OpType::DIVIDE if IsConst(left, 0) && KnownNonZero(right) && CanDrop(right) => Some(Rewrite::Const(0)),
+        OpType::SUBTRACT | OpType::BXOR if SameValue(left, right) => Some(Rewrite::Const(0)),
+        OpType::BAND | OpType::BOR if SameValue(left, right) => Some(Rewrite::Left),
+        OpType::NEGATE | OpType::BNOT if left.is_some_and(|inner| inner.exprCode == ExprType::OPERATION && inner.opCode == node.opCode) => {
+            Some(Rewrite::Inner)
+        }
+        _ => None,
+    }
+}
+
+/*
+*****************************************************************************************************
+THIS FUNCTION APPLIES THE ALGEBRAIC IDENTITIES EVERYWHERE IN AN EXPRESSION, INNERMOST FIRST, SO THAT
+OPERATIONS WITH ONE NON-CONSTANT OPERAND ARE SIMPLIFIED TOO. NODES ARE REWRITTEN IN PLACE.
+******************************************************************************************************
+*/
+fn SimplifyExpr(node: &mut RNode) {
+    if node.exprCode != ExprType::OPERATION || node.opCode == OpType::ADDRESSOF {
+        return;
+    }
+    if node.opCode == OpType::FUNCTIONCALL {
+        if let Some(args) = node.arguments.as_mut() {
+            for arg in args.iter_mut() {
+                SimplifyExpr(arg);
+            }
+        }
+        return;
+    }
+    if let Some(left) = node.left.as_mut() {
+        SimplifyExpr(left);
+    }
+    if let Some(right) = node.right.as_mut() {
+        SimplifyExpr(right);
+    }
+
+    let replacement = match SimplifyRule(node) {
+        Some(Rewrite::Left) => node.left.take(),
+        Some(Rewrite::Right) => node.right.take(),
+        Some(Rewrite::Inner) => node.left.take().and_then(|mut inner| inner.left.take()),
+        Some(Rewrite::Const(value)) => Some(ConstNode(value)),
+        None => None,
+    };
+    if let Some(replacement) = replacement {
+        *node = *replacement;
+        madeChange.store(true, Ordering::Relaxed);
+    }
+}
+
 /*
 *****************************************************************************************************
 THIS FUNCTION IS MEANT TO IDENTIFY THE STATEMENTS THAT ARE ACTUAL CANDIDATES FOR CONSTANT FOLDING
@@ -202,23 +340,42 @@ AND CALL THE APPROPRIATE FUNCTION FOR THE IDENTIFIED CANDIDATE'S CONSTANT FOLDIN
 #[no_mangle]
 fn ConstFoldPerFunction(funcNode: &mut RNode) {
     if let Some(mut statements) = funcNode.statements.as_mut() {
-        loop {
-            if let Some(node) = statements.node.as_mut() {
-                if node.stmtCode == StmtType::ASSIGN {
-                    if let Some(node_right) = node.right.as_mut() {
-                        if node_right.type_ == NodeType::EXPRESSION {
-                            if node_right.exprCode == ExprType::OPERATION {
-                                if node_right.opCode != OpType::FUNCTIONCALL &&
-                                node_right.opCode != OpType::O_NONE {
-                                    ConstFoldPerStatement(node_right);
-                                }
-                            }
-                        }
+        while let Some(node) = statements.node.as_mut() {
+            // the expressions of a statement hang from left and right
+            if node.type_ == NodeType::STATEMENT {
+                for side in [node.left.as_mut(), node.right.as_mut()].into_iter().flatten() {
+                    SimplifyExpr(side);
+                }
+            }
+            if node.stmtCode == StmtType::ASSIGN {
+                if let Some(node_right) = node.right.as_mut() {
+                    if node_right.type_ == NodeType::EXPRESSION &&
+                    node_right.exprCode == ExprType::OPERATION &&
+                    node_right.opCode != OpType::FUNCTIONCALL &&
+                    node_right.opCode != OpType::O_NONE {
+                        ConstFoldPerStatement(node_right);
                     }
                 }
             }
-            else {
-                break;
+            else if node.stmtCode == StmtType::ASSERT {
+                if let Some(cond) = node.left.as_mut() {
+                    if cond.exprCode == ExprType::OPERATION &&
+                    cond.opCode != OpType::FUNCTIONCALL &&
+                    cond.opCode != OpType::O_NONE {
+                        ConstFoldPerStatement(cond);
+                    }
+                }
+                // the check is dropped once its condition is proven true
+                if AssertOutcome(node) == Some(true) {
+                    if let Some(next_stmt) = statements.next.take() {
+                        **statements = *next_stmt;
+                    }
+                    else {
+                        statements.node = None;
+                    }
+                    madeChange.store(true, Ordering::Relaxed);
+                    continue;
+                }
             }
             if let Some(next_node) = statements.next.as_mut() {
                 statements = next_node;
@@ -268,8 +425,157 @@ pub fn ConstantFolding(mut worklist: &mut RList) -> bool {
     return res;
 }
 
+/*
+*****************************************************************************************************
+THIS FUNCTION RETURNS THE SOURCE LINE OF THE FIRST ASSERT THAT THE OPTIMIZER PROVED FALSE, IF ANY.
+IT IS MEANT TO RUN ONCE FOLDING AND PROPAGATION HAVE REACHED THEIR FIXED POINT.
+******************************************************************************************************
+*/
+pub fn FailedAssert(worklist: &RList) -> Option<i64> {
+    for func in worklist.iter() {
+        if let Some(statements) = func.statements.as_ref() {
+            for stmt in statements.iter() {
+                if stmt.stmtCode == StmtType::ASSERT && AssertOutcome(stmt) == Some(false) {
+                    return Some(stmt.value);
+                }
+            }
+        }
+    }
+    None
+}
+
+/*
+*****************************************************************************************************
+THIS FUNCTION LISTS THE DIVISIONS LEFT IN THE PROGRAM THAT ARE KNOWN TO TRAP WHEN THEY RUN. LIKE AN
+ASSERT THAT IS ALWAYS FALSE, EACH ONE IS A COMPILE ERROR.
+******************************************************************************************************
+*/
+pub fn ConstantTraps(worklist: &RList) -> Vec<String> {
+    let mut traps: Vec<String> = Vec::new();
+    for func in worklist.iter() {
+        for stmt in func.statements.iter().flat_map(|list| list.iter()) {
+            if let Some(msg) = ConstantTrap(stmt) {
+                traps.push(format!("in function '{}': {}", func.name, msg));
+            }
+        }
+    }
+    traps
+}
+
 /*
 ****************************************************************************************************************************
 END OF CONSTANT FOLDING
 *****************************************************************************************************************************
 */
+
+#[cfg(test)]
+mod tests {
+    use super::{CalcExprValue, ConstantFolding, ConstantTraps, FailedAssert};
+    use crate::expression::build::*;
+    use crate::expression::*;
+
+    #[test]
+    fn folds_bitwise_not_and_logical_shift() {
+        // long foo() { long a = ~5; long b = -16 >>> 60; return a; }
+        let mut program = program(vec![func(
+            "foo",
+            vec![],
+            vec![
+                assign("a", unop(OpType::BNOT, constant(5))),
+                assign("b", binop(OpType::BLSHR, constant(-16), constant(60))),
+                ret(var("a")),
+            ],
+        )]);
+
+        assert!(ConstantFolding(&mut program));
+
+        let body = body(&program, 0);
+        assert_eq!(body[0].right.as_ref().unwrap().value, -6);
+        assert_eq!(body[1].right.as_ref().unwrap().value, 15);
+    }
+
+    #[test]
+    fn discharges_asserts_known_at_compile_time() {
+        // long foo() { assert(2 - 1); assert(1 - 1); return 0; }
+        let mut program = program(vec![func(
+            "foo",
+            vec![],
+            vec![
+                assert_at(3, binop(OpType::SUBTRACT, constant(2), constant(1))),
+                assert_at(4, binop(OpType::SUBTRACT, constant(1), constant(1))),
+                ret(constant(0)),
+            ],
+        )]);
+
+        assert!(ConstantFolding(&mut program));
+
+        let body = body(&program, 0);
+        assert_eq!(body.len(), 2);
+        assert_eq!(body[0].stmtCode, StmtType::ASSERT);
+        assert_eq!(FailedAssert(&program), Some(4));
+    }
+
+    #[test]
+    fn simplifies_identities_with_one_variable_operand() {
+        // long foo(long x) { long a = x * 1 + 0; long b = x - x; long c = -(-x) << 0;
+        //                    long d = (x & 0) | f(x) * 0; long e = 0 / (x | 4); return a; }
+        let mut program = program(vec![func(
+            "foo",
+            vec!["x"],
+            vec![
+                assign("a", binop(OpType::ADD, binop(OpType::MULTIPLY, param_ref("x"), constant(1)), constant(0))),
+                assign("b", binop(OpType::SUBTRACT, param_ref("x"), param_ref("x"))),
+                assign("c", binop(OpType::BSHL, unop(OpType::NEGATE, unop(OpType::NEGATE, param_ref("x"))), constant(0))),
+                assign(
+                    "d",
+                    binop(
+                        OpType::BOR,
+                        binop(OpType::BAND, param_ref("x"), constant(0)),
+                        binop(OpType::MULTIPLY, call("f", vec![param_ref("x")]), constant(0)),
+                    ),
+                ),
+                assign("e", binop(OpType::DIVIDE, constant(0), binop(OpType::BOR, param_ref("x"), constant(4)))),
+                ret(var("a")),
+            ],
+        )]);
+
+        assert!(ConstantFolding(&mut program));
+
+        let body = body(&program, 0);
+        let a = body[0].right.as_ref().unwrap();
+        assert_eq!((a.exprCode, a.name.as_str()), (ExprType::VARIABLE, "x"));
+        assert_eq!(body[1].right.as_ref().unwrap().exprCode, ExprType::CONSTANT);
+        assert_eq!(body[2].right.as_ref().unwrap().name, "x");
+        // the call must still run, so only (x & 0) goes
+        let d = body[3].right.as_ref().unwrap();
+        assert_eq!(d.opCode, OpType::MULTIPLY);
+        assert_eq!(body[4].right.as_ref().unwrap().exprCode, ExprType::CONSTANT);
+    }
+
+    #[test]
+    fn folds_like_the_hardware_and_reports_trapping_divisions() {
+        // long foo() { long a = 2 - 3; long b = 9223372036854775807 + 1; long c = 5 << 65; long d = 1 / 0; return a; }
+        let mut program = program(vec![func(
+            "foo",
+            vec![],
+            vec![
+                assign("a", binop(OpType::SUBTRACT, constant(2), constant(3))),
+                assign("b", binop(OpType::ADD, constant(i64::MAX), constant(1))),
+                assign("c", binop(OpType::BSHL, constant(5), constant(65))),
+                assign("d", binop(OpType::DIVIDE, constant(1), constant(0))),
+                ret(var("a")),
+            ],
+        )]);
+
+        assert!(ConstantFolding(&mut program));
+
+        let body = body(&program, 0);
+        assert_eq!(body[0].right.as_ref().unwrap().value, -1);
+        assert_eq!(body[1].right.as_ref().unwrap().value, i64::MIN);
+        assert_eq!(body[2].right.as_ref().unwrap().value, 10);
+        assert_eq!(body[3].right.as_ref().unwrap().opCode, OpType::DIVIDE);
+        assert_eq!(ConstantTraps(&program), vec!["in function 'foo': division of 1 by zero".to_string()]);
+        assert!(CalcExprValue(OpType::DIVIDE, i64::MIN, Some(-1)).is_err());
+        assert_eq!(CalcExprValue(OpType::BSHR, -256, Some(-60)), Ok(-16));
+    }
+}
@@ -7,7 +7,13 @@
 #![allow(non_snake_case)]
 #![allow(dead_code)]
 extern crate libc;
+use crate::cfg::*;
+use crate::constfolding::{ConstantTrap, EvalConstExpr};
+use crate::cse::PureFunctions;
 use crate::expression::*;
+use crate::ipcp::*;
+use crate::sccp::*;
+use std::collections::{BTreeMap, BTreeSet};
 use lazy_static::lazy_static;
 use std::sync::atomic::{AtomicBool, Ordering};
 
@@ -18,292 +24,267 @@ use std::sync::atomic::{AtomicBool, Ordering};
    DECLARE THEM IN THE HEADER FILE.
 **************************************************************************************
 */
-#[derive(Clone)]
-struct refConst {
-    pub name: String,
-    pub value: i64,
-    pub next: LinkConst,
-}
-
-type LinkConst = Option<Box<refConst>>;
 
-impl Default for refConst {
-    fn default() -> refConst {
-        refConst {
-            name: "".to_string(),
-            value: 0,
-            next: None,
-        }
-    }
+lazy_static! {
+    static ref madeChange: AtomicBool = AtomicBool::new(false);
 }
 
-struct constList {
-    pub head: LinkConst,
+/*
+**********************************************************************************************************************************
+ YOU CAN MAKE CHANGES/ADD AUXILLIARY FUNCTIONS BELOW THIS LINE
+**********************************************************************************************************************************
+*/
+
+/*
+************************************************************************************
+  THIS FUNCTION TURNS AN EXPRESSION NODE INTO A CONSTANT, IN PLACE
+*************************************************************************************
+*/
+pub fn MakeConstant(node: &mut RNode, val: i64) {
+    node.name = "".to_string();
+    node.value = val;
+
+    node.type_ = NodeType::EXPRESSION;
+    node.exprCode = ExprType::CONSTANT;
+    node.opCode = OpType::O_NONE;
+    node.stmtCode = StmtType::S_NONE;
+
+    node.left = None;
+    node.right = None;
+    node.arguments = None;
+    node.statements = None;
 }
 
-impl constList {
-    fn new() -> Self {
-        constList { head: None }
+/*
+************************************************************************************
+  THIS FUNCTION REPLACES EVERY PART OF AN EXPRESSION KNOWN TO BE CONSTANT BEFORE
+  ITS STATEMENT RUNS, AT ANY DEPTH: A VARIABLE, A NESTED OPERATION, A CALL
+  ARGUMENT. THE OPERAND OF & IS A MEMORY LOCATION, NOT A VALUE, AND STAYS.
+*************************************************************************************
+*/
+fn PropagateIntoExpr(node: &mut RNode, env: &ConstEnv) {
+    if node.exprCode == ExprType::CONSTANT {
+        return;
+    }
+    if let ConstValue::Const(val) = EvalExpr(node, env) {
+        MakeConstant(node, val);
+        madeChange.store(true, Ordering::Relaxed);
+        return;
+    }
+    if node.exprCode == ExprType::VARIABLE || node.opCode == OpType::ADDRESSOF || node.opCode == OpType::RESULTHIGH {
+        return;
     }
 
-    fn free_list(&mut self) {
-        while !self.head.is_none() {
-            self.head.take().map(|node| {
-                self.head = node.next;
-            });
+    if node.opCode != OpType::FUNCTIONCALL {
+        if let Some(left) = node.left.as_mut() {
+            PropagateIntoExpr(left, env);
         }
     }
+    if let Some(right) = node.right.as_mut() {
+        PropagateIntoExpr(right, env);
+    }
+    if let Some(args) = node.arguments.as_mut() {
+        for arg in args.iter_mut() {
+            PropagateIntoExpr(arg, env);
+        }
+    }
+}
 
-    /*
-    *************************************************************************
-      FUNCTION TO ADD A CONSTANT VALUE AND THE ASSOCIATED VARIABLE TO THE LIST
-    **************************************************************************
-    */
-    fn update_list(&mut self, name: String, value: i64) {
-        let new_last = Box::new(refConst {
-            name: name.clone(),
-            value,
-            next: None,
-        });
-
-        if !self.head.is_none() {
-            let mut current = &mut self.head;
-            loop {
-                if let Some(node) = current.as_mut() {
-                    if node.next.is_none() {
-                        node.next = Some(new_last);
-                        break;
-                    }
-                    current = &mut node.next;
-                } else {
-                    break;
-                }
+/*
+************************************************************************************
+  THIS FUNCTION DELETES THE STATEMENTS AT THE GIVEN POSITIONS OF A FUNCTION BODY
+*************************************************************************************
+*/
+fn RemoveStatements(mut statements: &mut RList, dead: &BTreeSet<usize>) {
+    let mut pos: usize = 0;
+    while statements.node.is_some() {
+        if dead.contains(&pos) {
+            pos += 1;
+            if let Some(next_stmt) = statements.next.take() {
+                *statements = *next_stmt;
+            } else {
+                statements.node = None;
             }
+            madeChange.store(true, Ordering::Relaxed);
+            continue;
+        }
+        pos += 1;
+
+        if let Some(next) = statements.next.as_mut() {
+            statements = next;
         } else {
-            self.head = Some(new_last);
+            break;
         }
     }
-    /*
-    ****************************************************************************
-      FUNCTION TO PRINT OUT THE LIST TO SEE ALL CONSTANTS THAT ARE USED/REFERRED
-      AFTER THEIR ASSIGNMENT. YOU CAN USE THIS FOR DEBUGGING PURPOSES OR TO CHECK
-      IF YOUR LIST IS GETTING UPDATED CORRECTLY
-    ******************************************************************************
-    */
-    fn print_list(&self) {
-        let mut current = &self.head;
-
-        if current.is_none() {
-            println!("List is empty");
-        }
-
-        loop {
-            if let Some(node) = current.as_ref() {
-                print!("{}:{} -> ", (node.name), node.value);
+}
 
-                current = &node.next;
-            } else {
-                break;
+/*
+************************************************************************************
+  THIS FUNCTION PROPOGATES CONSTANTS THROUGH ONE FUNCTION WITH SCCP. EACH BLOCK
+  THAT CAN RUN IS REPLAYED FROM THE ENVIRONMENT AT ITS START, SUBSTITUTING INTO
+  EVERY STATEMENT, AND THE BLOCKS THAT CAN NEVER RUN ARE DELETED: CODE AFTER A
+  RETURN, OR AFTER AN ASSERT THAT ALWAYS FAILS.
+*************************************************************************************
+*/
+fn PropagatePerFunction(func: &mut RNode, params: &BTreeMap<String, i64>) {
+    let cfg = Cfg::build(func);
+    let solution = Sccp(func, &cfg, params);
+
+    let mut dead: BTreeSet<usize> = BTreeSet::new();
+    let mut stmts = StatementsMut(func);
+    for block in cfg.blocks() {
+        if !solution.executable[block.id] {
+            dead.extend(block.positions());
+            continue;
+        }
+        let mut env = solution.block_in[block.id].clone();
+        for stmt in cfg.instructions_mut(block.id, &mut stmts) {
+            if stmt.stmtCode == StmtType::ASSIGN {
+                if let Some(right) = stmt.right.as_mut() {
+                    PropagateIntoExpr(right, &env);
+                }
+            }
+            else if stmt.stmtCode == StmtType::STORE || stmt.stmtCode == StmtType::RETURN {
+                if let Some(left) = stmt.left.as_mut() {
+                    PropagateIntoExpr(left, &env);
+                }
+                // the value stored, or the second field of a returned struct
+                if let Some(right) = stmt.right.as_mut() {
+                    PropagateIntoExpr(right, &env);
+                }
+            }
+            else if stmt.stmtCode == StmtType::ASSERT {
+                if let Some(cond) = stmt.left.as_mut() {
+                    PropagateIntoExpr(cond, &env);
+                }
             }
+            TransferConst(stmt, &mut env, &solution.escaped);
         }
-        println!();
     }
 
-    /*
-    *****************************************************************************
-      FUNCTION TO LOOKUP IF A CONSTANT ASSOCIATED VARIABLE IS ALREADY IN THE LIST
-    ******************************************************************************
-    */
-    fn search_list(&self, name: String) -> Option<i64> {
-        let mut current = &self.head;
-        loop {
-            if let Some(node) = current.as_ref() {
-                if node.name == name {
-                    return Some(node.value);
-                }
+    if let Some(statements) = func.statements.as_mut() {
+        RemoveStatements(statements, &dead);
+    }
+}
 
-                current = &node.next;
-            } else {
-                break;
+/*
+************************************************************************************
+  CONSTANT PROPAGATION FOR EVERY FUNCTION. WHAT IS KNOWN ACROSS CALLS IS GATHERED
+  FIRST: THE PARAMETERS EVERY CALLER PASSES THE SAME CONSTANT FOR, AND THE
+  FUNCTIONS THAT ALWAYS RETURN THE SAME CONSTANT.
+*************************************************************************************
+*/
+#[no_mangle]
+pub fn ConstProp(mut worklist: &mut RList) -> bool {
+    madeChange.store(false, Ordering::Relaxed);
+    let params = ConstantParams(worklist);
+    let returns = ConstantReturns(worklist);
+    let pure = PureFunctions(worklist);
+    let unknown: BTreeMap<String, i64> = BTreeMap::new();
+    loop {
+        if let Some(node) = worklist.node.as_mut() {
+            if node.statements.is_some() {
+                PropagatePerFunction(node, params.get(&node.name).unwrap_or(&unknown));
+                if ReplaceConstantCalls(node, &returns, &pure) {
+                    madeChange.store(true, Ordering::Relaxed);
+                }
             }
         }
-        return None;
+        else{
+            break;
+        }
+
+        if let Some(next_node) = worklist.next.as_mut() {
+            worklist = next_node;
+        }
+        else{
+            break;
+        }
     }
+    let res: bool = madeChange.load(Ordering::Relaxed);
+    return res;
 }
 
-lazy_static! {
-    static ref madeChange: AtomicBool = AtomicBool::new(false);
+/*
+************************************************************************************
+  THIS FUNCTION REPLACES EVERY USE OF A `const` DECLARATION INSIDE AN EXPRESSION
+  WITH ITS FOLDED VALUE. UNLIKE TrackConst IT WALKS THE WHOLE TREE, SO USES NESTED
+  IN OPERATIONS AND CALL ARGUMENTS ARE CAUGHT TOO.
+*************************************************************************************
+*/
+fn FoldConstRefs(node: &mut RNode) {
+    if node.exprCode == ExprType::VARIABLE {
+        let is_const_ref = node
+            .left
+            .as_ref()
+            .is_some_and(|decl| decl.stmtCode == StmtType::CONSTDECL);
+        if is_const_ref {
+            if let Some(val) = EvalConstExpr(node) {
+                MakeConstant(node, val);
+            }
+        }
+        return;
+    }
+
+    if node.opCode != OpType::FUNCTIONCALL {
+        if let Some(left) = node.left.as_mut() {
+            FoldConstRefs(left);
+        }
+    }
+    if let Some(right) = node.right.as_mut() {
+        FoldConstRefs(right);
+    }
+    let mut args = node.arguments.as_mut();
+    while let Some(arg_list) = args {
+        if let Some(arg) = arg_list.node.as_mut() {
+            FoldConstRefs(arg);
+        }
+        args = arg_list.next.as_mut();
+    }
 }
 
 /*
-**********************************************************************************************************************************
- YOU CAN MAKE CHANGES/ADD AUXILLIARY FUNCTIONS BELOW THIS LINE
-**********************************************************************************************************************************
+************************************************************************************
+  THIS FUNCTION CHECKS THAT A `const` DECLARATION FOLDS AT COMPILE TIME
+*************************************************************************************
 */
+fn CheckConstDecl(decl: &RNode) -> Result<(), String> {
+    let init = decl.right.as_ref();
+    match init.and_then(|init| EvalConstExpr(init)) {
+        Some(_) => Ok(()),
+        None => match init.and_then(|init| ConstantTrap(init)) {
+            Some(msg) => Err(format!("initializer of const '{}': {}", decl.name, msg)),
+            None => Err(format!(
+                "initializer of const '{}' is not a compile-time constant",
+                decl.name
+            )),
+        },
+    }
+}
 
 /*
 ************************************************************************************
-  THIS FUNCTION IS MEANT TO UPDATE THE CONSTANT LIST WITH THE ASSOCIATED VARIABLE
-  AND CONSTANT VALUE WHEN ONE IS SEEN. IT SHOULD ALSO PROPOGATE THE CONSTANTS WHEN
-  WHEN APPLICABLE. YOU CAN ADD A NEW FUNCTION IF YOU WISH TO MODULARIZE BETTER.
+  THIS FUNCTION FOLDS THE `const` DECLARATIONS OF ONE FUNCTION BODY AND DROPS
+  THEM FROM THE STATEMENT LIST
 *************************************************************************************
 */
-#[no_mangle]
-fn TrackConst(mut statements: &mut RList, clist: &mut constList) {
-    loop {
-        if let Some(node) = statements.node.as_mut() {
-            /*
-            ****************************************
-                    TODO : YOUR CODE HERE
-            ****************************************
-            */
-            if node.stmtCode == StmtType::ASSIGN {
-                if let Some(right_node) = node.right.as_mut() {
-                    if right_node.type_ == NodeType::EXPRESSION {
-                        if right_node.exprCode == ExprType::CONSTANT {
-                            clist.update_list(node.name.clone(), right_node.value);
-                        }
-                        else if right_node.exprCode == ExprType::VARIABLE || right_node.exprCode == ExprType::PARAMETER {
-                            if let Some(val) = clist.search_list(right_node.name.clone()).as_mut() {
-                                right_node.name = "".to_string();
-                                right_node.value = *val;
-
-                                right_node.type_ = NodeType::EXPRESSION;
-                                right_node.exprCode = ExprType::CONSTANT;
-                                right_node.opCode = OpType::O_NONE;
-                                right_node.stmtCode = StmtType::S_NONE;
-
-                                right_node.left = None;
-                                right_node.right = None;
-                                right_node.arguments = None;
-                                right_node.statements = None;
-
-                                madeChange.store(true, Ordering::Relaxed);
-                            }
-                        }
-                        else if right_node.exprCode == ExprType::OPERATION {
-                            if right_node.opCode == OpType::NEGATE {
-                                if let Some(inner_exp) = right_node.left.as_mut() {
-                                    if let Some(val) = clist.search_list(inner_exp.name.clone()).as_mut() {
-                                        inner_exp.name = "".to_string();
-                                        inner_exp.value = *val;
-
-                                        inner_exp.type_ = NodeType::EXPRESSION;
-                                        inner_exp.exprCode = ExprType::CONSTANT;
-                                        inner_exp.opCode = OpType::O_NONE;
-                                        inner_exp.stmtCode = StmtType::S_NONE;
-
-                                        inner_exp.left = None;
-                                        inner_exp.right = None;
-                                        inner_exp.arguments = None;
-                                        inner_exp.statements = None;
-
-                                        madeChange.store(true, Ordering::Relaxed);
-                                    }
-                                }
-                            }
-                            
-                            else if right_node.opCode == OpType::FUNCTIONCALL {
-                                if let Some(mut args) = right_node.arguments.as_mut() {
-                                    'arg_counter: loop {
-                                        if let Some(arg) = args.node.as_mut() {
-                                            if let Some(val) = clist.search_list(arg.name.clone()).as_mut() {
-                                                arg.name = "".to_string();
-                                                arg.value = *val;
-
-                                                arg.type_ = NodeType::EXPRESSION;
-                                                arg.exprCode = ExprType::CONSTANT;
-                                                arg.opCode = OpType::O_NONE;
-                                                arg.stmtCode = StmtType::S_NONE;
-
-                                                arg.left = None;
-                                                arg.right = None;
-                                                arg.arguments = None;
-                                                arg.statements = None;
-                                                madeChange.store(true, Ordering::Relaxed);
-                                            }
-                                        }
-                                        else {
-                                            break 'arg_counter;
-                                        }
-
-                                        if let Some(next_arg) = args.next.as_mut() {
-                                            args = next_arg;
-                                        }
-                                        else {
-                                            break 'arg_counter;
-                                        }
-                                    }
-                                }
-                            }
-
-                            else {
-                                if let Some(left_operand) = right_node.left.as_mut() {
-                                    if left_operand.exprCode == ExprType::VARIABLE || left_operand.exprCode == ExprType::PARAMETER {
-                                        if let Some(val) = clist.search_list(left_operand.name.clone()).as_mut() {
-                                            left_operand.name = "".to_string();
-                                            left_operand.value = *val;
-
-                                            left_operand.type_ = NodeType::EXPRESSION;
-                                            left_operand.exprCode = ExprType::CONSTANT;
-                                            left_operand.opCode = OpType::O_NONE;
-                                            left_operand.stmtCode = StmtType::S_NONE;
-
-                                            left_operand.left = None;
-                                            left_operand.right = None;
-                                            left_operand.arguments = None;
-                                            left_operand.statements = None;
-                                            madeChange.store(true, Ordering::Relaxed);
-                                        }
-                                    }
-                                }
-                                if let Some(right_operand) = right_node.right.as_mut() {
-                                    if right_operand.exprCode == ExprType::VARIABLE || right_operand.exprCode == ExprType::PARAMETER {
-                                        if let Some(val) = clist.search_list(right_operand.name.clone()).as_mut(){
-                                            right_operand.name = "".to_string();
-                                            right_operand.value = *val;
-
-                                            right_operand.type_ = NodeType::EXPRESSION;
-                                            right_operand.exprCode = ExprType::CONSTANT;
-                                            right_operand.opCode = OpType::O_NONE;
-                                            right_operand.stmtCode = StmtType::S_NONE;
-
-                                            right_operand.left = None;
-                                            right_operand.right = None;
-                                            right_operand.arguments = None;
-                                            right_operand.statements = None;
-
-                                            madeChange.store(true, Ordering::Relaxed);
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-                
-            }
-            else if node.stmtCode == StmtType::RETURN {
-                if let Some(inner_exp) = node.left.as_mut() {
-                    if let Some(val) = clist.search_list(inner_exp.name.clone()).as_mut() {
-                        inner_exp.name = "".to_string();
-                        inner_exp.value = *val;
-
-                        inner_exp.type_ = NodeType::EXPRESSION;
-                        inner_exp.exprCode = ExprType::CONSTANT;
-                        inner_exp.opCode = OpType::O_NONE;
-                        inner_exp.stmtCode = StmtType::S_NONE;
-
-                        inner_exp.left = None;
-                        inner_exp.right = None;
-                        inner_exp.arguments = None;
-                        inner_exp.statements = None;
-                        madeChange.store(true, Ordering::Relaxed);
-                    }
-                }
+fn ConstDeclsPerFunction(mut statements: &mut RList) -> Result<(), String> {
+    while let Some(stmtNode) = statements.node.as_mut() {
+        if stmtNode.stmtCode == StmtType::CONSTDECL {
+            CheckConstDecl(stmtNode)?;
+            if let Some(next_stmt) = statements.next.take() {
+                *statements = *next_stmt;
+            } else {
+                statements.node = None;
             }
-        } else {
-            break;
+            continue;
+        }
+
+        if let Some(right) = stmtNode.right.as_mut() {
+            FoldConstRefs(right);
+        }
+        if let Some(left) = stmtNode.left.as_mut() {
+            FoldConstRefs(left);
         }
 
         if let Some(next) = statements.next.as_mut() {
@@ -312,59 +293,140 @@ fn TrackConst(mut statements: &mut RList, clist: &mut constList) {
             break;
         }
     }
+    Ok(())
 }
 
-#[no_mangle]
-pub fn ConstProp(mut worklist: &mut RList) -> bool {
-    let mut clist = constList::new();
-
-    madeChange.store(false, Ordering::Relaxed);
-    loop {
-        /*
-        ****************************************
-                TODO : YOUR CODE HERE
-        ****************************************
-        */
-
-        if let Some(node) = worklist.node.as_mut() {
-            if let Some(statements) = node.statements.as_mut() {
-                TrackConst(statements, &mut clist);
+/*
+************************************************************************************
+  THIS FUNCTION GUARANTEES THAT EVERY `const` DECLARATION, AT FILE OR FUNCTION
+  SCOPE, IS FOLDED BEFORE THE OPTIMIZER LOOP RUNS. EACH USE IS REPLACED WITH ITS
+  VALUE AND THE DECLARATION ITSELF IS REMOVED, SO CODEGEN NEVER GIVES IT A STACK
+  SLOT. AN INITIALIZER THAT DOES NOT FOLD IS REPORTED AS AN ERROR.
+*************************************************************************************
+*/
+pub fn ConstDecls(mut worklist: &mut RList) -> Result<(), String> {
+    while let Some(node) = worklist.node.as_mut() {
+        if node.type_ == NodeType::STATEMENT && node.stmtCode == StmtType::CONSTDECL {
+            CheckConstDecl(node)?;
+            if let Some(next_node) = worklist.next.take() {
+                *worklist = *next_node;
+            } else {
+                worklist.node = None;
             }
+            continue;
         }
-        else{
-            break;
+
+        if let Some(statements) = node.statements.as_mut() {
+            ConstDeclsPerFunction(statements)?;
         }
 
         if let Some(next_node) = worklist.next.as_mut() {
             worklist = next_node;
-            clist.free_list();
-        }
-        else{
+        } else {
             break;
         }
     }
-    let res: bool = madeChange.load(Ordering::Relaxed);
-    return res;
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
 
-    use super::constList;
+    use super::{ConstDecls, ConstProp};
+    use crate::expression::build::*;
+    use crate::expression::*;
 
     #[test]
-    fn create_list() {
-        let mut clist = constList::new();
+    fn const_decls_fold_and_disappear() {
+        // const long N = 4 * 8;
+        // long foo(long a) { const long M = N + 1; long x = a * M; return x; }
+        let n_init = || binop(OpType::MULTIPLY, constant(4), constant(8));
+        let m_init = || binop(OpType::ADD, const_ref("N", n_init()), constant(1));
+        let mut program = program(vec![
+            const_decl("N", n_init()),
+            func(
+                "foo",
+                vec!["a"],
+                vec![
+                    const_decl("M", m_init()),
+                    assign("x", binop(OpType::MULTIPLY, param_ref("a"), const_ref("M", m_init()))),
+                    ret(var("x")),
+                ],
+            ),
+        ]);
+
+        assert_eq!(ConstDecls(&mut program), Ok(()));
+
+        assert_eq!(nodes(&program).len(), 1);
+        let body = body(&program, 0);
+        assert_eq!(body.len(), 2);
+        let x = body[0].right.as_ref().unwrap();
+        let m = x.right.as_ref().unwrap();
+        assert_eq!(m.exprCode, ExprType::CONSTANT);
+        assert_eq!(m.value, 33);
+    }
 
-        clist.print_list();
-        clist.update_list("A".to_string(), 1);
-        clist.update_list("B".to_string(), 2);
-        clist.update_list("C".to_string(), 3);
+    #[test]
+    fn const_decl_must_be_constant() {
+        // long foo(long a) { const long M = a + 1; return M; }
+        let mut program = program(vec![func(
+            "foo",
+            vec!["a"],
+            vec![
+                const_decl("M", binop(OpType::ADD, param_ref("a"), constant(1))),
+                ret(constant(0)),
+            ],
+        )]);
+
+        assert!(ConstDecls(&mut program).is_err());
+    }
 
-        clist.print_list();
+    #[test]
+    fn store_forgets_escaped_constants() {
+        // long foo(long* p) { long x = 1; long y = 2; long* q = &x; *q = 5; long z = x + y; return z; }
+        let mut program = program(vec![func(
+            "foo",
+            vec!["p"],
+            vec![
+                assign("x", constant(1)),
+                assign("y", constant(2)),
+                assign("q", unop(OpType::ADDRESSOF, var("x"))),
+                store(var("q"), constant(5)),
+                assign("z", binop(OpType::ADD, var("x"), var("y"))),
+                ret(var("z")),
+            ],
+        )]);
+
+        assert!(ConstProp(&mut program));
+
+        let body = body(&program, 0);
+        let sum = body[4].right.as_ref().unwrap();
+        assert_eq!(sum.left.as_ref().unwrap().exprCode, ExprType::VARIABLE);
+        assert_eq!(sum.right.as_ref().unwrap().exprCode, ExprType::CONSTANT);
+    }
 
-        assert_eq!(clist.search_list("B".to_string()), Some(2));
-        clist.free_list();
-        clist.print_list();
+    #[test]
+    fn nested_uses_fold_and_unreachable_code_goes() {
+        // long foo(long a) { long x = 3; long y = a * (x + 1); return bar(-x) + y; long z = 1; }
+        let mut program = program(vec![func(
+            "foo",
+            vec!["a"],
+            vec![
+                assign("x", constant(3)),
+                assign("y", binop(OpType::MULTIPLY, param_ref("a"), binop(OpType::ADD, var("x"), constant(1)))),
+                ret(binop(OpType::ADD, call("bar", vec![unop(OpType::NEGATE, var("x"))]), var("y"))),
+                assign("z", constant(1)),
+            ],
+        )]);
+
+        assert!(ConstProp(&mut program));
+
+        let body = body(&program, 0);
+        assert_eq!(body.len(), 3);
+        let product = body[1].right.as_ref().unwrap();
+        assert_eq!(product.right.as_ref().unwrap().value, 4);
+        let sum = body[2].left.as_ref().unwrap();
+        let arg = sum.left.as_ref().unwrap().arguments.as_ref().unwrap().iter().next().unwrap();
+        assert_eq!((arg.exprCode, arg.value), (ExprType::CONSTANT, -3));
     }
 }
@@ -0,0 +1,215 @@
+/*
+***********************************************************************
+  COPYPROP.RS : COPY PROPAGATION
+************************************************************************
+*/
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+use crate::cfg::*;
+use crate::dataflow::*;
+use crate::expression::*;
+use lazy_static::lazy_static;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+lazy_static! {
+    static ref madeChange: AtomicBool = AtomicBool::new(false);
+}
+
+/*
+*************************************************************************************
+  AVAILABLE COPIES: AFTER `long b = a;`, b HOLDS THE VALUE OF a UNTIL EITHER ONE IS
+  ASSIGNED AGAIN. A COPY IS AVAILABLE AT A POINT ONLY IF IT IS ON EVERY PATH.
+  COPIES OF ESCAPED VARIABLES ARE NEVER RECORDED, AS A STORE OR CALL MAY CHANGE
+  THEM WITHOUT NAMING THEM.
+**************************************************************************************
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub enum Copies {
+    // no path has been seen yet: every copy is available
+    All,
+    // each copy, from the variable assigned to the one it was copied from
+    Known(BTreeMap<String, String>),
+}
+
+impl Lattice for Copies {
+    fn meet(&mut self, other: &Self) {
+        match (&mut *self, other) {
+            (_, Copies::All) => {}
+            (Copies::All, _) => *self = other.clone(),
+            (Copies::Known(mine), Copies::Known(theirs)) => {
+                mine.retain(|dest, src| theirs.get(dest) == Some(src))
+            }
+        }
+    }
+}
+
+impl Copies {
+    fn source(&self, name: &str) -> Option<&String> {
+        match self {
+            Copies::All => None,
+            Copies::Known(copies) => copies.get(name),
+        }
+    }
+}
+
+struct AvailableCopies {
+    escaped: BTreeSet<String>,
+}
+
+impl AvailableCopies {
+    // The variable a statement copies, if it is a copy that can be tracked
+    fn copied<'a>(&self, stmt: &'a RNode) -> Option<&'a RNode> {
+        let dest = AssignedName(stmt)?;
+        let src = stmt.right.as_ref()?;
+        let tracked = src.exprCode == ExprType::VARIABLE
+            && src.name != dest
+            && !self.escaped.contains(dest)
+            && !self.escaped.contains(&src.name);
+        if tracked {
+            Some(src)
+        } else {
+            None
+        }
+    }
+}
+
+impl Analysis for AvailableCopies {
+    type Fact = Copies;
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn boundary(&self) -> Self::Fact {
+        Copies::Known(BTreeMap::new())
+    }
+
+    fn initial(&self) -> Self::Fact {
+        Copies::All
+    }
+
+    fn transfer(&self, stmt: &RNode, _pos: usize, fact: &mut Self::Fact) {
+        let mut copies = match std::mem::replace(fact, Copies::All) {
+            // only unreachable code sees All, and no copy is used from there
+            Copies::All => BTreeMap::new(),
+            Copies::Known(copies) => copies,
+        };
+        if let Some(name) = AssignedName(stmt) {
+            copies.retain(|dest, src| dest != name && src != name);
+            if let Some(src) = self.copied(stmt) {
+                copies.insert(name.to_string(), src.name.clone());
+            }
+        }
+        *fact = Copies::Known(copies);
+    }
+}
+
+/*
+*************************************************************************************
+  REPLACE EVERY USE OF A COPY BY ITS SOURCE, AT ANY DEPTH. sources HOLDS A VARIABLE
+  NODE FOR EVERY SOURCE, SO THE USE KEEPS THE LINK TO ITS DECLARATION.
+**************************************************************************************
+*/
+fn ReplaceCopies(node: &mut RNode, copies: &Copies, sources: &BTreeMap<String, RNode>) {
+    if node.exprCode == ExprType::VARIABLE {
+        if let Some(src) = copies.source(&node.name).and_then(|name| sources.get(name)) {
+            *node = src.clone();
+            madeChange.store(true, Ordering::Relaxed);
+        }
+        return;
+    }
+    // the operand of high() names the call it belongs to, and is not read
+    if node.opCode == OpType::ADDRESSOF || node.opCode == OpType::RESULTHIGH {
+        return;
+    }
+    // the left link of a call is the callee
+    if node.opCode != OpType::FUNCTIONCALL {
+        if let Some(left) = node.left.as_mut() {
+            ReplaceCopies(left, copies, sources);
+        }
+    }
+    if let Some(right) = node.right.as_mut() {
+        ReplaceCopies(right, copies, sources);
+    }
+    if let Some(args) = node.arguments.as_mut() {
+        for arg in args.iter_mut() {
+            ReplaceCopies(arg, copies, sources);
+        }
+    }
+}
+
+fn CopyPropPerFunction(func: &mut RNode) {
+    let analysis = AvailableCopies { escaped: EscapedNames(func) };
+    let mut sources: BTreeMap<String, RNode> = BTreeMap::new();
+    for stmt in func.statements.iter().flat_map(|list| list.iter()) {
+        if let Some(src) = analysis.copied(stmt) {
+            sources.insert(src.name.clone(), src.clone());
+        }
+    }
+    if sources.is_empty() {
+        return;
+    }
+
+    let cfg = Cfg::build(func);
+    let solution = Solve(&analysis, &cfg, func);
+    let mut stmts = StatementsMut(func);
+    for block in cfg.blocks() {
+        let mut copies = solution.block_in[block.id].clone();
+        for (pos, stmt) in block.positions().zip(cfg.instructions_mut(block.id, &mut stmts)) {
+            // the expressions of a statement hang from left and right
+            for side in [stmt.left.as_mut(), stmt.right.as_mut()].into_iter().flatten() {
+                ReplaceCopies(side, &copies, &sources);
+            }
+            analysis.transfer(stmt, pos, &mut copies);
+        }
+    }
+}
+
+/*
+*************************************************************************************
+  COPY PROPAGATION FOR EVERY FUNCTION. THE COPIES THEMSELVES STAY, FOR DeadAssign
+  TO DELETE ONCE NOTHING READS THEM.
+**************************************************************************************
+*/
+pub fn CopyProp(worklist: &mut RList) -> bool {
+    madeChange.store(false, Ordering::Relaxed);
+    for func in worklist.iter_mut() {
+        if func.statements.is_some() {
+            CopyPropPerFunction(func);
+        }
+    }
+    madeChange.load(Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CopyProp;
+    use crate::expression::build::*;
+    use crate::expression::*;
+
+    #[test]
+    fn copy_chains_collapse_until_redefined() {
+        // long foo(long a) { long b = a; long c = b * 2; a = 5; long d = b + c; return d; }
+        let mut program = program(vec![func(
+            "foo",
+            vec!["a"],
+            vec![
+                assign("b", param_ref("a")),
+                assign("c", binop(OpType::MULTIPLY, var("b"), constant(2))),
+                assign("a", constant(5)),
+                assign("d", binop(OpType::ADD, var("b"), var("c"))),
+                ret(var("d")),
+            ],
+        )]);
+
+        assert!(CopyProp(&mut program));
+
+        let body = body(&program, 0);
+        let product = body[1].right.as_ref().unwrap();
+        assert_eq!(product.left.as_ref().unwrap().name, "a");
+        // a was assigned again, so b no longer copies it
+        let sum = body[3].right.as_ref().unwrap();
+        assert_eq!(sum.left.as_ref().unwrap().name, "b");
+        // the return reads d, which is no copy
+        assert!(!CopyProp(&mut program));
+    }
+}
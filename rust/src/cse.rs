@@ -0,0 +1,333 @@
+/*
+***********************************************************************
+  CSE.RS : COMMON SUBEXPRESSION ELIMINATION BY LOCAL VALUE NUMBERING
+************************************************************************
+*/
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+use crate::cfg::*;
+use crate::dataflow::*;
+use crate::expression::*;
+use crate::linkage::CollectCalls;
+use lazy_static::lazy_static;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+lazy_static! {
+    static ref madeChange: AtomicBool = AtomicBool::new(false);
+}
+
+/*
+*************************************************************************************
+  A FUNCTION IS PURE WHEN A CALL ONLY COMPUTES A RESULT FROM ITS ARGUMENTS: IT IS
+  DEFINED IN THIS FILE, NEVER TOUCHES MEMORY THROUGH A POINTER, AND ONLY CALLS
+  PURE FUNCTIONS. EVERY DEFINED FUNCTION STARTS OUT PURE AND LOSES IT UNTIL
+  NOTHING CHANGES, SO RECURSIVE FUNCTIONS CAN BE PURE TOO.
+**************************************************************************************
+*/
+pub fn PureFunctions(worklist: &RList) -> BTreeSet<String> {
+    let mut calls: BTreeMap<&str, BTreeSet<String>> = BTreeMap::new();
+    let mut pure: BTreeSet<String> = BTreeSet::new();
+    for func in worklist.iter().filter(|node| node.type_ == NodeType::FUNCTIONDECL && !node.is_prototype()) {
+        let touches_memory = func.statements.iter().flat_map(|list| list.iter()).any(|stmt| {
+            stmt.stmtCode == StmtType::STORE || stmt.contains_op(OpType::DEREF)
+        });
+        // a struct of two fields comes back partly in %rdx, which only the call
+        // itself can provide, so such a call is never reused nor dropped
+        let returns_pair = func.statements.iter().flat_map(|list| list.iter()).any(|stmt| {
+            stmt.stmtCode == StmtType::RETURN && stmt.right.is_some()
+        });
+        if !touches_memory && !returns_pair {
+            let mut callees: BTreeSet<String> = BTreeSet::new();
+            CollectCalls(func, &mut callees);
+            calls.insert(&func.name, callees);
+            pure.insert(func.name.clone());
+        }
+    }
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (name, callees) in calls.iter() {
+            if pure.contains(*name) && !callees.iter().all(|callee| pure.contains(callee)) {
+                pure.remove(*name);
+                changed = true;
+            }
+        }
+    }
+    pure
+}
+
+/*
+*************************************************************************************
+  THE VALUE NUMBERS OF ONE BLOCK. EQUAL NUMBERS ARE EQUAL VALUES: A VARIABLE GETS
+  THE NUMBER OF WHAT WAS LAST ASSIGNED TO IT, AND AN OPERATION THE NUMBER OF ITS
+  OPERATOR APPLIED TO THE NUMBERS OF ITS OPERANDS, IN A FIXED ORDER WHEN THE
+  OPERATOR IS COMMUTATIVE. ANYTHING READ FROM MEMORY, ESCAPED VARIABLES INCLUDED,
+  GETS A NUMBER OF ITS OWN, SO STORES AND CALLS CANNOT MAKE A NUMBER STALE.
+**************************************************************************************
+*/
+struct ValueTable<'a> {
+    next: usize,
+    vars: BTreeMap<String, usize>,
+    exprs: BTreeMap<String, usize>,
+    // a variable known to hold each value, as a node that reads it
+    holders: BTreeMap<usize, RNode>,
+    pure: &'a BTreeSet<String>,
+    escaped: &'a BTreeSet<String>,
+}
+
+fn IsCommutative(op: OpType) -> bool {
+    matches!(op, OpType::ADD | OpType::MULTIPLY | OpType::BAND | OpType::BOR | OpType::BXOR)
+}
+
+impl ValueTable<'_> {
+    fn fresh(&mut self) -> usize {
+        self.next += 1;
+        self.next
+    }
+
+    fn number_of(&mut self, key: String) -> usize {
+        if let Some(&number) = self.exprs.get(&key) {
+            return number;
+        }
+        let number = self.fresh();
+        self.exprs.insert(key, number);
+        number
+    }
+
+    fn var(&mut self, name: &str) -> usize {
+        if let Some(&number) = self.vars.get(name) {
+            return number;
+        }
+        let number = self.fresh();
+        self.vars.insert(name.to_string(), number);
+        number
+    }
+
+    // The variable holding a value, if it still does
+    fn holder(&self, number: usize) -> Option<&RNode> {
+        self.holders
+            .get(&number)
+            .filter(|holder| self.vars.get(&holder.name) == Some(&number))
+    }
+
+    // Number an expression, replacing each operation whose value a variable
+    // already holds with that variable
+    fn number(&mut self, node: &mut RNode) -> usize {
+        let number = match node.exprCode {
+            ExprType::CONSTANT => Some(self.number_of(format!("const {}", node.value))),
+            ExprType::VARIABLE => {
+                if self.escaped.contains(&node.name) {
+                    None
+                } else {
+                    Some(self.var(&node.name))
+                }
+            }
+            ExprType::OPERATION => self.number_operation(node),
+            _ => None,
+        };
+        let number = number.unwrap_or_else(|| self.fresh());
+
+        if node.exprCode == ExprType::OPERATION {
+            if let Some(holder) = self.holder(number) {
+                *node = holder.clone();
+                madeChange.store(true, Ordering::Relaxed);
+            }
+        }
+        number
+    }
+
+    // The number of an operation, or None when it is a value of its own
+    fn number_operation(&mut self, node: &mut RNode) -> Option<usize> {
+        match node.opCode {
+            OpType::FUNCTIONCALL => {
+                let mut args: Vec<usize> = Vec::new();
+                for arg in node.arguments.iter_mut().flat_map(|list| list.iter_mut()) {
+                    args.push(self.number(arg));
+                }
+                let callee = node.left.as_ref().map_or(String::new(), |callee| callee.name.clone());
+                if self.pure.contains(&callee) {
+                    Some(self.number_of(format!("call {} {:?}", callee, args)))
+                } else {
+                    None
+                }
+            }
+            OpType::DEREF => {
+                if let Some(addr) = node.left.as_mut() {
+                    self.number(addr);
+                }
+                None
+            }
+            OpType::ADDRESSOF | OpType::RESULTHIGH | OpType::O_NONE => None,
+            op => {
+                let left = node.left.as_mut().map(|left| self.number(left));
+                let right = node.right.as_mut().map(|right| self.number(right));
+                let (left, right) = match (left, right) {
+                    (Some(l), Some(r)) if IsCommutative(op) && r < l => (Some(r), Some(l)),
+                    operands => operands,
+                };
+                Some(self.number_of(format!("{:?} {:?} {:?}", op, left, right)))
+            }
+        }
+    }
+
+    // After x = e: x holds the value of e, and holds it for later statements
+    // unless another variable already does
+    fn assign(&mut self, stmt: &RNode, number: usize) {
+        self.vars.insert(stmt.name.clone(), number);
+        if self.holder(number).is_none() && !self.escaped.contains(&stmt.name) {
+            let mut holder = RNode::new(
+                stmt.name.clone(),
+                0,
+                NodeType::EXPRESSION,
+                ExprType::VARIABLE,
+                OpType::O_NONE,
+                StmtType::S_NONE,
+                None,
+                None,
+                None,
+                None,
+            );
+            // a variable links to the statement declaring it
+            holder.left = Some(Box::new(stmt.clone()));
+            self.holders.insert(number, holder);
+        }
+    }
+}
+
+fn CsePerBlock(stmts: &mut [&mut RNode], pure: &BTreeSet<String>, escaped: &BTreeSet<String>) {
+    let mut table = ValueTable {
+        next: 0,
+        vars: BTreeMap::new(),
+        exprs: BTreeMap::new(),
+        holders: BTreeMap::new(),
+        pure,
+        escaped,
+    };
+    for stmt in stmts {
+        let mut number: Option<usize> = None;
+        for side in [stmt.left.as_mut(), stmt.right.as_mut()].into_iter().flatten() {
+            number = Some(table.number(side));
+        }
+        if stmt.stmtCode == StmtType::ASSIGN {
+            let number = number.unwrap_or_else(|| table.fresh());
+            table.assign(stmt, number);
+        }
+    }
+}
+
+/*
+*************************************************************************************
+  COMMON SUBEXPRESSION ELIMINATION FOR EVERY FUNCTION, ONE BASIC BLOCK AT A TIME
+**************************************************************************************
+*/
+pub fn CommonSubexpr(worklist: &mut RList) -> bool {
+    madeChange.store(false, Ordering::Relaxed);
+    let pure = PureFunctions(worklist);
+    for func in worklist.iter_mut() {
+        if func.statements.is_none() {
+            continue;
+        }
+        let escaped = EscapedNames(func);
+        let cfg = Cfg::build(func);
+        let mut stmts = StatementsMut(func);
+        for block in cfg.blocks() {
+            CsePerBlock(cfg.instructions_mut(block.id, &mut stmts), &pure, &escaped);
+        }
+    }
+    madeChange.load(Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommonSubexpr;
+    use crate::expression::build::*;
+    use crate::expression::*;
+
+    #[test]
+    fn commutative_operands_match() {
+        // long bar(long a, long b) { long t1 = a * b; long t2 = boo(t1); long t3 = b * a; return t3 + t2; }
+        let mut program = program(vec![
+            prototype("boo", vec!["x"]),
+            func(
+                "bar",
+                vec!["a", "b"],
+                vec![
+                    assign("t1", binop(OpType::MULTIPLY, param_ref("a"), param_ref("b"))),
+                    assign("t2", call("boo", vec![var("t1")])),
+                    assign("t3", binop(OpType::MULTIPLY, param_ref("b"), param_ref("a"))),
+                    ret(binop(OpType::ADD, var("t3"), var("t2"))),
+                ],
+            ),
+        ]);
+
+        assert!(CommonSubexpr(&mut program));
+
+        let body = body(&program, 1);
+        let t3 = body[2].right.as_ref().unwrap();
+        assert_eq!((t3.exprCode, t3.name.as_str()), (ExprType::VARIABLE, "t1"));
+    }
+
+    #[test]
+    fn only_pure_calls_merge() {
+        // long sq(long x) { return x * x; }  long ext(long x);
+        // long foo(long a) { long p = sq(a); long q = sq(a); long r = ext(a); long s = ext(a); return p + q + r + s; }
+        let sum = binop(
+            OpType::ADD,
+            binop(OpType::ADD, var("p"), var("q")),
+            binop(OpType::ADD, var("r"), var("s")),
+        );
+        let mut program = program(vec![
+            func("sq", vec!["x"], vec![ret(binop(OpType::MULTIPLY, param_ref("x"), param_ref("x")))]),
+            prototype("ext", vec!["x"]),
+            func(
+                "foo",
+                vec!["a"],
+                vec![
+                    assign("p", call("sq", vec![param_ref("a")])),
+                    assign("q", call("sq", vec![param_ref("a")])),
+                    assign("r", call("ext", vec![param_ref("a")])),
+                    assign("s", call("ext", vec![param_ref("a")])),
+                    ret(sum),
+                ],
+            ),
+        ]);
+
+        assert!(CommonSubexpr(&mut program));
+
+        let body = body(&program, 2);
+        assert_eq!(body[1].right.as_ref().unwrap().name, "p");
+        assert_eq!(body[3].right.as_ref().unwrap().opCode, OpType::FUNCTIONCALL);
+    }
+
+    #[test]
+    fn struct_calls_never_merge() {
+        // struct P mk(long x) { return (struct P){x, x}; }
+        // long foo(long a) { struct P p = mk(a); struct P q = mk(a); return p.y + q.y; }
+        let mut ret_pair = ret(param_ref("x"));
+        ret_pair.right = Some(param_ref("x"));
+        let mut program = program(vec![
+            func("mk", vec!["x"], vec![ret_pair]),
+            func(
+                "foo",
+                vec!["a"],
+                vec![
+                    assign("p.x", call("mk", vec![param_ref("a")])),
+                    assign("p.y", high_of(var("p.x"))),
+                    assign("q.x", call("mk", vec![param_ref("a")])),
+                    assign("q.y", high_of(var("q.x"))),
+                    ret(binop(OpType::ADD, var("p.y"), var("q.y"))),
+                ],
+            ),
+        ]);
+
+        CommonSubexpr(&mut program);
+
+        // q.y comes from %rdx, so the second call has to stay
+        let body = body(&program, 1);
+        assert_eq!(body[2].right.as_ref().unwrap().opCode, OpType::FUNCTIONCALL);
+        assert_eq!(body[3].right.as_ref().unwrap().left.as_ref().unwrap().name, "q.x");
+    }
+}
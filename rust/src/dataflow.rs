@@ -0,0 +1,419 @@
+/*
+***********************************************************************
+  DATAFLOW.RS : MONOTONE DATAFLOW FRAMEWORK OVER FUNCTION STATEMENTS
+************************************************************************
+*/
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+use crate::cfg::*;
+use crate::expression::*;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+/*
+*************************************************************************************
+  A LATTICE OF FACTS. meet COMBINES THE FACTS THAT REACH A JOIN OVER SEVERAL PATHS,
+  AND MUST ONLY EVER MOVE DOWN THE LATTICE SO THE SOLVER TERMINATES.
+**************************************************************************************
+*/
+pub trait Lattice: Clone + PartialEq {
+    fn meet(&mut self, other: &Self);
+}
+
+// Sets combine by union: a fact holds at a join if it holds along any path
+impl<T: Ord + Clone> Lattice for BTreeSet<T> {
+    fn meet(&mut self, other: &Self) {
+        self.extend(other.iter().cloned());
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Forward,
+    Backward,
+}
+
+/*
+*************************************************************************************
+  AN ANALYSIS GIVES THE FACT AT THE BOUNDARY (THE ENTRY OF A FORWARD ANALYSIS, THE
+  EXIT OF A BACKWARD ONE), THE FACT EVERY OTHER BLOCK STARTS FROM, AND HOW ONE
+  STATEMENT CHANGES A FACT. pos IS THE STATEMENT'S POSITION IN THE FUNCTION.
+**************************************************************************************
+*/
+pub trait Analysis {
+    type Fact: Lattice;
+    const DIRECTION: Direction;
+
+    fn boundary(&self) -> Self::Fact;
+    fn initial(&self) -> Self::Fact;
+    fn transfer(&self, stmt: &RNode, pos: usize, fact: &mut Self::Fact);
+}
+
+// The facts at the start and at the end of every block, in program order
+// whatever the direction of the analysis
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution<F> {
+    pub block_in: Vec<F>,
+    pub block_out: Vec<F>,
+}
+
+// Apply the transfer function of every statement of a block, in the analysis' direction
+pub fn TransferBlock<A: Analysis>(analysis: &A, cfg: &Cfg, id: BlockId, stmts: &[&RNode], fact: &mut A::Fact) {
+    let block = cfg.block(id);
+    let stmts: Vec<(usize, &RNode)> = block.positions().zip(cfg.instructions(id, stmts).iter().copied()).collect();
+    match A::DIRECTION {
+        Direction::Forward => stmts.iter().for_each(|(pos, stmt)| analysis.transfer(stmt, *pos, fact)),
+        Direction::Backward => stmts.iter().rev().for_each(|(pos, stmt)| analysis.transfer(stmt, *pos, fact)),
+    }
+}
+
+/*
+*************************************************************************************
+  WORKLIST SOLVER. BLOCKS ARE FIRST VISITED IN REVERSE POSTORDER (FORWARD) OR ITS
+  REVERSE (BACKWARD), AND A BLOCK IS VISITED AGAIN WHEN THE FACT FLOWING INTO IT
+  CHANGES. cfg MUST BE THE GRAPH OF func.
+**************************************************************************************
+*/
+pub fn Solve<A: Analysis>(analysis: &A, cfg: &Cfg, func: &RNode) -> Solution<A::Fact> {
+    let mut block_in: Vec<A::Fact> = (0..cfg.len()).map(|_| analysis.initial()).collect();
+    let mut block_out: Vec<A::Fact> = block_in.clone();
+    let forward = A::DIRECTION == Direction::Forward;
+    let stmts = Statements(func);
+
+    // unreachable blocks are solved too, after the others
+    let mut order = cfg.reverse_postorder();
+    for id in 0..cfg.len() {
+        if !order.contains(&id) {
+            order.push(id);
+        }
+    }
+    if !forward {
+        order.reverse();
+    }
+    let mut pending: VecDeque<BlockId> = order.into_iter().collect();
+    let mut queued = vec![true; cfg.len()];
+
+    while let Some(id) = pending.pop_front() {
+        queued[id] = false;
+        let block = cfg.block(id);
+        let (sources, dependents) = if forward { (&block.preds, &block.succs) } else { (&block.succs, &block.preds) };
+        let boundary = if forward { cfg.entry() } else { cfg.exit() };
+
+        let mut fact = if id == boundary { analysis.boundary() } else { analysis.initial() };
+        for &source in sources.iter() {
+            fact.meet(if forward { &block_out[source] } else { &block_in[source] });
+        }
+        let mut result = fact.clone();
+        TransferBlock(analysis, cfg, id, &stmts, &mut result);
+
+        let (start, end) = if forward { (&mut block_in[id], &mut block_out[id]) } else { (&mut block_out[id], &mut block_in[id]) };
+        *start = fact;
+        if *end != result {
+            *end = result;
+            for &dependent in dependents.iter() {
+                if !queued[dependent] {
+                    queued[dependent] = true;
+                    pending.push_back(dependent);
+                }
+            }
+        }
+    }
+    Solution { block_in, block_out }
+}
+
+/*
+*************************************************************************************
+  HELPERS ON STATEMENTS
+**************************************************************************************
+*/
+
+// The variable a statement assigns, if any
+pub fn AssignedName(stmt: &RNode) -> Option<&str> {
+    match stmt.stmtCode {
+        StmtType::ASSIGN | StmtType::CONSTDECL => Some(&stmt.name),
+        _ => None,
+    }
+}
+
+// Collect every variable read anywhere in an expression or statement
+pub fn ReadNames(node: &RNode, names: &mut BTreeSet<String>) {
+    if node.exprCode == ExprType::VARIABLE {
+        names.insert(node.name.clone());
+    }
+    for child in node.subexpressions() {
+        ReadNames(child, names);
+    }
+}
+
+// Whether the statement may read or write memory through a pointer, including in a callee
+fn TouchesMemory(stmt: &RNode) -> bool {
+    stmt.stmtCode == StmtType::STORE || stmt.contains_op(OpType::DEREF) || stmt.contains_op(OpType::FUNCTIONCALL)
+}
+
+// The variables whose address is taken somewhere in the function
+pub fn EscapedNames(func: &RNode) -> BTreeSet<String> {
+    let mut names: Vec<String> = Vec::new();
+    for stmt in func.statements.iter().flat_map(|list| list.iter()) {
+        stmt.address_taken(&mut names);
+    }
+    names.into_iter().collect()
+}
+
+/*
+*************************************************************************************
+  REACHING DEFINITIONS: THE ASSIGNMENTS, BY POSITION, WHOSE VALUE MAY STILL BE IN
+  THEIR VARIABLE. A STORE OR CALL MAY ALSO CHANGE AN ESCAPED VARIABLE, BUT HAS NO
+  POSITION TO STAND FOR IT, SO IT DOES NOT KILL ANY DEFINITION.
+**************************************************************************************
+*/
+pub struct ReachingDefinitions {
+    // the positions of the assignments of each variable
+    defs: BTreeMap<String, BTreeSet<usize>>,
+}
+
+impl ReachingDefinitions {
+    pub fn new(func: &RNode) -> Self {
+        let mut defs: BTreeMap<String, BTreeSet<usize>> = BTreeMap::new();
+        for (pos, stmt) in func.statements.iter().flat_map(|list| list.iter()).enumerate() {
+            if let Some(name) = AssignedName(stmt) {
+                defs.entry(name.to_string()).or_default().insert(pos);
+            }
+        }
+        ReachingDefinitions { defs }
+    }
+}
+
+impl Analysis for ReachingDefinitions {
+    type Fact = BTreeSet<usize>;
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn boundary(&self) -> Self::Fact {
+        BTreeSet::new()
+    }
+
+    fn initial(&self) -> Self::Fact {
+        BTreeSet::new()
+    }
+
+    fn transfer(&self, stmt: &RNode, pos: usize, fact: &mut Self::Fact) {
+        if let Some(name) = AssignedName(stmt) {
+            if let Some(defs) = self.defs.get(name) {
+                fact.retain(|def| !defs.contains(def));
+            }
+            fact.insert(pos);
+        }
+    }
+}
+
+/*
+*************************************************************************************
+  LIVENESS: THE VARIABLES WHOSE CURRENT VALUE MAY STILL BE READ. WHATEVER READS
+  MEMORY, A LOAD OR A CALL, MAY READ EVERY ESCAPED VARIABLE.
+**************************************************************************************
+*/
+pub struct Liveness {
+    escaped: BTreeSet<String>,
+}
+
+impl Liveness {
+    pub fn new(func: &RNode) -> Self {
+        Liveness { escaped: EscapedNames(func) }
+    }
+}
+
+impl Analysis for Liveness {
+    type Fact = BTreeSet<String>;
+    const DIRECTION: Direction = Direction::Backward;
+
+    fn boundary(&self) -> Self::Fact {
+        BTreeSet::new()
+    }
+
+    fn initial(&self) -> Self::Fact {
+        BTreeSet::new()
+    }
+
+    fn transfer(&self, stmt: &RNode, _pos: usize, fact: &mut Self::Fact) {
+        if let Some(name) = AssignedName(stmt) {
+            fact.remove(name);
+        }
+        ReadNames(stmt, fact);
+        if stmt.contains_op(OpType::DEREF) || stmt.contains_op(OpType::FUNCTIONCALL) {
+            fact.extend(self.escaped.iter().cloned());
+        }
+    }
+}
+
+/*
+*************************************************************************************
+  AVAILABLE EXPRESSIONS: THE OPERATIONS COMPUTED ON EVERY PATH WITH NONE OF THEIR
+  OPERANDS ASSIGNED SINCE. CALLS, LOADS AND OPERATIONS ON ESCAPED VARIABLES ARE
+  NEVER AVAILABLE, AS MEMORY CAN CHANGE BEHIND THEM. EXPRESSIONS ARE KNOWN BY
+  ExprKey, SO THAT EQUAL TREES ARE THE SAME EXPRESSION.
+**************************************************************************************
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub enum Available {
+    // no path has been seen yet: every expression is available
+    All,
+    Exprs(BTreeSet<String>),
+}
+
+// Expressions combine by intersection: available at a join only if available along all paths
+impl Lattice for Available {
+    fn meet(&mut self, other: &Self) {
+        match (&mut *self, other) {
+            (_, Available::All) => {}
+            (Available::All, _) => *self = other.clone(),
+            (Available::Exprs(mine), Available::Exprs(theirs)) => mine.retain(|expr| theirs.contains(expr)),
+        }
+    }
+}
+
+impl Available {
+    pub fn contains(&self, key: &str) -> bool {
+        match self {
+            Available::All => true,
+            Available::Exprs(exprs) => exprs.contains(key),
+        }
+    }
+}
+
+// A textual key identifying an expression by its shape, like (ADD a 1)
+pub fn ExprKey(expr: &RNode) -> String {
+    match expr.exprCode {
+        ExprType::CONSTANT => expr.value.to_string(),
+        ExprType::VARIABLE | ExprType::PARAMETER => expr.name.clone(),
+        _ => {
+            let operands: Vec<String> = expr.subexpressions().into_iter().map(ExprKey).collect();
+            format!("({:?} {})", expr.opCode, operands.join(" "))
+        }
+    }
+}
+
+pub struct AvailableExpressions {
+    escaped: BTreeSet<String>,
+    // the variables each candidate expression reads
+    operands: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl AvailableExpressions {
+    pub fn new(func: &RNode) -> Self {
+        let mut analysis = AvailableExpressions { escaped: EscapedNames(func), operands: BTreeMap::new() };
+        for stmt in func.statements.iter().flat_map(|list| list.iter()) {
+            let mut exprs: Vec<&RNode> = Vec::new();
+            analysis.candidates(stmt, &mut exprs);
+            for expr in exprs {
+                let mut names: BTreeSet<String> = BTreeSet::new();
+                ReadNames(expr, &mut names);
+                analysis.operands.insert(ExprKey(expr), names);
+            }
+        }
+        analysis
+    }
+
+    // The operations in a statement that can be available, innermost first
+    fn candidates<'a>(&self, node: &'a RNode, exprs: &mut Vec<&'a RNode>) {
+        for child in node.subexpressions() {
+            self.candidates(child, exprs);
+        }
+        if node.exprCode != ExprType::OPERATION || node.opCode == OpType::ADDRESSOF || node.opCode == OpType::RESULTHIGH {
+            return;
+        }
+        let mut names: BTreeSet<String> = BTreeSet::new();
+        ReadNames(node, &mut names);
+        let opaque = node.contains_op(OpType::FUNCTIONCALL) || node.contains_op(OpType::DEREF);
+        if !opaque && names.is_disjoint(&self.escaped) {
+            exprs.push(node);
+        }
+    }
+}
+
+impl Analysis for AvailableExpressions {
+    type Fact = Available;
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn boundary(&self) -> Self::Fact {
+        Available::Exprs(BTreeSet::new())
+    }
+
+    fn initial(&self) -> Self::Fact {
+        Available::All
+    }
+
+    fn transfer(&self, stmt: &RNode, _pos: usize, fact: &mut Self::Fact) {
+        let mut exprs: Vec<&RNode> = Vec::new();
+        self.candidates(stmt, &mut exprs);
+        let mut available = match std::mem::replace(fact, Available::All) {
+            Available::All => self.operands.keys().cloned().collect(),
+            Available::Exprs(exprs) => exprs,
+        };
+        available.extend(exprs.into_iter().map(ExprKey));
+        if let Some(name) = AssignedName(stmt) {
+            available.retain(|key| !self.operands.get(key).is_some_and(|names| names.contains(name)));
+        }
+        if TouchesMemory(stmt) {
+            available.retain(|key| self.operands.get(key).is_some_and(|names| names.is_disjoint(&self.escaped)));
+        }
+        *fact = Available::Exprs(available);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expression::build::*;
+
+    // long foo(long a) { long x = a + 1; assert(x); long y = a + 1; x = y; return x + y; }
+    fn sample() -> Box<RNode> {
+        func(
+            "foo",
+            vec!["a"],
+            vec![
+                assign("x", binop(OpType::ADD, param_ref("a"), constant(1))),
+                assert_at(1, var("x")),
+                assign("y", binop(OpType::ADD, param_ref("a"), constant(1))),
+                assign("x", var("y")),
+                ret(binop(OpType::ADD, var("x"), var("y"))),
+            ],
+        )
+    }
+
+    #[test]
+    fn reaching_definitions_are_killed_by_reassignment() {
+        let foo = sample();
+        let cfg = Cfg::build(&foo);
+
+        let solution = Solve(&ReachingDefinitions::new(&foo), &cfg, &foo);
+
+        assert_eq!(solution.block_out[0], BTreeSet::from([0]));
+        assert_eq!(solution.block_in[1], BTreeSet::from([0]));
+        assert_eq!(solution.block_out[1], BTreeSet::from([2, 3]));
+    }
+
+    #[test]
+    fn liveness_flows_backward() {
+        let foo = sample();
+        let cfg = Cfg::build(&foo);
+
+        let solution = Solve(&Liveness::new(&foo), &cfg, &foo);
+
+        let names = |set: &BTreeSet<String>| set.iter().cloned().collect::<Vec<String>>();
+        assert_eq!(names(&solution.block_in[0]), vec!["a"]);
+        assert_eq!(names(&solution.block_out[0]), vec!["a"]);
+        assert!(solution.block_in[cfg.exit()].is_empty());
+    }
+
+    #[test]
+    fn expressions_stay_available_until_an_operand_changes() {
+        let foo = sample();
+        let cfg = Cfg::build(&foo);
+        let analysis = AvailableExpressions::new(&foo);
+
+        let solution = Solve(&analysis, &cfg, &foo);
+
+        assert!(solution.block_in[1].contains("(ADD a 1)"));
+        assert!(!solution.block_in[1].contains("(ADD x y)"));
+        assert_eq!(solution.block_out[1], Available::Exprs(BTreeSet::from(["(ADD a 1)".to_string(), "(ADD x y)".to_string()])));
+    }
+}
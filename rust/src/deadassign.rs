@@ -8,6 +8,7 @@
 #![allow(dead_code)]
 extern crate libc;
 use crate::expression::*;
+use crate::liveness::LiveVariables;
 use lazy_static::lazy_static;
 use std::sync::atomic::{AtomicBool, Ordering};
 
@@ -19,140 +20,6 @@ use std::sync::atomic::{AtomicBool, Ordering};
 **************************************************************************************
 */
 
-#[derive(Clone)]
-struct refVar {
-    pub name: String,
-    pub next: LinkVar,
-}
-
-type LinkVar = Option<Box<refVar>>;
-
-impl Default for refVar {
-    fn default() -> refVar {
-        refVar {
-            name: "".to_string(),
-            next: None,
-        }
-    }
-}
-
-struct varList {
-    pub head: LinkVar,
-}
-
-impl varList {
-    fn new() -> Self {
-        varList { head: None }
-    }
-
-    fn free_list(&mut self) {
-        while !self.head.is_none() {
-            self.head.take().map(|node| {
-                self.head = node.next;
-            });
-        }
-    }
-
-    /*
-    ***********************************************************************
-    FUNCTION TO ADD A REFERENCE TO THE REFERENCE LIST
-    ************************************************************************
-    */
-    fn add_ref(&mut self, name: String) {
-        let new_last = Box::new(refVar {
-            name: name,
-            next: None,
-        });
-
-        if !self.head.is_none() {
-            let mut current = &mut self.head;
-            loop {
-                if let Some(node) = current.as_mut() {
-                    if node.next.is_none() {
-                        node.next = Some(new_last);
-                        break;
-                    }
-                    current = &mut node.next;
-                } else {
-                    break;
-                }
-            }
-        } else {
-            self.head = Some(new_last);
-        }
-    }
-
-    /*
-    ***********************************************************************
-    FUNCTION TO UPDATE THE REFERENCE LIST WHEN A VARIABLE IS REFERENCED
-    IF NOT DONE SO ALREADY.
-    ************************************************************************
-    */
-    fn update_list(&mut self, node: &mut RNode) {
-        if let Some(right) = node.right.as_mut() {
-            if right.exprCode == ExprType::VARIABLE {
-                if !self.var_exists(right.name.clone()) {
-                    self.add_ref(right.name.clone())
-                }
-            }
-        }
-
-        if let Some(left) = node.left.as_mut() {
-            if left.exprCode == ExprType::VARIABLE {
-                if !self.var_exists(left.name.clone()) {
-                    self.add_ref(left.name.clone())
-                }
-            }
-        }
-    }
-
-    /*
-    ****************************************************************************
-      FUNCTION TO PRINT OUT THE LIST TO SEE ALL CONSTANTS THAT ARE USED/REFERRED
-      AFTER THEIR ASSIGNMENT. YOU CAN USE THIS FOR DEBUGGING PURPOSES OR TO CHECK
-      IF YOUR LIST IS GETTING UPDATED CORRECTLY
-    ******************************************************************************
-    */
-    fn print_list(&self) {
-        let mut current = &self.head;
-
-        if current.is_none() {
-            println!("List is empty");
-        }
-
-        loop {
-            if let Some(node) = current.as_ref() {
-                print!("{}-> ", node.name);
-                current = &node.next;
-            } else {
-                break;
-            }
-        }
-        println!();
-    }
-
-    /*
-    *****************************************************************************
-      FUNCTION TO LOOKUP IF A CONSTANT ASSOCIATED VARIABLE IS ALREADY IN THE LIST
-    ******************************************************************************
-    */
-    fn var_exists(&self, name: String) -> bool {
-        let mut current = &self.head;
-        loop {
-            if let Some(node) = current.as_ref() {
-                if node.name == name {
-                    return true;
-                }
-
-                current = &node.next;
-            } else {
-                break;
-            }
-        }
-        return false;
-    }
-}
-
 lazy_static! {
     static ref madeChange: AtomicBool = AtomicBool::new(false);
 }
@@ -163,110 +30,34 @@ lazy_static! {
 **********************************************************************************************************************************
 */
 
-/*
-********************************************************************
-  THIS FUNCTION IS MEANT TO TRACK THE REFERENCES OF EACH VARIABLE
-  TO HELP DETERMINE IF IT WAS USED OR NOT LATER
-********************************************************************
-*/
-#[no_mangle]
-fn TrackRef(funcNode: &mut RNode, vlist: &mut varList) {
-    if let Some(mut statements) = funcNode.statements.as_mut() {
-        loop {
-            if let Some(stmtNode) = statements.node.as_mut() {
-                /*
-                ****************************************
-                        TODO : YOUR CODE HERE
-                ****************************************
-                */
-                if stmtNode.stmtCode == StmtType::ASSIGN {
-                    if let Some(stmtNodeRight) = stmtNode.right.as_mut() {
-                        if stmtNodeRight.opCode == OpType::FUNCTIONCALL {
-                            if let Some(mut args) = stmtNodeRight.arguments.as_mut() {
-                                loop {
-                                    if let Some(arg) = args.node.as_mut() {
-                                        if arg.exprCode == ExprType::VARIABLE {
-                                            if !vlist.var_exists(arg.name.clone()) {
-                                                vlist.add_ref(arg.name.clone());
-                                            }
-                                        }
-                                    }
-                                    else {
-                                        break;
-                                    }
-        
-                                    if let Some(next_arg) = args.next.as_mut() {
-                                        args = next_arg;
-                                    }
-                                    else {
-                                        break;
-                                    }
-                                }
-                            }
-                        }
-                        else if stmtNodeRight.exprCode == ExprType::OPERATION {
-                            vlist.update_list(stmtNodeRight);
-                        }
-                        else if stmtNodeRight.exprCode == ExprType::VARIABLE {
-                            if !vlist.var_exists(stmtNodeRight.name.clone()) {
-                                vlist.add_ref(stmtNodeRight.name.clone());
-                            }
-                        }
-                    }
-                }
-                else if stmtNode.stmtCode == StmtType::RETURN {
-                    if let Some(stmtNodeLeft) = stmtNode.left.as_mut() {
-                        if stmtNodeLeft.exprCode == ExprType::VARIABLE {
-                            if !vlist.var_exists(stmtNodeLeft.name.clone()) {
-                                vlist.add_ref(stmtNodeLeft.name.clone());
-                            }
-                        }
-                    }
-                }
-
-                //unimplemented!();
-            } else {
-                break;
-            }
-            if let Some(next) = statements.next.as_mut() {
-                statements = next;
-            } else {
-                break;
-            }
-        }
-    } else {
-        return;
-    }
-}
-
 /*
 ***************************************************************
-  THIS FUNCTION IS MEANT TO DO THE ACTUAL DEADCODE REMOVAL
-  BASED ON THE INFORMATION OF TRACKED REFERENCES
+  THIS FUNCTION IS MEANT TO DO THE ACTUAL DEADCODE REMOVAL: AN
+  ASSIGNMENT IS DEAD WHEN ITS VARIABLE IS NOT LIVE RIGHT AFTER IT
 ****************************************************************
 */
 #[no_mangle]
-fn RemoveDead(mut statements: &mut RList, vlist: &mut varList) {
+fn RemoveDead(mut statements: &mut RList, live: &LiveVariables) {
+    // the position each statement had when liveness was computed
+    let mut pos: usize = 0;
     while let Some(stmtNode) = statements.node.as_mut() {
-        /*
-          *************************************************************************************
-        TODO: YOUR CODE HERE
-          **************************************************************************************
-          */
         if stmtNode.stmtCode == StmtType::ASSIGN {
-            if !vlist.var_exists(stmtNode.name.clone()) {
+            // a call may store through a pointer, so it stays even if its result is unused
+            if !live.is_live_after(&stmtNode.name, pos) && !stmtNode.contains_op(OpType::FUNCTIONCALL) {
                 if let Some(next_stmt) = statements.next.take() {
                     *statements = *next_stmt;
                 }
                 else {
                     statements.node = None;
                 }
+                pos += 1;
                 madeChange.store(true, Ordering::Relaxed);
                 continue;
             }
 
         }
 
+        pos += 1;
         if let Some(next) = statements.next.as_mut() {
             statements = next;
         } else {
@@ -276,33 +67,66 @@ fn RemoveDead(mut statements: &mut RList, vlist: &mut varList) {
 }
 
 #[no_mangle]
-pub fn DeadAssign(mut worklist: &mut RList) -> bool {
-    let mut vlist = varList::new();
+pub fn DeadAssign(worklist: &mut RList) -> bool {
     madeChange.store(false, Ordering::Relaxed);
-    loop {
-        /*
-        ****************************************
-                TODO : YOUR CODE HERE
-        ****************************************
-        */
-        if let Some(mut node) = worklist.node.as_mut() {
-            TrackRef(&mut node,&mut vlist);
-            if let Some(mut stmts) = node.statements.as_mut() {
-                RemoveDead(&mut stmts, &mut vlist);
-            }
+    for node in worklist.iter_mut() {
+        if node.statements.is_none() {
+            continue;
         }
-        else {
-            break;
+        let live = LiveVariables::compute(node);
+        if let Some(stmts) = node.statements.as_mut() {
+            RemoveDead(stmts, &live);
         }
+    }
+    madeChange.load(Ordering::Relaxed)
+}
 
-        if let Some(next_node) = worklist.next.as_mut() {
-            worklist = next_node;
-            vlist.free_list();
-        }
-        else {
-            break;
-        }
+#[cfg(test)]
+mod tests {
+    use super::DeadAssign;
+    use crate::expression::build::*;
+    use crate::expression::*;
+
+    #[test]
+    fn returned_struct_fields_stay_alive() {
+        // struct P foo(long a) { struct P r = mk(a); long unused = a; return r; }
+        let mut ret_r = ret(var("r.x"));
+        ret_r.right = Some(var("r.y"));
+        let mut program = program(vec![func(
+            "foo",
+            vec!["a"],
+            vec![
+                assign("r.x", call("mk", vec![param_ref("a")])),
+                assign("r.y", high_of(var("r.x"))),
+                assign("unused", param_ref("a")),
+                ret_r,
+            ],
+        )]);
+
+        assert!(DeadAssign(&mut program));
+
+        let names: Vec<&str> = body(&program, 0).iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["r.x", "r.y", ""]);
+    }
+
+    #[test]
+    fn overwritten_values_are_dead() {
+        // long foo(long a) { long x = a; x = x + 1; long y = x; x = 2; return y; }
+        let mut program = program(vec![func(
+            "foo",
+            vec!["a"],
+            vec![
+                assign("x", param_ref("a")),
+                assign("x", binop(OpType::ADD, var("x"), constant(1))),
+                assign("y", var("x")),
+                assign("x", constant(2)),
+                ret(var("y")),
+            ],
+        )]);
+
+        assert!(DeadAssign(&mut program));
+
+        let kept: Vec<String> = body(&program, 0).iter().map(|s| format!("{}{:?}", s.name, s.stmtCode)).collect();
+        assert_eq!(kept, vec!["xASSIGN", "xASSIGN", "yASSIGN", "RETURN"]);
     }
-    let res: bool = madeChange.load(Ordering::Relaxed);
-    return res;
 }
@@ -6,6 +6,15 @@ use std::fmt;
 use std::ptr;
 
 /* Structs and Enums */
+
+// Value of a FUNCTIONDECL node, as in Expression.h
+pub const LINKAGE_EXTERNAL: i64 = 0;
+pub const LINKAGE_STATIC: i64 = 1;
+
+// Value of an ASSIGN or PARAMETER node: the declared type of the variable
+pub const DECL_LONG: i64 = 0;
+pub const DECL_POINTER: i64 = 1;
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum NodeType {
@@ -13,6 +22,7 @@ pub enum NodeType {
     FUNCTIONDECL,
     STATEMENT,
     EXPRESSION,
+    STRUCTDECL,
 }
 
 impl fmt::Display for NodeType {
@@ -22,6 +32,7 @@ impl fmt::Display for NodeType {
             NodeType::FUNCTIONDECL => "FUNCTIONDECL",
             NodeType::STATEMENT => "STATEMENT",
             NodeType::EXPRESSION => "EXPRESSION",
+            NodeType::STRUCTDECL => "STRUCTDECL",
         };
         write!(f, "{}", s)
     }
@@ -33,6 +44,9 @@ pub enum StmtType {
     S_NONE,
     ASSIGN,
     RETURN,
+    CONSTDECL,
+    ASSERT,
+    STORE,
 }
 
 impl fmt::Display for StmtType {
@@ -41,6 +55,9 @@ impl fmt::Display for StmtType {
             StmtType::S_NONE => "S_NONE",
             StmtType::ASSIGN => "ASSIGN",
             StmtType::RETURN => "RETURN",
+            StmtType::CONSTDECL => "CONSTDECL",
+            StmtType::ASSERT => "ASSERT",
+            StmtType::STORE => "STORE",
         };
         write!(f, "{}", s)
     }
@@ -84,6 +101,11 @@ pub enum OpType {
     BXOR,
     BSHR,
     BSHL,
+    BNOT,
+    BLSHR,
+    DEREF,
+    ADDRESSOF,
+    RESULTHIGH,
 }
 
 impl fmt::Display for OpType {
@@ -101,6 +123,11 @@ impl fmt::Display for OpType {
             OpType::BXOR => "BXOR",
             OpType::BSHR => "BSHR",
             OpType::BSHL => "BSHL",
+            OpType::BNOT => "BNOT",
+            OpType::BLSHR => "BLSHR",
+            OpType::DEREF => "DEREF",
+            OpType::ADDRESSOF => "ADDRESSOF",
+            OpType::RESULTHIGH => "RESULTHIGH",
         };
         write!(f, "{}", s)
     }
@@ -175,7 +202,7 @@ impl AsMut<NodeList> for NodeList {
     }
 }
 
-#[derive(PartialEq)]
+#[derive(Clone, PartialEq)]
 pub struct RList {
     pub node: LinkNode,
     pub next: LinkList,
@@ -184,7 +211,7 @@ pub struct RList {
 pub type LinkNode = Option<Box<RNode>>;
 pub type LinkList = Option<Box<RList>>;
 
-#[derive(PartialEq)]
+#[derive(Clone, PartialEq)]
 pub struct RNode {
     pub name: String,
     pub value: i64,
@@ -245,6 +272,50 @@ impl RList {
             Some(Box::new(from_nodelist(raw)))
         }
     }
+
+    // Walk the nodes of the list in order, for passes that only read the IR
+    pub fn iter(&self) -> RListIter<'_> {
+        RListIter {
+            current: Some(self),
+        }
+    }
+
+    // Walk the nodes of the list in order, for passes that rewrite them in place
+    pub fn iter_mut(&mut self) -> RListIterMut<'_> {
+        RListIterMut {
+            current: Some(self),
+        }
+    }
+}
+
+pub struct RListIter<'a> {
+    current: Option<&'a RList>,
+}
+
+impl<'a> Iterator for RListIter<'a> {
+    type Item = &'a RNode;
+
+    fn next(&mut self) -> Option<&'a RNode> {
+        let list = self.current?;
+        let node = list.node.as_deref()?;
+        self.current = list.next.as_deref();
+        Some(node)
+    }
+}
+
+pub struct RListIterMut<'a> {
+    current: Option<&'a mut RList>,
+}
+
+impl<'a> Iterator for RListIterMut<'a> {
+    type Item = &'a mut RNode;
+
+    fn next(&mut self) -> Option<&'a mut RNode> {
+        let list = self.current.take()?;
+        let node = list.node.as_deref_mut()?;
+        self.current = list.next.as_deref_mut();
+        Some(node)
+    }
 }
 
 // Implement the conversion from raw pointer to Option<Box<Node>>
@@ -280,6 +351,13 @@ impl RNode {
             None
         } else {
             unsafe {
+                // A call only keeps the callee's signature. Copying its body would
+                // never end for recursive functions.
+                let left = if (*raw).opCode == OpType::FUNCTIONCALL {
+                    RNode::callee_from_raw((*raw).left)
+                } else {
+                    RNode::from_raw((*raw).left)
+                };
                 Some(Box::new(RNode::new(
                     (*raw).name(),
                     (*raw).value(),
@@ -287,7 +365,7 @@ impl RNode {
                     (*raw).exprCode,
                     (*raw).opCode,
                     (*raw).stmtCode,
-                    RNode::from_raw((*raw).left),
+                    left,
                     RNode::from_raw((*raw).right),
                     RList::from_raw((*raw).arguments),
                     RList::from_raw((*raw).statements),
@@ -295,6 +373,71 @@ impl RNode {
             }
         }
     }
+
+    fn callee_from_raw(raw: *mut Node) -> LinkNode {
+        if raw.is_null() {
+            None
+        } else {
+            unsafe {
+                Some(Box::new(RNode::new(
+                    (*raw).name(),
+                    (*raw).value(),
+                    (*raw).type_,
+                    (*raw).exprCode,
+                    (*raw).opCode,
+                    (*raw).stmtCode,
+                    None,
+                    None,
+                    RList::from_raw((*raw).arguments),
+                    None,
+                )))
+            }
+        }
+    }
+
+    // A function declared with a prototype only, defined elsewhere
+    pub fn is_prototype(&self) -> bool {
+        self.type_ == NodeType::FUNCTIONDECL && self.statements.is_none()
+    }
+
+    // A function only visible inside its own file
+    pub fn is_static(&self) -> bool {
+        self.type_ == NodeType::FUNCTIONDECL && self.value == LINKAGE_STATIC
+    }
+
+    // The expressions directly inside an expression or statement. The left link of a
+    // VARIABLE (its declaration) and of a FUNCTIONCALL (the callee) are not part of it.
+    pub fn subexpressions(&self) -> Vec<&RNode> {
+        let mut children: Vec<&RNode> = Vec::new();
+        if self.exprCode != ExprType::VARIABLE && self.opCode != OpType::FUNCTIONCALL {
+            children.extend(self.left.as_deref());
+        }
+        children.extend(self.right.as_deref());
+        if self.opCode == OpType::FUNCTIONCALL {
+            if let Some(args) = self.arguments.as_ref() {
+                children.extend(args.iter());
+            }
+        }
+        children
+    }
+
+    // Whether the operation appears anywhere in the expression
+    pub fn contains_op(&self, op: OpType) -> bool {
+        (self.exprCode == ExprType::OPERATION && self.opCode == op)
+            || self.subexpressions().iter().any(|child| child.contains_op(op))
+    }
+
+    // Collect the variables whose address is taken anywhere in the expression
+    pub fn address_taken(&self, names: &mut Vec<String>) {
+        if self.opCode == OpType::ADDRESSOF {
+            if let Some(var) = self.left.as_ref() {
+                names.push(var.name.clone());
+            }
+        }
+        for child in self.subexpressions() {
+            child.address_taken(names);
+        }
+    }
 }
 
 fn convert_rnode_to_node(rnode: &RNode) -> *mut Node {
@@ -378,6 +521,10 @@ pub fn print_program(list: &RList) {
     loop {
         if let Some(node) = current.node.as_ref() {
             print_node(node);
+            // File scope constants are printed like statements
+            if node.type_ == NodeType::STATEMENT {
+                println!(";");
+            }
         } else {
             break;
         }
@@ -406,11 +553,21 @@ pub fn print_node(node: &RNode) {
 }
 
 fn print_function_decl(node: &RNode) {
-    print!("long {} (", node.name);
+    if node.is_static() {
+        print!("static ");
+    }
+    // left is the type of a returned struct
+    let return_type = node.left.as_ref().map_or("long", |t| t.name.as_str());
+    print!("{} {} (", return_type, node.name);
 
     if let Some(arguments) = node.arguments.as_ref() {
         print_parameters(arguments);
     }
+    // A prototype has no body
+    if node.is_prototype() {
+        println!(");");
+        return;
+    }
     print!(") {{\n");
 
     if let Some(statements) = node.statements.as_ref() {
@@ -467,13 +624,29 @@ fn print_statement(node: &RNode) {
         StmtType::RETURN => {
             print_return(node);
         }
+        StmtType::CONSTDECL => {
+            print_const_decl(node);
+        }
+        StmtType::ASSERT => {
+            print_assert(node);
+        }
+        StmtType::STORE => {
+            print_store(node);
+        }
         _ => {}
     }
 }
 
 fn print_return(node: &RNode) {
     print!("return ");
-    if let Some(left) = node.left.as_ref() {
+    // Both fields of a returned struct
+    if let (Some(left), Some(right)) = (node.left.as_ref(), node.right.as_ref()) {
+        print!("{{");
+        print_expression(left);
+        print!(", ");
+        print_expression(right);
+        print!("}}");
+    } else if let Some(left) = node.left.as_ref() {
         print_expression(left);
     }
 }
@@ -484,6 +657,32 @@ fn print_assignment(node: &RNode) {
         print_expression(right);
     }
 }
+
+fn print_assert(node: &RNode) {
+    print!("assert(");
+    if let Some(left) = node.left.as_ref() {
+        print_expression(left);
+    }
+    print!(")");
+}
+
+fn print_store(node: &RNode) {
+    print!("*");
+    if let Some(left) = node.left.as_ref() {
+        print_expression(left);
+    }
+    print!(" = ");
+    if let Some(right) = node.right.as_ref() {
+        print_expression(right);
+    }
+}
+
+fn print_const_decl(node: &RNode) {
+    print!("const long {} = ", node.name);
+    if let Some(right) = node.right.as_ref() {
+        print_expression(right);
+    }
+}
  
 fn print_expression(node: &RNode) {
     match node.exprCode {
@@ -491,7 +690,9 @@ fn print_expression(node: &RNode) {
             if let Some(left) = node.left.as_ref() {
                 if left.type_ == NodeType::EXPRESSION && left.exprCode == ExprType::PARAMETER {
                     print!("{}", left.name);
-                } else if left.type_ == NodeType::STATEMENT && left.stmtCode == StmtType::ASSIGN {
+                } else if left.type_ == NodeType::STATEMENT
+                    && (left.stmtCode == StmtType::ASSIGN || left.stmtCode == StmtType::CONSTDECL)
+                {
                     print!("{}", left.name);
                 }
             }
@@ -500,7 +701,11 @@ fn print_expression(node: &RNode) {
             print!("{}", node.value);
         }
         ExprType::PARAMETER => {
-            print!("long {}", node.name);
+            if node.value == DECL_POINTER {
+                print!("long* {}", node.name);
+            } else {
+                print!("long {}", node.name);
+            }
         }
         ExprType::OPERATION => {
             print_operation(node);
@@ -544,6 +749,26 @@ fn print_operation(node: &RNode) {
         OpType::BSHL => {
             print_binary_operation(node, "<<");
         }
+        OpType::BLSHR => {
+            print_binary_operation(node, ">>>");
+        }
+        OpType::BNOT => {
+            print_unary_operation(node, "~");
+        }
+        OpType::DEREF => {
+            print_unary_operation(node, "*");
+        }
+        OpType::ADDRESSOF => {
+            print_unary_operation(node, "&");
+        }
+        OpType::RESULTHIGH => match node.left.as_ref() {
+            Some(low) => {
+                print!("high(");
+                print_expression(low);
+                print!(")");
+            }
+            None => print!("high(result)"),
+        },
         _ => {}
     }
 }
@@ -578,3 +803,179 @@ fn print_unary_operation(node: &RNode, op: &str) {
     print!(")");
 }
 
+
+/*********************************************************************************************************
+                                Helpers to build IR trees in unit tests
+**********************************************************************************************************/
+#[cfg(test)]
+pub mod build {
+    use super::*;
+
+    fn node(type_: NodeType, exprCode: ExprType, opCode: OpType, stmtCode: StmtType) -> RNode {
+        RNode::new("".to_string(), 0, type_, exprCode, opCode, stmtCode, None, None, None, None)
+    }
+
+    pub fn list(nodes: Vec<Box<RNode>>) -> LinkList {
+        let mut head: LinkList = None;
+        for n in nodes.into_iter().rev() {
+            head = Some(Box::new(RList { node: Some(n), next: head }));
+        }
+        head
+    }
+
+    pub fn program(nodes: Vec<Box<RNode>>) -> RList {
+        list(nodes).map(|l| *l).unwrap_or_else(RList::new)
+    }
+
+    pub fn constant(value: i64) -> Box<RNode> {
+        let mut n = node(NodeType::EXPRESSION, ExprType::CONSTANT, OpType::O_NONE, StmtType::S_NONE);
+        n.value = value;
+        Box::new(n)
+    }
+
+    pub fn param(name: &str) -> Box<RNode> {
+        let mut n = node(NodeType::EXPRESSION, ExprType::PARAMETER, OpType::O_NONE, StmtType::S_NONE);
+        n.name = name.to_string();
+        Box::new(n)
+    }
+
+    fn var_to(name: &str, decl: Box<RNode>) -> Box<RNode> {
+        let mut n = node(NodeType::EXPRESSION, ExprType::VARIABLE, OpType::O_NONE, StmtType::S_NONE);
+        n.name = name.to_string();
+        n.left = Some(decl);
+        Box::new(n)
+    }
+
+    // a use of a local variable; the declaration it links to is only a stub
+    pub fn var(name: &str) -> Box<RNode> {
+        var_to(name, assign(name, constant(0)))
+    }
+
+    // a use of a function parameter
+    pub fn param_ref(name: &str) -> Box<RNode> {
+        var_to(name, param(name))
+    }
+
+    // a use of a `const` declaration with the given initializer
+    pub fn const_ref(name: &str, init: Box<RNode>) -> Box<RNode> {
+        var_to(name, const_decl(name, init))
+    }
+
+    pub fn binop(op: OpType, left: Box<RNode>, right: Box<RNode>) -> Box<RNode> {
+        let mut n = node(NodeType::EXPRESSION, ExprType::OPERATION, op, StmtType::S_NONE);
+        n.left = Some(left);
+        n.right = Some(right);
+        Box::new(n)
+    }
+
+    pub fn unop(op: OpType, expr: Box<RNode>) -> Box<RNode> {
+        let mut n = node(NodeType::EXPRESSION, ExprType::OPERATION, op, StmtType::S_NONE);
+        n.left = Some(expr);
+        Box::new(n)
+    }
+
+    // the second field of the struct returned by the call just before
+    pub fn result_high() -> Box<RNode> {
+        Box::new(node(NodeType::EXPRESSION, ExprType::OPERATION, OpType::RESULTHIGH, StmtType::S_NONE))
+    }
+
+    // the second field of the struct returned by the call assigned to low
+    pub fn high_of(low: Box<RNode>) -> Box<RNode> {
+        unop(OpType::RESULTHIGH, low)
+    }
+
+    pub fn call(callee: &str, args: Vec<Box<RNode>>) -> Box<RNode> {
+        let mut n = node(NodeType::EXPRESSION, ExprType::OPERATION, OpType::FUNCTIONCALL, StmtType::S_NONE);
+        let mut decl = node(NodeType::FUNCTIONDECL, ExprType::E_NONE, OpType::O_NONE, StmtType::S_NONE);
+        decl.name = callee.to_string();
+        n.left = Some(Box::new(decl));
+        n.arguments = list(args);
+        Box::new(n)
+    }
+
+    pub fn assign(name: &str, expr: Box<RNode>) -> Box<RNode> {
+        let mut n = node(NodeType::STATEMENT, ExprType::E_NONE, OpType::O_NONE, StmtType::ASSIGN);
+        n.name = name.to_string();
+        n.right = Some(expr);
+        Box::new(n)
+    }
+
+    pub fn const_decl(name: &str, expr: Box<RNode>) -> Box<RNode> {
+        let mut n = assign(name, expr);
+        n.stmtCode = StmtType::CONSTDECL;
+        n
+    }
+
+    pub fn assert_at(line: i64, cond: Box<RNode>) -> Box<RNode> {
+        let mut n = node(NodeType::STATEMENT, ExprType::E_NONE, OpType::O_NONE, StmtType::ASSERT);
+        n.left = Some(cond);
+        n.value = line;
+        Box::new(n)
+    }
+
+    pub fn store(addr: Box<RNode>, expr: Box<RNode>) -> Box<RNode> {
+        let mut n = node(NodeType::STATEMENT, ExprType::E_NONE, OpType::O_NONE, StmtType::STORE);
+        n.left = Some(addr);
+        n.right = Some(expr);
+        Box::new(n)
+    }
+
+    pub fn ret(expr: Box<RNode>) -> Box<RNode> {
+        let mut n = node(NodeType::STATEMENT, ExprType::E_NONE, OpType::O_NONE, StmtType::RETURN);
+        n.left = Some(expr);
+        Box::new(n)
+    }
+
+    pub fn func(name: &str, params: Vec<&str>, stmts: Vec<Box<RNode>>) -> Box<RNode> {
+        let mut n = node(NodeType::FUNCTIONDECL, ExprType::E_NONE, OpType::O_NONE, StmtType::S_NONE);
+        n.name = name.to_string();
+        n.arguments = list(params.into_iter().map(param).collect());
+        n.statements = list(stmts);
+        Box::new(n)
+    }
+
+    pub fn prototype(name: &str, params: Vec<&str>) -> Box<RNode> {
+        let mut n = func(name, params, vec![]);
+        n.statements = None;
+        n
+    }
+
+    // the nodes of a list, in order
+    pub fn nodes(list: &RList) -> Vec<&RNode> {
+        list.iter().collect()
+    }
+
+    // the statements of the function at the given position in the program
+    pub fn body(program: &RList, index: usize) -> Vec<&RNode> {
+        match nodes(program)[index].statements.as_ref() {
+            Some(stmts) => nodes(stmts),
+            None => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::build::*;
+    use super::*;
+
+    #[test]
+    fn recursive_calls_copy_only_the_callee_signature() {
+        // long fact(long n) { return fact(n); }, with the call linked back to its own declaration
+        let program = program(vec![func("fact", vec!["n"], vec![ret(call("fact", vec![param_ref("n")]))])]);
+        let raw = to_nodelist(&program);
+        unsafe {
+            let decl = (*raw).node;
+            let call = (*(*(*decl).statements).node).left;
+            (*call).left = decl;
+        }
+
+        let converted = from_nodelist(raw);
+
+        let body = body(&converted, 0);
+        let callee = body[0].left.as_ref().unwrap().left.as_ref().unwrap();
+        assert_eq!(callee.name, "fact");
+        assert!(callee.is_prototype());
+        assert_eq!(nodes(callee.arguments.as_ref().unwrap()).len(), 1);
+    }
+}
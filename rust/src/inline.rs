@@ -0,0 +1,424 @@
+/*
+***********************************************************************
+  INLINE.RS : INLINING OF SMALL FUNCTIONS INTO THEIR CALLERS
+************************************************************************
+*/
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+use crate::expression::*;
+use crate::linkage::CollectCalls;
+use lazy_static::lazy_static;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+lazy_static! {
+    static ref madeChange: AtomicBool = AtomicBool::new(false);
+}
+
+// The largest body, in nodes, that is copied into its callers
+const INLINE_LIMIT: usize = 40;
+
+// The largest a caller grows to by inlining, in nodes, over every round. Many
+// calls to small callees, or a chain of them, would blow it up otherwise.
+const CALLER_LIMIT: usize = 400;
+
+pub fn Size(node: &RNode) -> usize {
+    1 + node.subexpressions().iter().map(|child| Size(child)).sum::<usize>()
+}
+
+// The statements of a function that can run: those up to its first return
+pub fn Body(func: &RNode) -> Vec<&RNode> {
+    let mut body: Vec<&RNode> = Vec::new();
+    for stmt in func.statements.iter().flat_map(|list| list.iter()) {
+        body.push(stmt);
+        if stmt.stmtCode == StmtType::RETURN {
+            break;
+        }
+    }
+    body
+}
+
+/*
+*************************************************************************************
+  THE FUNCTIONS THAT CAN CALL THEMSELVES, DIRECTLY OR THROUGH OTHERS. INLINING ONE
+  OF THEM WOULD NEVER END.
+**************************************************************************************
+*/
+pub fn RecursiveFunctions(worklist: &RList) -> BTreeSet<String> {
+    let mut calls: BTreeMap<&str, BTreeSet<String>> = BTreeMap::new();
+    for func in worklist.iter().filter(|node| node.type_ == NodeType::FUNCTIONDECL && !node.is_prototype()) {
+        let mut callees: BTreeSet<String> = BTreeSet::new();
+        CollectCalls(func, &mut callees);
+        calls.insert(&func.name, callees);
+    }
+
+    let mut recursive: BTreeSet<String> = BTreeSet::new();
+    for name in calls.keys() {
+        let mut seen: BTreeSet<&str> = BTreeSet::new();
+        let mut pending: Vec<&str> = calls[name].iter().map(String::as_str).collect();
+        while let Some(callee) = pending.pop() {
+            if callee == *name {
+                recursive.insert(name.to_string());
+                break;
+            }
+            if seen.insert(callee) {
+                pending.extend(calls.get(callee).into_iter().flatten().map(String::as_str));
+            }
+        }
+    }
+    recursive
+}
+
+/*
+*************************************************************************************
+  THE FUNCTIONS WORTH INLINING: DEFINED HERE, NOT RECURSIVE, SMALL ENOUGH, AND
+  RETURNING A SINGLE VALUE. A STRUCT RESULT COMES BACK IN TWO REGISTERS, AND THE
+  CALLER READS THE SECOND ONE AFTER THE CALL, SO THOSE CALLS STAY.
+**************************************************************************************
+*/
+fn Inlinable(worklist: &RList) -> BTreeMap<String, RNode> {
+    let recursive = RecursiveFunctions(worklist);
+    let mut callees: BTreeMap<String, RNode> = BTreeMap::new();
+    for func in worklist.iter().filter(|node| node.type_ == NodeType::FUNCTIONDECL && !node.is_prototype()) {
+        if recursive.contains(&func.name) {
+            continue;
+        }
+        let body = Body(func);
+        let returns_one = body
+            .last()
+            .is_some_and(|stmt| stmt.stmtCode == StmtType::RETURN && stmt.left.is_some() && stmt.right.is_none());
+        let size: usize = body.iter().map(|stmt| Size(stmt)).sum();
+        if returns_one && size <= INLINE_LIMIT {
+            callees.insert(func.name.clone(), func.clone());
+        }
+    }
+    callees
+}
+
+// An assignment, which also declares the variable
+pub fn AssignNode(name: String, declType: i64, value: LinkNode) -> RNode {
+    RNode::new(
+        name,
+        declType,
+        NodeType::STATEMENT,
+        ExprType::E_NONE,
+        OpType::O_NONE,
+        StmtType::ASSIGN,
+        None,
+        value,
+        None,
+        None,
+    )
+}
+
+// A use of the variable an assignment declares, linked to it
+fn UseOf(assign: &RNode) -> RNode {
+    let mut node = RNode::new(
+        assign.name.clone(),
+        0,
+        NodeType::EXPRESSION,
+        ExprType::VARIABLE,
+        OpType::O_NONE,
+        StmtType::S_NONE,
+        None,
+        None,
+        None,
+        None,
+    );
+    node.left = Some(Box::new(AssignNode(assign.name.clone(), assign.value, None)));
+    node
+}
+
+/*
+*************************************************************************************
+  RENAME THE VARIABLES OF A CALLEE FOR ONE CALL SITE: x BECOMES prefix.x. A USE OF
+  A PARAMETER NOW READS THE VARIABLE ITS ARGUMENT IS ASSIGNED TO, SO IT LINKS TO
+  THAT ASSIGNMENT INSTEAD.
+**************************************************************************************
+*/
+fn Rename(node: &mut RNode, prefix: &str) {
+    if node.exprCode == ExprType::VARIABLE {
+        node.name = format!("{}.{}", prefix, node.name);
+        if let Some(decl) = node.left.as_mut() {
+            if decl.exprCode == ExprType::PARAMETER {
+                **decl = AssignNode(node.name.clone(), decl.value, None);
+            } else {
+                decl.name = node.name.clone();
+            }
+        }
+        return;
+    }
+    if node.stmtCode == StmtType::ASSIGN {
+        node.name = format!("{}.{}", prefix, node.name);
+    }
+    // the left link of a call is the callee
+    if node.opCode != OpType::FUNCTIONCALL {
+        if let Some(left) = node.left.as_mut() {
+            Rename(left, prefix);
+        }
+    }
+    if let Some(right) = node.right.as_mut() {
+        Rename(right, prefix);
+    }
+    if let Some(args) = node.arguments.as_mut() {
+        for arg in args.iter_mut() {
+            Rename(arg, prefix);
+        }
+    }
+}
+
+// A name for the result of a call to callee that no variable in names has or starts
+// with, so it can also prefix variables. It is added to names.
+pub fn FreshPrefix(names: &mut BTreeSet<String>, callee: &str) -> String {
+    let taken = |names: &BTreeSet<String>, prefix: &str| {
+        names.iter().any(|name| name == prefix || name.starts_with(&format!("{}.", prefix)))
+    };
+    let mut count = 1;
+    while taken(names, &format!("{}.{}", callee, count)) {
+        count += 1;
+    }
+    let prefix = format!("{}.{}", callee, count);
+    names.insert(prefix.clone());
+    prefix
+}
+
+// The variables a function assigns
+pub fn AssignedNames(func: &RNode) -> BTreeSet<String> {
+    func.statements
+        .iter()
+        .flat_map(|list| list.iter())
+        .filter(|stmt| stmt.stmtCode == StmtType::ASSIGN)
+        .map(|stmt| stmt.name.clone())
+        .collect()
+}
+
+/*
+*************************************************************************************
+  THE CALLS OF ONE CALLER BEING INLINED. EACH CALL SITE GETS A PREFIX NO VARIABLE
+  OF THE CALLER STARTS WITH. THE CALLEE'S STATEMENTS, PRECEDED BY AN ASSIGNMENT OF
+  EACH ARGUMENT TO ITS PARAMETER, COLLECT IN prelude, AND THE CALL BECOMES A READ
+  OF THE VARIABLE NAMED BY THE PREFIX ITSELF, WHICH HOLDS THE RETURNED VALUE.
+  EVERY COPY IS PAID FROM budget, THE NODES THE CALLER CAN STILL GROW BY; A CALL
+  THAT DOES NOT FIT STAYS.
+**************************************************************************************
+*/
+struct Inliner<'a> {
+    callees: &'a BTreeMap<String, RNode>,
+    names: BTreeSet<String>,
+    prelude: Vec<RNode>,
+    budget: usize,
+}
+
+impl Inliner<'_> {
+    // Inline the calls of an expression, innermost first, so arguments come before
+    fn expand(&mut self, node: &mut RNode) {
+        if node.exprCode == ExprType::VARIABLE || node.opCode == OpType::ADDRESSOF {
+            return;
+        }
+        if node.opCode != OpType::FUNCTIONCALL {
+            if let Some(left) = node.left.as_mut() {
+                self.expand(left);
+            }
+        }
+        if let Some(right) = node.right.as_mut() {
+            self.expand(right);
+        }
+        if let Some(args) = node.arguments.as_mut() {
+            for arg in args.iter_mut() {
+                self.expand(arg);
+            }
+        }
+        if node.opCode != OpType::FUNCTIONCALL {
+            return;
+        }
+
+        let callees = self.callees;
+        let Some(callee) = node.left.as_ref().and_then(|callee| callees.get(&callee.name)) else {
+            return;
+        };
+        let params: Vec<&RNode> = callee.arguments.iter().flat_map(|list| list.iter()).collect();
+        let args: Vec<&RNode> = node.arguments.iter().flat_map(|list| list.iter()).collect();
+        if params.len() != args.len() {
+            return;
+        }
+        // the body plus one assignment per argument
+        let cost = Body(callee).iter().map(|stmt| Size(stmt)).sum::<usize>() + params.len();
+        if cost > self.budget {
+            return;
+        }
+        self.budget -= cost;
+
+        let prefix = FreshPrefix(&mut self.names, &callee.name);
+        for (param, arg) in params.iter().zip(args) {
+            let name = format!("{}.{}", prefix, param.name);
+            self.prelude.push(AssignNode(name, param.value, Some(Box::new(arg.clone()))));
+        }
+        for stmt in Body(callee) {
+            let mut stmt = stmt.clone();
+            Rename(&mut stmt, &prefix);
+            if stmt.stmtCode == StmtType::RETURN {
+                let result = AssignNode(prefix.clone(), DECL_LONG, stmt.left.take());
+                *node = UseOf(&result);
+                self.prelude.push(result);
+            } else {
+                self.prelude.push(stmt);
+            }
+        }
+        madeChange.store(true, Ordering::Relaxed);
+    }
+}
+
+// Put statements in front of the one at the head of a list
+pub fn InsertBefore(statements: &mut RList, stmts: Vec<RNode>) {
+    let mut head = Box::new(RList {
+        node: statements.node.take(),
+        next: statements.next.take(),
+    });
+    for stmt in stmts.into_iter().rev() {
+        head = Box::new(RList {
+            node: Some(Box::new(stmt)),
+            next: Some(head),
+        });
+    }
+    *statements = *head;
+}
+
+fn InlinePerFunction(func: &mut RNode, callees: &BTreeMap<String, RNode>) {
+    let size: usize = func.statements.iter().flat_map(|list| list.iter()).map(Size).sum();
+    let budget = CALLER_LIMIT.saturating_sub(size);
+    let mut inliner = Inliner { callees, names: AssignedNames(func), prelude: Vec::new(), budget };
+
+    let Some(mut statements) = func.statements.as_mut() else {
+        return;
+    };
+    while let Some(stmt) = statements.node.as_mut() {
+        // the expressions of a statement hang from left and right
+        for side in [stmt.left.as_mut(), stmt.right.as_mut()].into_iter().flatten() {
+            inliner.expand(side);
+        }
+        let prelude = std::mem::take(&mut inliner.prelude);
+        let inserted = prelude.len();
+        if inserted > 0 {
+            InsertBefore(statements, prelude);
+        }
+        // step over the inlined statements and the one that made the calls
+        for _ in 0..inserted {
+            statements = statements.next.as_mut().expect("inlined statements are followed by the call site");
+        }
+        if let Some(next) = statements.next.as_mut() {
+            statements = next;
+        } else {
+            break;
+        }
+    }
+}
+
+/*
+*************************************************************************************
+  INLINING FOR EVERY FUNCTION. THE CALLEES ARE TAKEN AS THEY ARE BEFORE ANY CALLER
+  CHANGES, AND A CALLEE THAT STILL MAKES CALLS GETS THEM INLINED ON A LATER ROUND.
+**************************************************************************************
+*/
+pub fn Inline(worklist: &mut RList) -> bool {
+    madeChange.store(false, Ordering::Relaxed);
+    let callees = Inlinable(worklist);
+    if callees.is_empty() {
+        return false;
+    }
+    for func in worklist.iter_mut() {
+        if func.type_ == NodeType::FUNCTIONDECL && func.statements.is_some() {
+            InlinePerFunction(func, &callees);
+        }
+    }
+    madeChange.load(Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Inline, Size, CALLER_LIMIT};
+    use crate::expression::build::*;
+    use crate::expression::*;
+
+    #[test]
+    fn small_callees_are_copied_in_with_fresh_names() {
+        // long boo(long a) { long t = a * 5; return t + 1; }
+        // long loop(long n) { long r = loop(n - 1); return r; }
+        // long bar(long t) { long x = boo(t) + boo(loop(t)); return x; }
+        let mut program = program(vec![
+            func(
+                "boo",
+                vec!["a"],
+                vec![
+                    assign("t", binop(OpType::MULTIPLY, param_ref("a"), constant(5))),
+                    ret(binop(OpType::ADD, var("t"), constant(1))),
+                ],
+            ),
+            func(
+                "loop",
+                vec!["n"],
+                vec![
+                    assign("r", call("loop", vec![binop(OpType::SUBTRACT, param_ref("n"), constant(1))])),
+                    ret(var("r")),
+                ],
+            ),
+            func(
+                "bar",
+                vec!["t"],
+                vec![
+                    assign(
+                        "x",
+                        binop(OpType::ADD, call("boo", vec![param_ref("t")]), call("boo", vec![call("loop", vec![param_ref("t")])])),
+                    ),
+                    ret(var("x")),
+                ],
+            ),
+        ]);
+
+        assert!(Inline(&mut program));
+
+        let body = body(&program, 2);
+        let names: Vec<&str> = body.iter().map(|stmt| stmt.name.as_str()).collect();
+        assert_eq!(names, vec!["boo.1.a", "boo.1.t", "boo.1", "boo.2.a", "boo.2.t", "boo.2", "x", ""]);
+        // a parameter use reads the variable its argument went to
+        let product = body[1].right.as_ref().unwrap();
+        assert_eq!(product.left.as_ref().unwrap().name, "boo.1.a");
+        assert_eq!(body[0].right.as_ref().unwrap().name, "t");
+        // the recursive call stays
+        assert_eq!(body[3].right.as_ref().unwrap().opCode, OpType::FUNCTIONCALL);
+        let sum = body[6].right.as_ref().unwrap();
+        assert_eq!((sum.left.as_ref().unwrap().name.as_str(), sum.right.as_ref().unwrap().name.as_str()), ("boo.1", "boo.2"));
+        assert!(!Inline(&mut program));
+    }
+
+    #[test]
+    fn callers_stop_growing_at_the_limit() {
+        // long boo(long a) { long t = a * 5; return t * t + a; }
+        // long bar(long n) { long s0 = boo(n); long s1 = boo(n + 1); ... return n; }
+        let mut stmts: Vec<Box<RNode>> = (0..40)
+            .map(|i| assign(&format!("s{}", i), call("boo", vec![binop(OpType::ADD, param_ref("n"), constant(i))])))
+            .collect();
+        stmts.push(ret(param_ref("n")));
+        let mut program = program(vec![
+            func(
+                "boo",
+                vec!["a"],
+                vec![
+                    assign("t", binop(OpType::MULTIPLY, param_ref("a"), constant(5))),
+                    ret(binop(OpType::ADD, binop(OpType::MULTIPLY, var("t"), var("t")), param_ref("a"))),
+                ],
+            ),
+            func("bar", vec!["n"], stmts),
+        ]);
+
+        assert!(Inline(&mut program));
+        assert!(!Inline(&mut program));
+
+        let body = body(&program, 1);
+        let size: usize = body.iter().map(|stmt| Size(stmt)).sum();
+        assert!(size <= CALLER_LIMIT);
+        // the first calls were inlined, the last ones are still there
+        assert_eq!(body[0].name, "boo.1.a");
+        assert_eq!(body[body.len() - 2].right.as_ref().unwrap().opCode, OpType::FUNCTIONCALL);
+    }
+}
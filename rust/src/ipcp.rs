@@ -0,0 +1,359 @@
+/*
+***********************************************************************
+  IPCP.RS : CONSTANTS ACROSS FUNCTION BOUNDARIES
+************************************************************************
+*/
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+use crate::constprop::MakeConstant;
+use crate::expression::*;
+use crate::inline::*;
+use crate::liveness::LiveVariables;
+use lazy_static::lazy_static;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+lazy_static! {
+    static ref madeChange: AtomicBool = AtomicBool::new(false);
+}
+
+// The largest body, in nodes, that is cloned for constant arguments
+const SPECIALIZE_LIMIT: usize = 100;
+
+fn Defined(worklist: &RList) -> impl Iterator<Item = &RNode> {
+    worklist.iter().filter(|node| node.type_ == NodeType::FUNCTIONDECL && !node.is_prototype())
+}
+
+fn CalleeName(call: &RNode) -> &str {
+    call.left.as_ref().map_or("", |callee| callee.name.as_str())
+}
+
+// The constant passed at each argument position of a call, if any
+fn ArgTuple(call: &RNode) -> Vec<Option<i64>> {
+    call.arguments
+        .iter()
+        .flat_map(|list| list.iter())
+        .map(|arg| if arg.exprCode == ExprType::CONSTANT { Some(arg.value) } else { None })
+        .collect()
+}
+
+fn CollectCallSites<'a>(node: &'a RNode, sites: &mut BTreeMap<String, Vec<&'a RNode>>) {
+    if node.exprCode == ExprType::OPERATION && node.opCode == OpType::FUNCTIONCALL {
+        sites.entry(CalleeName(node).to_string()).or_default().push(node);
+    }
+    for child in node.subexpressions() {
+        CollectCallSites(child, sites);
+    }
+}
+
+// Every call in the program, by callee
+fn CallSites(worklist: &RList) -> BTreeMap<String, Vec<&RNode>> {
+    let mut sites: BTreeMap<String, Vec<&RNode>> = BTreeMap::new();
+    for func in Defined(worklist) {
+        for stmt in func.statements.iter().flat_map(|list| list.iter()) {
+            CollectCallSites(stmt, &mut sites);
+        }
+    }
+    sites
+}
+
+/*
+*************************************************************************************
+  THE PARAMETERS EVERY CALLER PASSES THE SAME CONSTANT FOR, BY FUNCTION. ONLY A
+  STATIC FUNCTION HAS ALL ITS CALLERS IN THIS FILE; ANY OTHER MAY BE CALLED FROM
+  OUTSIDE WITH ANYTHING.
+**************************************************************************************
+*/
+pub fn ConstantParams(worklist: &RList) -> BTreeMap<String, BTreeMap<String, i64>> {
+    let sites = CallSites(worklist);
+    let mut known: BTreeMap<String, BTreeMap<String, i64>> = BTreeMap::new();
+    for func in Defined(worklist).filter(|func| func.is_static()) {
+        let Some(calls) = sites.get(&func.name) else {
+            continue;
+        };
+        let tuples: Vec<Vec<Option<i64>>> = calls.iter().map(|call| ArgTuple(call)).collect();
+        let mut consts: BTreeMap<String, i64> = BTreeMap::new();
+        for (pos, param) in func.arguments.iter().flat_map(|list| list.iter()).enumerate() {
+            let first = tuples[0].get(pos).copied().flatten();
+            if let Some(value) = first {
+                if tuples.iter().all(|tuple| tuple.get(pos).copied().flatten() == Some(value)) {
+                    consts.insert(param.name.clone(), value);
+                }
+            }
+        }
+        if !consts.is_empty() {
+            known.insert(func.name.clone(), consts);
+        }
+    }
+    known
+}
+
+/*
+*************************************************************************************
+  THE FUNCTIONS THAT ALWAYS RETURN THE SAME CONSTANT, ONCE PROPAGATION HAS FOLDED
+  THEIR RETURN. WHO CALLS THEM DOES NOT MATTER.
+**************************************************************************************
+*/
+pub fn ConstantReturns(worklist: &RList) -> BTreeMap<String, i64> {
+    let mut returns: BTreeMap<String, i64> = BTreeMap::new();
+    for func in Defined(worklist) {
+        let last = Body(func).last().copied();
+        if let Some(ret) = last.filter(|stmt| stmt.stmtCode == StmtType::RETURN && stmt.right.is_none()) {
+            if let Some(value) = ret.left.as_ref().filter(|value| value.exprCode == ExprType::CONSTANT) {
+                returns.insert(func.name.clone(), value.value);
+            }
+        }
+    }
+    returns
+}
+
+/*
+*************************************************************************************
+  THE CALLS OF ONE FUNCTION TO FUNCTIONS RETURNING A CONSTANT BECOME THAT CONSTANT.
+  A PURE CALL WHOSE ARGUMENTS MAKE NO CALLS IS SIMPLY DROPPED. ANY OTHER CALL STILL
+  HAS TO RUN, SO IT MOVES TO AN ASSIGNMENT OF ITS OWN, TO A VARIABLE NOBODY READS,
+  AHEAD OF ITS STATEMENT. A CALL ALREADY IN THAT FORM IS LEFT ALONE.
+**************************************************************************************
+*/
+struct CallResults<'a> {
+    returns: &'a BTreeMap<String, i64>,
+    pure: &'a BTreeSet<String>,
+    names: BTreeSet<String>,
+    prelude: Vec<RNode>,
+}
+
+impl CallResults<'_> {
+    // settled is whether node is a call whose result is never read
+    fn replace(&mut self, node: &mut RNode, settled: bool) {
+        if node.exprCode == ExprType::VARIABLE || node.opCode == OpType::ADDRESSOF {
+            return;
+        }
+        if node.opCode != OpType::FUNCTIONCALL {
+            if let Some(left) = node.left.as_mut() {
+                self.replace(left, false);
+            }
+        }
+        if let Some(right) = node.right.as_mut() {
+            self.replace(right, false);
+        }
+        if let Some(args) = node.arguments.as_mut() {
+            for arg in args.iter_mut() {
+                self.replace(arg, false);
+            }
+        }
+        if node.opCode != OpType::FUNCTIONCALL || settled {
+            return;
+        }
+
+        let callee = CalleeName(node).to_string();
+        let Some(&value) = self.returns.get(&callee) else {
+            return;
+        };
+        let args_call = node.arguments.iter().flat_map(|list| list.iter()).any(|arg| arg.contains_op(OpType::FUNCTIONCALL));
+        if !self.pure.contains(&callee) || args_call {
+            let name = FreshPrefix(&mut self.names, &callee);
+            self.prelude.push(AssignNode(name, DECL_LONG, Some(Box::new(node.clone()))));
+        }
+        MakeConstant(node, value);
+        madeChange.store(true, Ordering::Relaxed);
+    }
+}
+
+pub fn ReplaceConstantCalls(func: &mut RNode, returns: &BTreeMap<String, i64>, pure: &BTreeSet<String>) -> bool {
+    madeChange.store(false, Ordering::Relaxed);
+    let live = LiveVariables::compute(func);
+    let mut results = CallResults { returns, pure, names: AssignedNames(func), prelude: Vec::new() };
+
+    let Some(mut statements) = func.statements.as_mut() else {
+        return false;
+    };
+    let mut pos: usize = 0;
+    while let Some(stmt) = statements.node.as_mut() {
+        let settled = stmt.stmtCode == StmtType::ASSIGN && !live.is_live_after(&stmt.name, pos);
+        if let Some(left) = stmt.left.as_mut() {
+            results.replace(left, false);
+        }
+        if let Some(right) = stmt.right.as_mut() {
+            results.replace(right, settled);
+        }
+        let prelude = std::mem::take(&mut results.prelude);
+        let inserted = prelude.len();
+        if inserted > 0 {
+            InsertBefore(statements, prelude);
+        }
+        for _ in 0..inserted {
+            statements = statements.next.as_mut().expect("moved calls are followed by their statement");
+        }
+        pos += 1;
+        if let Some(next) = statements.next.as_mut() {
+            statements = next;
+        } else {
+            break;
+        }
+    }
+    madeChange.load(Ordering::Relaxed)
+}
+
+/*
+*************************************************************************************
+  SPECIALIZATION: WHEN SEVERAL CALLS TO A FUNCTION PASS THE SAME CONSTANTS, THEY GO
+  TO A STATIC COPY OF IT INSTEAD. EVERY CALLER OF THE COPY AGREES ON THOSE
+  CONSTANTS, SO ConstantParams FOLDS THEM INTO ITS BODY. RECURSIVE FUNCTIONS ARE
+  NOT COPIED, AND NEITHER IS A STATIC FUNCTION WHOSE CALLERS ALL AGREE ALREADY.
+**************************************************************************************
+*/
+struct Specialization {
+    callee: String,
+    tuple: Vec<Option<i64>>,
+    clone: RNode,
+}
+
+fn Redirect(node: &mut RNode, plans: &[Specialization]) {
+    if node.exprCode == ExprType::OPERATION && node.opCode == OpType::FUNCTIONCALL {
+        let tuple = ArgTuple(node);
+        let plan = plans.iter().find(|plan| plan.callee == CalleeName(node) && plan.tuple == tuple);
+        if let (Some(plan), Some(callee)) = (plan, node.left.as_mut()) {
+            callee.name = plan.clone.name.clone();
+        }
+    }
+    if node.exprCode == ExprType::VARIABLE || node.opCode == OpType::ADDRESSOF {
+        return;
+    }
+    if node.opCode != OpType::FUNCTIONCALL {
+        if let Some(left) = node.left.as_mut() {
+            Redirect(left, plans);
+        }
+    }
+    if let Some(right) = node.right.as_mut() {
+        Redirect(right, plans);
+    }
+    if let Some(args) = node.arguments.as_mut() {
+        for arg in args.iter_mut() {
+            Redirect(arg, plans);
+        }
+    }
+}
+
+pub fn Specialize(worklist: &mut RList) -> bool {
+    let recursive = RecursiveFunctions(worklist);
+    let sites = CallSites(worklist);
+    let mut names: BTreeSet<String> = worklist.iter().map(|node| node.name.clone()).collect();
+
+    let mut plans: Vec<Specialization> = Vec::new();
+    for func in Defined(worklist) {
+        let size: usize = Body(func).iter().map(|stmt| Size(stmt)).sum();
+        if recursive.contains(&func.name) || size > SPECIALIZE_LIMIT {
+            continue;
+        }
+        let mut tuples: BTreeMap<Vec<Option<i64>>, usize> = BTreeMap::new();
+        for call in sites.get(&func.name).into_iter().flatten() {
+            *tuples.entry(ArgTuple(call)).or_default() += 1;
+        }
+        if func.is_static() && tuples.len() == 1 {
+            continue;
+        }
+        for (tuple, count) in tuples {
+            if count < 2 || tuple.iter().all(Option::is_none) {
+                continue;
+            }
+            let mut count = 1;
+            while names.contains(&format!("{}.spec{}", func.name, count)) {
+                count += 1;
+            }
+            let mut clone = func.clone();
+            clone.name = format!("{}.spec{}", func.name, count);
+            clone.value = LINKAGE_STATIC;
+            names.insert(clone.name.clone());
+            plans.push(Specialization { callee: func.name.clone(), tuple, clone });
+        }
+    }
+    if plans.is_empty() {
+        return false;
+    }
+
+    for func in worklist.iter_mut() {
+        for stmt in func.statements.iter_mut().flat_map(|list| list.iter_mut()) {
+            Redirect(stmt, &plans);
+        }
+    }
+    let mut last = worklist;
+    while last.next.is_some() {
+        last = last.next.as_mut().expect("checked just before");
+    }
+    for plan in plans {
+        last.next = Some(Box::new(RList { node: Some(Box::new(plan.clone)), next: None }));
+        last = last.next.as_mut().expect("the copy was just added");
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Specialize;
+    use crate::constprop::ConstProp;
+    use crate::expression::build::*;
+    use crate::expression::*;
+
+    #[test]
+    fn constants_cross_calls_both_ways() {
+        // static long scale(long x, long k) { return x * k; }
+        // long five(long p) { *p = 1; return 5; }
+        // long foo(long a, long p) { long s = scale(a, 3); long t = scale(a + 1, 3); long f = five(p); return s + t + f; }
+        let mut scale = func("scale", vec!["x", "k"], vec![ret(binop(OpType::MULTIPLY, param_ref("x"), param_ref("k")))]);
+        scale.value = LINKAGE_STATIC;
+        let sum = binop(OpType::ADD, binop(OpType::ADD, var("s"), var("t")), var("f"));
+        let mut program = program(vec![
+            scale,
+            func("five", vec!["p"], vec![store(param_ref("p"), constant(1)), ret(constant(5))]),
+            func(
+                "foo",
+                vec!["a", "p"],
+                vec![
+                    assign("s", call("scale", vec![param_ref("a"), constant(3)])),
+                    assign("t", call("scale", vec![binop(OpType::ADD, param_ref("a"), constant(1)), constant(3)])),
+                    assign("f", call("five", vec![param_ref("p")])),
+                    ret(sum),
+                ],
+            ),
+        ]);
+
+        assert!(ConstProp(&mut program));
+
+        let product = body(&program, 0)[0].left.as_ref().unwrap();
+        assert_eq!(product.right.as_ref().unwrap().value, 3);
+        // five stores through p, so the call stays, but f is known
+        let foo = body(&program, 2);
+        assert_eq!((foo[2].name.as_str(), foo[2].right.as_ref().unwrap().opCode), ("five.1", OpType::FUNCTIONCALL));
+        assert_eq!(foo[3].right.as_ref().unwrap().value, 5);
+        assert!(!Specialize(&mut program));
+    }
+
+    #[test]
+    fn repeated_constant_arguments_get_a_copy() {
+        // long scale(long x, long k) { return x * k; }
+        // long foo(long a) { long s = scale(a, 3); long t = scale(a, 3); long u = scale(3, a); return s + t + u; }
+        let sum = binop(OpType::ADD, binop(OpType::ADD, var("s"), var("t")), var("u"));
+        let mut program = program(vec![
+            func("scale", vec!["x", "k"], vec![ret(binop(OpType::MULTIPLY, param_ref("x"), param_ref("k")))]),
+            func(
+                "foo",
+                vec!["a"],
+                vec![
+                    assign("s", call("scale", vec![param_ref("a"), constant(3)])),
+                    assign("t", call("scale", vec![param_ref("a"), constant(3)])),
+                    assign("u", call("scale", vec![constant(3), param_ref("a")])),
+                    ret(sum),
+                ],
+            ),
+        ]);
+
+        assert!(Specialize(&mut program));
+
+        let callee = |pos: usize| body(&program, 1)[pos].right.as_ref().unwrap().left.as_ref().unwrap().name.clone();
+        assert_eq!((callee(0), callee(1), callee(2)), ("scale.spec1".to_string(), "scale.spec1".to_string(), "scale".to_string()));
+        let copy = nodes(&program)[2];
+        assert!(copy.is_static());
+        assert!(!Specialize(&mut program));
+    }
+}
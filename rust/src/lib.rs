@@ -1,57 +1,136 @@
 #![allow(unused)]
 
+mod cfg;
 mod codegen;
 mod constfolding;
 mod constprop;
+mod copyprop;
+mod cse;
+mod dataflow;
 mod deadassign;
 pub mod expression;
+mod inline;
+mod ipcp;
+mod linkage;
+mod liveness;
+mod overload;
+mod reassoc;
+mod sccp;
+mod ssa;
+mod tac;
 
+use cfg::Cfg;
 use codegen::*;
 use constfolding::*;
 use constprop::*;
+use copyprop::*;
+use cse::*;
 use deadassign::*;
 use expression::*;
+use inline::*;
+use ipcp::*;
+use linkage::*;
+use overload::*;
+use reassoc::*;
+use ssa::*;
+use tac::*;
 
 #[no_mangle]
-pub extern "C" fn rust_mod(funcdecls: *mut NodeList) {
-    let mut rlist = from_nodelist(funcdecls);
+pub extern "C" fn rust_mod(funcdecls: *mut NodeList) -> libc::c_int {
+    compile(from_nodelist(funcdecls))
+}
 
+// Optimize the program and write its code to assembly.s. Returns the exit
+// status: 1 when the program has an error.
+fn compile(mut rlist: RList) -> libc::c_int {
     println!("Printing the AST BEFORE OPTIMIZATION");
     print_program(&rlist);
 
-    /*
-    *************************************
-         TODO: Call Optimizer Functions
-    *************************************
-    */
+    // overloads need distinct symbols before anything keys on function names
+    if let Err(msg) = MangleOverloads(&mut rlist) {
+        eprintln!("Error: {}", msg);
+        return 1;
+    }
+
+    // const declarations must fold whether or not the loop below would get to them
+    if let Err(msg) = ConstDecls(&mut rlist) {
+        eprintln!("Error: {}", msg);
+        return 1;
+    }
+
     let mut made_change: bool = true;
     while made_change {
-     /*
-     if ConstantFolding(&mut rlist) {
-          println!("folding\n");
-     }
-     else if ConstProp(&mut rlist) {
-          println!("prop\n");
-     }
-     else if DeadAssign(&mut rlist) {
-          println!("dead\n");
-     }
-     else {
-          made_change = false;
-     }
-     */
-     
-     made_change = ConstantFolding(&mut rlist) || ConstProp(&mut rlist) || DeadAssign(&mut rlist);
+        made_change = ConstantFolding(&mut rlist)
+            || Reassociate(&mut rlist)
+            || ConstProp(&mut rlist)
+            || CopyProp(&mut rlist)
+            || CommonSubexpr(&mut rlist)
+            || DeadAssign(&mut rlist)
+            || Inline(&mut rlist)
+            || Specialize(&mut rlist);
+    }
+
+    if let Some(line) = FailedAssert(&rlist) {
+        eprintln!("Error: (line {}) assertion is always false", line);
+        return 1;
+    }
+
+    let traps = ConstantTraps(&rlist);
+    for msg in traps.iter() {
+        eprintln!("Error: {}", msg);
+    }
+    if !traps.is_empty() {
+        return 1;
+    }
+
+    // static functions nobody calls anymore need no code
+    if let Err(msg) = StaticFunctions(&mut rlist) {
+        eprintln!("Error: {}", msg);
+        return 1;
     }
 
     println!("Printing the AST AFTER OPTIMIZATION");
     print_program(&rlist);
 
-    /*
-    *************************************
-         TODO: Call Codegen
-    *************************************
-    */
-    Codegen(&mut rlist);
+    // one more round of propagation and dead code removal on the three-address
+    // form, where every version of a variable has a single definition
+    let mut program = Lower(&rlist);
+    for func in program.functions.iter_mut() {
+        let mut ssa = ToSsa(func, Cfg::build_tac(func));
+        while SsaConstProp(&mut ssa) || SsaDeadCode(&mut ssa) {}
+        OutOfSsa(&mut ssa);
+        *func = ssa.into_tac();
+    }
+
+    Codegen(&program);
+    0
 }
 
+#[cfg(test)]
+mod tests {
+    use super::compile;
+    use crate::expression::build::*;
+    use crate::expression::*;
+
+    #[test]
+    fn divisions_known_to_trap_fail_the_compile() {
+        // long foo(long a) { long d = a + 1 / (2 - 2); return d; }
+        let program = program(vec![func(
+            "foo",
+            vec!["a"],
+            vec![
+                assign(
+                    "d",
+                    binop(
+                        OpType::ADD,
+                        param_ref("a"),
+                        binop(OpType::DIVIDE, constant(1), binop(OpType::SUBTRACT, constant(2), constant(2))),
+                    ),
+                ),
+                ret(var("d")),
+            ],
+        )]);
+
+        assert_eq!(compile(program), 1);
+    }
+}
@@ -0,0 +1,134 @@
+/*
+***********************************************************************
+  LINKAGE.RS : CLOSED-WORLD OPTIMIZATION OF STATIC FUNCTIONS
+************************************************************************
+*/
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+use crate::expression::*;
+use std::collections::BTreeSet;
+
+/*
+*************************************************************************************
+  COLLECT THE NAME OF EVERY FUNCTION CALLED IN A NODE, AT ANY DEPTH
+**************************************************************************************
+*/
+pub fn CollectCalls(node: &RNode, calls: &mut BTreeSet<String>) {
+    if node.opCode == OpType::FUNCTIONCALL {
+        if let Some(callee) = node.left.as_ref() {
+            calls.insert(callee.name.clone());
+        }
+    } else if let Some(left) = node.left.as_ref() {
+        // a VARIABLE links to its declaration, which is walked on its own
+        if node.exprCode != ExprType::VARIABLE {
+            CollectCalls(left, calls);
+        }
+    }
+    if let Some(right) = node.right.as_ref() {
+        CollectCalls(right, calls);
+    }
+    for list in [node.arguments.as_ref(), node.statements.as_ref()].into_iter().flatten() {
+        for item in list.iter() {
+            CollectCalls(item, calls);
+        }
+    }
+}
+
+/*
+*************************************************************************************
+  A STATIC FUNCTION CAN ONLY BE CALLED FROM ITS OWN FILE, SO IT MUST BE DEFINED
+  THERE, AND IT IS DEAD WHEN NO NON-STATIC FUNCTION CAN REACH IT THROUGH CALLS.
+  DEAD STATIC FUNCTIONS (AND THEIR PROTOTYPES) ARE DELETED.
+**************************************************************************************
+*/
+pub fn StaticFunctions(mut worklist: &mut RList) -> Result<(), String> {
+    let mut defined: BTreeSet<String> = BTreeSet::new();
+    let mut reachable: BTreeSet<String> = BTreeSet::new();
+    let mut pending: Vec<&RNode> = Vec::new();
+    for node in worklist.iter() {
+        if node.type_ == NodeType::FUNCTIONDECL && !node.is_prototype() {
+            defined.insert(node.name.clone());
+            if !node.is_static() {
+                pending.push(node);
+            }
+        }
+    }
+
+    // walk the call graph from the functions other files can call
+    while let Some(func) = pending.pop() {
+        let mut calls: BTreeSet<String> = BTreeSet::new();
+        CollectCalls(func, &mut calls);
+        for callee in calls {
+            if reachable.insert(callee.clone()) {
+                if let Some(decl) = worklist.iter().find(|n| n.name == callee && !n.is_prototype()) {
+                    pending.push(decl);
+                }
+            }
+        }
+    }
+
+    for node in worklist.iter() {
+        if node.is_static() && reachable.contains(&node.name) && !defined.contains(&node.name) {
+            return Err(format!("static function '{}' is used but never defined", node.name));
+        }
+    }
+
+    while let Some(node) = worklist.node.as_ref() {
+        if node.is_static() && !reachable.contains(&node.name) {
+            if let Some(next_node) = worklist.next.take() {
+                *worklist = *next_node;
+            } else {
+                worklist.node = None;
+            }
+            continue;
+        }
+
+        if let Some(next_node) = worklist.next.as_mut() {
+            worklist = next_node;
+        } else {
+            break;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StaticFunctions;
+    use crate::expression::build::*;
+    use crate::expression::*;
+
+    fn make_static(mut func: Box<RNode>) -> Box<RNode> {
+        func.value = LINKAGE_STATIC;
+        func
+    }
+
+    #[test]
+    fn uncalled_static_functions_are_deleted() {
+        // static long helper(long a) { return a; }
+        // static long unused(long a) { return unused(a); }
+        // long foo(long a) { return helper(a); }
+        let mut program = program(vec![
+            make_static(func("helper", vec!["a"], vec![ret(param_ref("a"))])),
+            make_static(func("unused", vec!["a"], vec![ret(call("unused", vec![param_ref("a")]))])),
+            func("foo", vec!["a"], vec![ret(call("helper", vec![param_ref("a")]))]),
+        ]);
+
+        assert!(StaticFunctions(&mut program).is_ok());
+
+        let names: Vec<&str> = nodes(&program).iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["helper", "foo"]);
+    }
+
+    #[test]
+    fn called_static_function_must_be_defined() {
+        // static long helper(long a); long foo(long a) { return helper(a); }
+        let mut program = program(vec![
+            make_static(prototype("helper", vec!["a"])),
+            func("foo", vec!["a"], vec![ret(call("helper", vec![param_ref("a")]))]),
+        ]);
+
+        assert!(StaticFunctions(&mut program).is_err());
+    }
+}
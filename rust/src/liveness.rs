@@ -0,0 +1,173 @@
+/*
+***********************************************************************
+  LIVENESS.RS : WHICH VARIABLES ARE LIVE AROUND EACH STATEMENT
+************************************************************************
+*/
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+use crate::cfg::*;
+use crate::dataflow::*;
+use crate::expression::*;
+use crate::tac::*;
+use std::collections::BTreeSet;
+
+/*
+*************************************************************************************
+  THE LIVE VARIABLES OF A FUNCTION, STATEMENT BY STATEMENT. A STATEMENT IS NAMED BY
+  ITS POSITION IN THE FUNCTION'S STATEMENT LIST, AS IN THE CFG. A VARIABLE IS LIVE
+  AFTER A STATEMENT WHEN ITS VALUE THERE MAY STILL BE READ; ANY LOAD OR CALL MAY
+  READ THE VARIABLES WHOSE ADDRESS IS TAKEN. THE ANSWERS DESCRIBE THE FUNCTION AS
+  IT WAS WHEN COMPUTED.
+**************************************************************************************
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiveVariables {
+    live_in: Vec<BTreeSet<String>>,
+    live_out: Vec<BTreeSet<String>>,
+}
+
+impl LiveVariables {
+    pub fn compute(func: &RNode) -> Self {
+        let stmts = Statements(func);
+        let count = stmts.len();
+        let mut live_in: Vec<BTreeSet<String>> = vec![BTreeSet::new(); count];
+        let mut live_out: Vec<BTreeSet<String>> = vec![BTreeSet::new(); count];
+
+        let cfg = Cfg::build(func);
+        let analysis = Liveness::new(func);
+        let solution = Solve(&analysis, &cfg, func);
+
+        // walk each block backward from its live-out set
+        for block in cfg.blocks() {
+            let mut live = solution.block_out[block.id].clone();
+            for (pos, stmt) in block.positions().zip(cfg.instructions(block.id, &stmts)).rev() {
+                live_out[pos] = live.clone();
+                analysis.transfer(stmt, pos, &mut live);
+                live_in[pos] = live.clone();
+            }
+        }
+        LiveVariables { live_in, live_out }
+    }
+
+    // The same answers for a function lowered to three-address code, where values
+    // are named as they print. A variable whose address is taken is never tracked:
+    // a store or a call may read or write it at any point.
+    pub fn compute_tac(func: &TacFunction) -> Self {
+        let count = func.body.len();
+        let mut live_in: Vec<BTreeSet<String>> = vec![BTreeSet::new(); count];
+        let mut live_out: Vec<BTreeSet<String>> = vec![BTreeSet::new(); count];
+        let escaped: BTreeSet<String> = func.body.iter().filter_map(AddressTaken).collect();
+
+        let cfg = Cfg::build_tac(func);
+        let mut block_in: Vec<BTreeSet<String>> = vec![BTreeSet::new(); cfg.len()];
+        // sets only ever grow, so this settles
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for block in cfg.blocks().rev() {
+                let mut live: BTreeSet<String> = block.succs.iter().flat_map(|succ| block_in[*succ].iter().cloned()).collect();
+                for (pos, instr) in block.positions().zip(cfg.instructions(block.id, &func.body)).rev() {
+                    live_out[pos] = live.clone();
+                    if let Some(dest) = instr.dest() {
+                        live.remove(&dest.to_string());
+                    }
+                    for value in instr.uses() {
+                        if !matches!(value, Value::Const(_)) && !escaped.contains(&value.to_string()) {
+                            live.insert(value.to_string());
+                        }
+                    }
+                    live_in[pos] = live.clone();
+                }
+                if live != block_in[block.id] {
+                    block_in[block.id] = live;
+                    changed = true;
+                }
+            }
+        }
+        LiveVariables { live_in, live_out }
+    }
+
+    // The variables live right after the statement at pos
+    pub fn live_out(&self, pos: usize) -> &BTreeSet<String> {
+        &self.live_out[pos]
+    }
+
+    // The variables live right before the statement at pos
+    pub fn live_in(&self, pos: usize) -> &BTreeSet<String> {
+        &self.live_in[pos]
+    }
+
+    pub fn is_live_after(&self, name: &str, pos: usize) -> bool {
+        self.live_out[pos].contains(name)
+    }
+
+    pub fn is_live_before(&self, name: &str, pos: usize) -> bool {
+        self.live_in[pos].contains(name)
+    }
+}
+
+// The variable an instruction takes the address of, if any
+pub fn AddressTaken(instr: &Instr) -> Option<String> {
+    match instr {
+        Instr::Unary { op: OpType::ADDRESSOF, src, .. } => Some(src.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LiveVariables;
+    use crate::expression::build::*;
+    use crate::expression::*;
+    use crate::tac::ParseTac;
+
+    #[test]
+    fn answers_per_statement() {
+        // long foo(long a) { long x = a + 1; long y = 2; assert(x); long p = &y; return *p; long z = a; }
+        let foo = func(
+            "foo",
+            vec!["a"],
+            vec![
+                assign("x", binop(OpType::ADD, param_ref("a"), constant(1))),
+                assign("y", constant(2)),
+                assert_at(1, var("x")),
+                assign("p", unop(OpType::ADDRESSOF, var("y"))),
+                ret(unop(OpType::DEREF, var("p"))),
+                assign("z", param_ref("a")),
+            ],
+        );
+
+        let live = LiveVariables::compute(&foo);
+
+        assert!(live.is_live_after("x", 1));
+        assert!(!live.is_live_after("x", 2));
+        // y is only read through p
+        assert!(live.is_live_after("y", 3));
+        assert!(live.live_out(4).is_empty());
+        assert!(!live.is_live_after("z", 5));
+        assert_eq!(live.live_in(0).iter().collect::<Vec<_>>(), vec!["a"]);
+    }
+
+    #[test]
+    fn answers_per_instruction() {
+        let tac = ParseTac(
+            "func foo(a) {\n\
+             \tt1 = a + 1\n\
+             \tt2 = addr y\n\
+             \ty = t1\n\
+             \tcall bar(t2)\n\
+             \tret t1\n\
+             }\n",
+        )
+        .unwrap();
+
+        let live = LiveVariables::compute_tac(&tac.functions[0]);
+
+        assert_eq!(live.live_in(0).iter().collect::<Vec<_>>(), vec!["a"]);
+        assert!(live.is_live_after("t1", 0) && live.is_live_after("t1", 3));
+        assert!(!live.is_live_after("t2", 3));
+        // y is only ever reached through t2
+        assert!(live.live_out(2).iter().all(|name| name != "y"));
+    }
+}
@@ -0,0 +1,174 @@
+/*
+***********************************************************************
+  OVERLOAD.RS : GIVE EVERY OVERLOADED FUNCTION ITS OWN ASSEMBLY SYMBOL
+************************************************************************
+*/
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+use crate::expression::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+/*
+*************************************************************************************
+  THE PARSER RESOLVES EVERY CALL TO THE OVERLOAD WITH THE MATCHING NUMBER OF
+  PARAMETERS. HERE, FUNCTIONS WHOSE NAME IS DECLARED WITH SEVERAL PARAMETER COUNTS
+  ARE RENAMED TO THE ITANIUM C++ SYMBOL OF `long name(long, ...)`, SO THE SYMBOL
+  ONLY DEPENDS ON THE SIGNATURE AND C++ CODE CAN STILL DECLARE AND CALL THEM.
+  A NAME THAT IS NOT OVERLOADED KEEPS ITS PLAIN SYMBOL. SO DOES ONE OVERLOAD OF
+  AN OVERLOADED NAME, SO C CODE CAN STILL CALL IT: A PROTOTYPE THAT IS NEVER
+  DEFINED HERE, SINCE IT NAMES A FUNCTION OF ANOTHER OBJECT FILE, OR ELSE THE
+  FIRST NON-STATIC OVERLOAD DECLARED.
+**************************************************************************************
+*/
+fn MangledName(name: &str, arity: usize) -> String {
+    let params = if arity == 0 { "v".to_string() } else { "l".repeat(arity) };
+    format!("_Z{}{}{}", name.len(), name, params)
+}
+
+fn Arity(node: &RNode) -> usize {
+    node.arguments.as_ref().map_or(0, |args| args.iter().count())
+}
+
+fn RenameCalls(node: &mut RNode, symbols: &BTreeMap<(String, usize), String>) {
+    if node.opCode == OpType::FUNCTIONCALL {
+        // the parser picked the overload with as many parameters as arguments
+        let arity = Arity(node);
+        if let Some(callee) = node.left.as_mut() {
+            if let Some(symbol) = symbols.get(&(callee.name.clone(), arity)) {
+                callee.name = symbol.clone();
+            }
+        }
+    } else if let Some(left) = node.left.as_mut() {
+        RenameCalls(left, symbols);
+    }
+    if let Some(right) = node.right.as_mut() {
+        RenameCalls(right, symbols);
+    }
+    for list in [node.arguments.as_mut(), node.statements.as_mut()].into_iter().flatten() {
+        let mut current = list;
+        loop {
+            if let Some(item) = current.node.as_mut() {
+                RenameCalls(item, symbols);
+            }
+            if let Some(next) = current.next.as_mut() {
+                current = next;
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+pub fn MangleOverloads(worklist: &mut RList) -> Result<(), String> {
+    // every declared (name, parameter count), in the order of its first declaration
+    let mut overloads: Vec<(String, usize)> = Vec::new();
+    let mut defined: BTreeSet<(String, usize)> = BTreeSet::new();
+    let mut local: BTreeSet<(String, usize)> = BTreeSet::new();
+    for node in worklist.iter() {
+        if node.type_ == NodeType::FUNCTIONDECL {
+            let key = (node.name.clone(), Arity(node));
+            if !overloads.contains(&key) {
+                overloads.push(key.clone());
+            }
+            if !node.is_prototype() {
+                defined.insert(key.clone());
+            }
+            if node.is_static() {
+                local.insert(key);
+            }
+        }
+    }
+
+    // the parameter count keeping the plain symbol, for every name
+    let mut external: BTreeMap<String, usize> = BTreeMap::new();
+    for (name, arity) in overloads.iter().filter(|key| !defined.contains(*key)) {
+        if let Some(first) = external.insert(name.clone(), *arity) {
+            return Err(format!(
+                "external function '{}' is declared with both {} and {} parameters",
+                name, first, arity
+            ));
+        }
+    }
+    for (name, arity) in overloads.iter().filter(|key| !local.contains(*key)) {
+        external.entry(name.clone()).or_insert(*arity);
+    }
+
+    let mut symbols: BTreeMap<(String, usize), String> = BTreeMap::new();
+    for (name, arity) in overloads.iter() {
+        let overloaded = overloads.iter().filter(|(other, _)| other == name).count() > 1;
+        if overloaded && external.get(name) != Some(arity) {
+            symbols.insert((name.clone(), *arity), MangledName(name, *arity));
+        }
+    }
+
+    let mut current = worklist;
+    loop {
+        if let Some(node) = current.node.as_mut() {
+            if let Some(symbol) = symbols.get(&(node.name.clone(), Arity(node))) {
+                if node.type_ == NodeType::FUNCTIONDECL {
+                    node.name = symbol.clone();
+                }
+            }
+            RenameCalls(node, &symbols);
+        }
+        if let Some(next) = current.next.as_mut() {
+            current = next;
+        } else {
+            break;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MangleOverloads;
+    use crate::expression::build::*;
+    use crate::expression::*;
+
+    #[test]
+    fn overloads_get_signature_symbols() {
+        // long boo(long a); long boo(long a, long b) { return boo(a); } long foo() { return boo(1, 2); }
+        let mut program = program(vec![
+            prototype("boo", vec!["a"]),
+            func("boo", vec!["a", "b"], vec![ret(call("boo", vec![param_ref("a")]))]),
+            func("foo", vec![], vec![ret(call("boo", vec![constant(1), constant(2)]))]),
+        ]);
+
+        assert!(MangleOverloads(&mut program).is_ok());
+
+        let names: Vec<&str> = nodes(&program).iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["boo", "_Z3booll", "foo"]);
+        let callee = |index: usize| body(&program, index)[0].left.as_ref().unwrap().left.as_ref().unwrap().name.clone();
+        assert_eq!(callee(1), "boo");
+        assert_eq!(callee(2), "_Z3booll");
+    }
+
+    #[test]
+    fn first_external_overload_keeps_plain_symbol() {
+        // long foo(long a, long b) { return foo(a); } long foo(long a) { return a; }
+        // static long foo() { return 1; }
+        let mut hidden = func("foo", vec![], vec![ret(constant(1))]);
+        hidden.value = LINKAGE_STATIC;
+        let mut program = program(vec![
+            func("foo", vec!["a", "b"], vec![ret(call("foo", vec![param_ref("a")]))]),
+            func("foo", vec!["a"], vec![ret(param_ref("a"))]),
+            hidden,
+        ]);
+
+        assert!(MangleOverloads(&mut program).is_ok());
+
+        let names: Vec<&str> = nodes(&program).iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["foo", "_Z3fool", "_Z3foov"]);
+        assert_eq!(body(&program, 0)[0].left.as_ref().unwrap().left.as_ref().unwrap().name, "_Z3fool");
+    }
+
+    #[test]
+    fn only_one_external_overload() {
+        // long bar(long a); long bar(long a, long b);
+        let mut program = program(vec![prototype("bar", vec!["a"]), prototype("bar", vec!["a", "b"])]);
+
+        assert!(MangleOverloads(&mut program).is_err());
+    }
+}
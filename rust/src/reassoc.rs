@@ -0,0 +1,206 @@
+/*
+***********************************************************************
+  REASSOC.RS : REASSOCIATION OF ASSOCIATIVE AND COMMUTATIVE CHAINS
+************************************************************************
+*/
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+use crate::constfolding::ConstNode;
+use crate::expression::*;
+use lazy_static::lazy_static;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+lazy_static! {
+    static ref madeChange: AtomicBool = AtomicBool::new(false);
+}
+
+/*
+*************************************************************************************
+  THE OPERATORS WHOSE CHAINS CAN BE REGROUPED AND REORDERED FREELY, WITH HOW TWO
+  CONSTANTS COMBINE AND THE CONSTANT THAT CHANGES NOTHING. ADDITION AND
+  MULTIPLICATION WRAP, AS addq AND imulq DO, SO REGROUPING NEVER CHANGES A RESULT.
+**************************************************************************************
+*/
+type Combiner = fn(i64, i64) -> i64;
+
+fn Combine(op: OpType) -> Option<(Combiner, i64)> {
+    match op {
+        OpType::ADD => Some((i64::wrapping_add, 0)),
+        OpType::MULTIPLY => Some((i64::wrapping_mul, 1)),
+        OpType::BAND => Some((|a, b| a & b, -1)),
+        OpType::BOR => Some((|a, b| a | b, 0)),
+        OpType::BXOR => Some((|a, b| a ^ b, 0)),
+        _ => None,
+    }
+}
+
+fn IsChainOf(node: &RNode, op: OpType) -> bool {
+    node.exprCode == ExprType::OPERATION && node.opCode == op
+}
+
+// The operands of a chain of op, from left to right
+fn Operands(node: &RNode, op: OpType) -> Vec<&RNode> {
+    if !IsChainOf(node, op) {
+        return vec![node];
+    }
+    let mut operands = Vec::new();
+    for side in [node.left.as_deref(), node.right.as_deref()].into_iter().flatten() {
+        operands.extend(Operands(side, op));
+    }
+    operands
+}
+
+fn TakeOperands(node: Box<RNode>, op: OpType, operands: &mut Vec<RNode>) {
+    if !IsChainOf(&node, op) {
+        operands.push(*node);
+        return;
+    }
+    let mut node = node;
+    for side in [node.left.take(), node.right.take()].into_iter().flatten() {
+        TakeOperands(side, op, operands);
+    }
+}
+
+fn Operation(op: OpType, left: Box<RNode>, right: Box<RNode>) -> Box<RNode> {
+    Box::new(RNode::new(
+        "".to_string(),
+        0,
+        NodeType::EXPRESSION,
+        ExprType::OPERATION,
+        op,
+        StmtType::S_NONE,
+        Some(left),
+        Some(right),
+        None,
+        None,
+    ))
+}
+
+/*
+*************************************************************************************
+  THIS FUNCTION REASSOCIATES AN EXPRESSION, INNERMOST FIRST. A CHAIN IS REBUILT AS
+  ITS OTHER OPERANDS, IN THEIR ORDER, LEANING LEFT, WITH ALL ITS CONSTANTS FOLDED
+  INTO ONE ON THE RIGHT OF THE TOP: 5 + (x + 3) BECOMES x + 8. A CHAIN ALREADY IN
+  THAT FORM IS LEFT ALONE, SO THE PASS SETTLES.
+**************************************************************************************
+*/
+fn ReassociateExpr(node: &mut RNode) {
+    if node.exprCode != ExprType::OPERATION || node.opCode == OpType::ADDRESSOF {
+        return;
+    }
+    if node.opCode == OpType::FUNCTIONCALL {
+        if let Some(args) = node.arguments.as_mut() {
+            for arg in args.iter_mut() {
+                ReassociateExpr(arg);
+            }
+        }
+        return;
+    }
+    if let Some(left) = node.left.as_mut() {
+        ReassociateExpr(left);
+    }
+    if let Some(right) = node.right.as_mut() {
+        ReassociateExpr(right);
+    }
+
+    let op = node.opCode;
+    let Some((combine, identity)) = Combine(op) else {
+        return;
+    };
+    let constants = Operands(node, op).iter().filter(|operand| operand.exprCode == ExprType::CONSTANT).count();
+    let on_the_right = node.right.as_ref().is_some_and(|right| right.exprCode == ExprType::CONSTANT);
+    if constants == 0 || (constants == 1 && on_the_right) {
+        return;
+    }
+
+    let mut operands: Vec<RNode> = Vec::new();
+    TakeOperands(Box::new(std::mem::replace(node, *ConstNode(0))), op, &mut operands);
+    let (folded, others): (Vec<RNode>, Vec<RNode>) =
+        operands.into_iter().partition(|operand| operand.exprCode == ExprType::CONSTANT);
+    let value = folded.iter().map(|operand| operand.value).fold(identity, combine);
+
+    let mut others = others.into_iter();
+    let rebuilt = match others.next() {
+        None => ConstNode(value),
+        Some(first) => {
+            let chain = others.fold(Box::new(first), |chain, operand| Operation(op, chain, Box::new(operand)));
+            if value == identity {
+                chain
+            } else {
+                Operation(op, chain, ConstNode(value))
+            }
+        }
+    };
+    *node = *rebuilt;
+    madeChange.store(true, Ordering::Relaxed);
+}
+
+/*
+*************************************************************************************
+  REASSOCIATION FOR EVERY EXPRESSION OF EVERY FUNCTION
+**************************************************************************************
+*/
+pub fn Reassociate(worklist: &mut RList) -> bool {
+    madeChange.store(false, Ordering::Relaxed);
+    for func in worklist.iter_mut() {
+        for stmt in func.statements.iter_mut().flat_map(|list| list.iter_mut()) {
+            // the expressions of a statement hang from left and right
+            for side in [stmt.left.as_mut(), stmt.right.as_mut()].into_iter().flatten() {
+                ReassociateExpr(side);
+            }
+        }
+    }
+    madeChange.load(Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Reassociate;
+    use crate::expression::build::*;
+    use crate::expression::*;
+
+    #[test]
+    fn constants_gather_and_fold() {
+        // long foo(long x, long y) { long a = 5 + (x + 3); long b = (x * 4) * 2;
+        //   long c = ((x ^ 6) ^ y) ^ 6; long d = (x + 9223372036854775807) + 1; return a + b + c + d; }
+        let sum = binop(
+            OpType::ADD,
+            binop(OpType::ADD, var("a"), var("b")),
+            binop(OpType::ADD, var("c"), var("d")),
+        );
+        let mut program = program(vec![func(
+            "foo",
+            vec!["x", "y"],
+            vec![
+                assign("a", binop(OpType::ADD, constant(5), binop(OpType::ADD, param_ref("x"), constant(3)))),
+                assign("b", binop(OpType::MULTIPLY, binop(OpType::MULTIPLY, param_ref("x"), constant(4)), constant(2))),
+                assign(
+                    "c",
+                    binop(
+                        OpType::BXOR,
+                        binop(OpType::BXOR, binop(OpType::BXOR, param_ref("x"), constant(6)), param_ref("y")),
+                        constant(6),
+                    ),
+                ),
+                assign("d", binop(OpType::ADD, binop(OpType::ADD, param_ref("x"), constant(i64::MAX)), constant(1))),
+                ret(sum),
+            ],
+        )]);
+
+        assert!(Reassociate(&mut program));
+
+        let body = body(&program, 0);
+        let shape = |pos: usize| {
+            let expr = body[pos].right.as_ref().unwrap();
+            let right = expr.right.as_ref().unwrap();
+            (expr.opCode, expr.left.as_ref().unwrap().name.clone(), right.exprCode, right.value)
+        };
+        assert_eq!(shape(0), (OpType::ADD, "x".to_string(), ExprType::CONSTANT, 8));
+        assert_eq!(shape(1), (OpType::MULTIPLY, "x".to_string(), ExprType::CONSTANT, 8));
+        // the two 6s cancel out
+        assert_eq!(shape(2), (OpType::BXOR, "x".to_string(), ExprType::VARIABLE, 0));
+        assert_eq!(shape(3), (OpType::ADD, "x".to_string(), ExprType::CONSTANT, i64::MIN));
+        assert!(!Reassociate(&mut program));
+    }
+}
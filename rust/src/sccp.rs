@@ -0,0 +1,201 @@
+/*
+***********************************************************************
+  SCCP.RS : CONDITIONAL CONSTANT PROPAGATION OVER THE CFG
+************************************************************************
+*/
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+use crate::cfg::*;
+use crate::constfolding::FoldOperation;
+use crate::dataflow::*;
+use crate::expression::*;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+/*
+*************************************************************************************
+  THE VALUE OF A VARIABLE AT SOME POINT: NOT ASSIGNED ON ANY PATH SEEN SO FAR, THE
+  SAME CONSTANT ON EVERY PATH, OR VARYING. VALUES ONLY EVER MOVE DOWN, SO THE
+  SOLVER TERMINATES.
+**************************************************************************************
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConstValue {
+    Undefined,
+    Const(i64),
+    Varying,
+}
+
+impl ConstValue {
+    pub fn meet(self, other: ConstValue) -> ConstValue {
+        match (self, other) {
+            (ConstValue::Undefined, value) | (value, ConstValue::Undefined) => value,
+            (ConstValue::Const(a), ConstValue::Const(b)) if a == b => ConstValue::Const(a),
+            _ => ConstValue::Varying,
+        }
+    }
+}
+
+// The value of every variable at a point; a missing variable is Undefined
+pub type ConstEnv = BTreeMap<String, ConstValue>;
+
+fn Lookup(env: &ConstEnv, name: &str) -> ConstValue {
+    env.get(name).copied().unwrap_or(ConstValue::Undefined)
+}
+
+// Merge the environment arriving over one edge into a block's. Returns whether it changed.
+fn MeetEnv(env: &mut ConstEnv, other: &ConstEnv) -> bool {
+    let mut changed = false;
+    for (name, value) in other.iter() {
+        let merged = Lookup(env, name).meet(*value);
+        if merged != Lookup(env, name) {
+            env.insert(name.clone(), merged);
+            changed = true;
+        }
+    }
+    changed
+}
+
+/*
+*************************************************************************************
+  THE VALUE OF AN EXPRESSION IN AN ENVIRONMENT. MEMORY, CALLS AND WHAT CANNOT BE
+  FOLDED AT COMPILE TIME (A DIVISION THAT TRAPS) ARE VARYING.
+**************************************************************************************
+*/
+pub fn EvalExpr(expr: &RNode, env: &ConstEnv) -> ConstValue {
+    match expr.exprCode {
+        ExprType::CONSTANT => ConstValue::Const(expr.value),
+        ExprType::VARIABLE => Lookup(env, &expr.name),
+        ExprType::OPERATION => {
+            let operand = |node: Option<&Box<RNode>>| node.map(|node| EvalExpr(node, env));
+            match expr.opCode {
+                OpType::FUNCTIONCALL | OpType::DEREF | OpType::ADDRESSOF | OpType::RESULTHIGH | OpType::O_NONE => {
+                    ConstValue::Varying
+                }
+                op => match (operand(expr.left.as_ref()), operand(expr.right.as_ref())) {
+                    (Some(ConstValue::Varying), _) | (_, Some(ConstValue::Varying)) => ConstValue::Varying,
+                    (Some(ConstValue::Const(left)), Some(ConstValue::Const(right))) => {
+                        FoldOperation(op, left, Some(right)).map_or(ConstValue::Varying, ConstValue::Const)
+                    }
+                    (Some(ConstValue::Const(left)), None) => {
+                        FoldOperation(op, left, None).map_or(ConstValue::Varying, ConstValue::Const)
+                    }
+                    (None, _) => ConstValue::Varying,
+                    _ => ConstValue::Undefined,
+                },
+            }
+        }
+        _ => ConstValue::Varying,
+    }
+}
+
+// How a statement changes the environment. escaped are the address-taken variables,
+// which a store or a call may change.
+pub fn TransferConst(stmt: &RNode, env: &mut ConstEnv, escaped: &BTreeSet<String>) {
+    if let Some(name) = AssignedName(stmt) {
+        let value = stmt.right.as_ref().map_or(ConstValue::Varying, |right| EvalExpr(right, env));
+        env.insert(name.to_string(), value);
+    }
+    if stmt.stmtCode == StmtType::STORE || stmt.contains_op(OpType::FUNCTIONCALL) {
+        for name in escaped.iter() {
+            env.insert(name.clone(), ConstValue::Varying);
+        }
+    }
+}
+
+// Whether control can leave a block through its fall-through edge: an assert whose
+// condition is the constant zero stops the program
+fn FallsThrough(last: Option<&RNode>, env: &ConstEnv) -> bool {
+    match last {
+        Some(stmt) if stmt.stmtCode == StmtType::ASSERT => {
+            stmt.left.as_ref().is_none_or(|cond| EvalExpr(cond, env) != ConstValue::Const(0))
+        }
+        _ => true,
+    }
+}
+
+/*
+*************************************************************************************
+  THE SOLUTION: WHICH BLOCKS CAN EXECUTE, AND THE ENVIRONMENT AT THE START OF EACH.
+  A BLOCK IS ONLY VISITED ONCE AN EDGE INTO IT IS KNOWN TO BE TAKEN, AND ONLY THE
+  ENVIRONMENTS OF TAKEN EDGES MEET, SO CONSTANTS FROM PATHS THAT NEVER RUN DO NOT
+  SPOIL THE ONES THAT DO (WEGMAN AND ZADECK). THE STATEMENTS ARE NOT IN SSA FORM,
+  SO EACH BLOCK KEEPS A WHOLE ENVIRONMENT INSTEAD OF ONE VALUE PER SSA NAME.
+**************************************************************************************
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct SccpSolution {
+    pub executable: Vec<bool>,
+    pub block_in: Vec<ConstEnv>,
+    pub escaped: BTreeSet<String>,
+}
+
+// known are the parameters every caller passes the same constant for
+pub fn Sccp(func: &RNode, cfg: &Cfg, known: &BTreeMap<String, i64>) -> SccpSolution {
+    let escaped = EscapedNames(func);
+    let stmts = Statements(func);
+    let mut executable = vec![false; cfg.len()];
+    let mut block_in: Vec<ConstEnv> = vec![ConstEnv::new(); cfg.len()];
+
+    // the other parameters hold whatever the caller passed
+    for param in func.arguments.iter().flat_map(|list| list.iter()) {
+        let value = known.get(&param.name).map_or(ConstValue::Varying, |value| ConstValue::Const(*value));
+        block_in[cfg.entry()].insert(param.name.clone(), value);
+    }
+    executable[cfg.entry()] = true;
+    let mut pending: VecDeque<BlockId> = VecDeque::from([cfg.entry()]);
+
+    while let Some(id) = pending.pop_front() {
+        let mut env = block_in[id].clone();
+        let mut last: Option<&RNode> = None;
+        for stmt in cfg.instructions(id, &stmts) {
+            TransferConst(stmt, &mut env, &escaped);
+            last = Some(*stmt);
+        }
+        if !FallsThrough(last, &env) {
+            continue;
+        }
+        for &succ in cfg.block(id).succs.iter() {
+            let changed = MeetEnv(&mut block_in[succ], &env);
+            if (changed || !executable[succ]) && !pending.contains(&succ) {
+                pending.push_back(succ);
+            }
+            executable[succ] = true;
+        }
+    }
+    SccpSolution { executable, block_in, escaped }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expression::build::*;
+
+    #[test]
+    fn constants_flow_until_a_false_assert() {
+        // long foo(long a) { long x = 4; assert(x - 4); long y = x; return y; }
+        let foo = func(
+            "foo",
+            vec!["a"],
+            vec![
+                assign("x", constant(4)),
+                assert_at(1, binop(OpType::SUBTRACT, var("x"), constant(4))),
+                assign("y", var("x")),
+                ret(var("y")),
+            ],
+        );
+        let cfg = Cfg::build(&foo);
+
+        let solution = Sccp(&foo, &cfg, &BTreeMap::new());
+
+        assert_eq!(solution.executable, vec![true, false, false]);
+        assert_eq!(solution.block_in[0].get("a"), Some(&ConstValue::Varying));
+    }
+
+    #[test]
+    fn joins_keep_equal_constants() {
+        assert_eq!(ConstValue::Undefined.meet(ConstValue::Const(3)), ConstValue::Const(3));
+        assert_eq!(ConstValue::Const(3).meet(ConstValue::Const(3)), ConstValue::Const(3));
+        assert_eq!(ConstValue::Const(3).meet(ConstValue::Const(4)), ConstValue::Varying);
+    }
+}
@@ -0,0 +1,640 @@
+/*
+***********************************************************************
+  SSA.RS : STATIC SINGLE ASSIGNMENT FORM OF THREE-ADDRESS CODE
+************************************************************************
+*/
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+use crate::cfg::*;
+use crate::constfolding::FoldOperation;
+use crate::expression::OpType;
+use crate::tac::*;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/*
+*************************************************************************************
+  DOMINATORS, BY THE ITERATIVE ALGORITHM OF COOPER, HARVEY AND KENNEDY. idom[b] IS
+  THE IMMEDIATE DOMINATOR OF BLOCK b. THE ENTRY IS ITS OWN IMMEDIATE DOMINATOR, AND
+  BLOCKS THAT CANNOT BE REACHED FROM THE ENTRY HAVE NONE.
+**************************************************************************************
+*/
+pub fn Dominators(cfg: &Cfg) -> Vec<Option<BlockId>> {
+    let rpo = cfg.reverse_postorder();
+    let mut order = vec![usize::MAX; cfg.len()];
+    for (index, &id) in rpo.iter().enumerate() {
+        order[id] = index;
+    }
+
+    let mut idom: Vec<Option<BlockId>> = vec![None; cfg.len()];
+    idom[cfg.entry()] = Some(cfg.entry());
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for &id in rpo.iter().skip(1) {
+            let mut new_idom: Option<BlockId> = None;
+            for &pred in cfg.block(id).preds.iter().filter(|&&pred| idom[pred].is_some()) {
+                new_idom = Some(match new_idom {
+                    None => pred,
+                    Some(other) => Intersect(&idom, &order, pred, other),
+                });
+            }
+            if new_idom != idom[id] {
+                idom[id] = new_idom;
+                changed = true;
+            }
+        }
+    }
+    idom
+}
+
+// The closest common dominator of two blocks, walking up from the one later in reverse postorder
+fn Intersect(idom: &[Option<BlockId>], order: &[usize], mut a: BlockId, mut b: BlockId) -> BlockId {
+    while a != b {
+        while order[a] > order[b] {
+            a = idom[a].expect("a processed block has an immediate dominator");
+        }
+        while order[b] > order[a] {
+            b = idom[b].expect("a processed block has an immediate dominator");
+        }
+    }
+    a
+}
+
+// The dominance frontier of every block: the blocks where its dominance ends
+pub fn DominanceFrontiers(cfg: &Cfg, idom: &[Option<BlockId>]) -> Vec<BTreeSet<BlockId>> {
+    let mut frontiers: Vec<BTreeSet<BlockId>> = vec![BTreeSet::new(); cfg.len()];
+    for block in cfg.blocks().filter(|block| block.preds.len() > 1) {
+        let Some(block_idom) = idom[block.id] else { continue };
+        for &pred in block.preds.iter().filter(|&&pred| idom[pred].is_some()) {
+            let mut runner = pred;
+            while runner != block_idom {
+                frontiers[runner].insert(block.id);
+                match idom[runner] {
+                    Some(up) if up != runner => runner = up,
+                    _ => break,
+                }
+            }
+        }
+    }
+    frontiers
+}
+
+/*
+*************************************************************************************
+  IN SSA FORM EVERY VARIABLE IS ASSIGNED ONCE. EACH ASSIGNMENT OF x DEFINES A NEW
+  VERSION x#1, x#2, ... (NO NAME FROM THE SOURCE OR THE PASSES HAS A #), AND WHERE
+  PATHS WITH DIFFERENT VERSIONS JOIN, A PHI PICKS THE ONE OF THE EDGE TAKEN:
+      x#3 = phi(b1: x#1, b2: x#2)
+  THE ORIGINAL NAME IS THE VALUE ON ENTRY, WHICH ONLY MATTERS FOR PARAMETERS.
+  VARIABLES WHOSE ADDRESS IS TAKEN CAN CHANGE THROUGH A STORE, SO THEY KEEP THEIR
+  NAME. TEMPORARIES ARE ASSIGNED ONCE BY LOWERING AND ARE LEFT ALONE.
+**************************************************************************************
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Phi {
+    // the variable the phi merges, by its original name
+    pub var: String,
+    pub dest: Value,
+    // the version arriving over the edge from each predecessor
+    pub args: Vec<(BlockId, Value)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SsaBlock {
+    pub phis: Vec<Phi>,
+    pub body: Vec<Instr>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SsaFunction {
+    pub name: String,
+    pub is_static: bool,
+    pub params: Vec<String>,
+    pub temps: usize,
+    pub blocks: Vec<SsaBlock>,
+    pub cfg: Cfg,
+    pub idom: Vec<Option<BlockId>>,
+}
+
+fn ValueName(value: &Value) -> Option<&str> {
+    match value {
+        Value::Var(name) => Some(name),
+        _ => None,
+    }
+}
+
+// Variables that may be renamed: assigned somewhere and never address-taken
+fn RenamedVariables(func: &TacFunction) -> BTreeSet<String> {
+    let mut escaped: BTreeSet<&str> = BTreeSet::new();
+    let mut assigned: BTreeSet<String> = func.params.iter().cloned().collect();
+    for instr in func.body.iter() {
+        if let Instr::Unary { op: OpType::ADDRESSOF, src: Value::Var(name), .. } = instr {
+            escaped.insert(name);
+        }
+        if let Some(Value::Var(name)) = instr.dest() {
+            assigned.insert(name.clone());
+        }
+    }
+    assigned.retain(|name| !escaped.contains(name.as_str()));
+    assigned
+}
+
+struct Renamer<'a> {
+    renamed: &'a BTreeSet<String>,
+    // the versions in scope for each variable, innermost last
+    stacks: BTreeMap<String, Vec<Value>>,
+    counters: BTreeMap<String, usize>,
+}
+
+impl Renamer<'_> {
+    fn fresh(&mut self, var: &str) -> Value {
+        let counter = self.counters.entry(var.to_string()).or_insert(0);
+        *counter += 1;
+        let version = Value::Var(format!("{}#{}", var, counter));
+        self.stacks.entry(var.to_string()).or_default().push(version.clone());
+        version
+    }
+
+    fn current(&self, var: &str) -> Value {
+        self.stacks
+            .get(var)
+            .and_then(|stack| stack.last())
+            .cloned()
+            .unwrap_or(Value::Var(var.to_string()))
+    }
+
+    // Rename the uses and definitions of a block, fill in the phis of its successors,
+    // then go on with the blocks it immediately dominates
+    fn walk(&mut self, id: BlockId, blocks: &mut [SsaBlock], cfg: &Cfg, children: &[Vec<BlockId>]) {
+        let mut defined: Vec<String> = Vec::new();
+
+        for phi in blocks[id].phis.iter_mut() {
+            phi.dest = self.fresh(&phi.var);
+            defined.push(phi.var.clone());
+        }
+        for instr in blocks[id].body.iter_mut() {
+            for value in instr.uses_mut() {
+                if let Value::Var(name) = value {
+                    if self.renamed.contains(name.as_str()) {
+                        *value = self.current(name);
+                    }
+                }
+            }
+            if let Some(value) = instr.dest_mut() {
+                if let Value::Var(name) = value {
+                    if self.renamed.contains(name.as_str()) {
+                        let var = name.clone();
+                        *value = self.fresh(&var);
+                        defined.push(var);
+                    }
+                }
+            }
+        }
+        for &succ in cfg.block(id).succs.iter() {
+            for phi in blocks[succ].phis.iter_mut() {
+                let version = self.current(&phi.var);
+                phi.args.push((id, version));
+            }
+        }
+        for &child in children[id].iter() {
+            self.walk(child, blocks, cfg, children);
+        }
+
+        for var in defined {
+            if let Some(stack) = self.stacks.get_mut(&var) {
+                stack.pop();
+            }
+        }
+    }
+}
+
+/*
+*************************************************************************************
+  CONSTRUCTION OF SSA FORM (CYTRON ET AL.), SEMI-PRUNED: ONLY VARIABLES READ IN SOME
+  BLOCK BEFORE BEING ASSIGNED THERE CAN NEED A PHI. NOTHING IS LIVE AT THE EXIT, SO
+  IT NEVER GETS ONE. cfg MUST DESCRIBE THE POSITIONS OF func.body.
+**************************************************************************************
+*/
+pub fn ToSsa(func: &TacFunction, cfg: Cfg) -> SsaFunction {
+    let renamed = RenamedVariables(func);
+    let mut blocks: Vec<SsaBlock> = cfg
+        .blocks()
+        .map(|block| SsaBlock { phis: Vec::new(), body: func.body[block.positions()].to_vec() })
+        .collect();
+    let idom = Dominators(&cfg);
+    let frontiers = DominanceFrontiers(&cfg, &idom);
+
+    // the blocks assigning each variable, and the variables read before being assigned in a block
+    let mut def_blocks: BTreeMap<&str, BTreeSet<BlockId>> = BTreeMap::new();
+    let mut non_local: BTreeSet<&str> = BTreeSet::new();
+    for param in func.params.iter().filter(|param| renamed.contains(*param)) {
+        def_blocks.entry(param).or_default().insert(cfg.entry());
+    }
+    for (id, block) in blocks.iter().enumerate() {
+        let mut killed: BTreeSet<&str> = BTreeSet::new();
+        for instr in block.body.iter() {
+            for value in instr.uses() {
+                // names are borrowed from renamed, as the blocks get phis below
+                if let Some(name) = ValueName(value).and_then(|name| renamed.get(name)) {
+                    if !killed.contains(name.as_str()) {
+                        non_local.insert(name);
+                    }
+                }
+            }
+            if let Some(name) = instr.dest().and_then(ValueName).and_then(|name| renamed.get(name)) {
+                killed.insert(name);
+                def_blocks.entry(name).or_default().insert(id);
+            }
+        }
+    }
+
+    for var in non_local {
+        let Some(defs) = def_blocks.get(var) else { continue };
+        let mut has_phi: BTreeSet<BlockId> = BTreeSet::new();
+        let mut pending: Vec<BlockId> = defs.iter().cloned().collect();
+        while let Some(id) = pending.pop() {
+            for &frontier in frontiers[id].iter() {
+                if frontier == cfg.exit() || !has_phi.insert(frontier) {
+                    continue;
+                }
+                blocks[frontier].phis.push(Phi { var: var.to_string(), dest: Value::Var(var.to_string()), args: Vec::new() });
+                if !defs.contains(&frontier) {
+                    pending.push(frontier);
+                }
+            }
+        }
+    }
+
+    let mut children: Vec<Vec<BlockId>> = vec![Vec::new(); cfg.len()];
+    for (id, parent) in idom.iter().enumerate() {
+        if let Some(parent) = *parent {
+            if parent != id {
+                children[parent].push(id);
+            }
+        }
+    }
+    let mut renamer = Renamer { renamed: &renamed, stacks: BTreeMap::new(), counters: BTreeMap::new() };
+    renamer.walk(cfg.entry(), &mut blocks, &cfg, &children);
+
+    SsaFunction {
+        name: func.name.clone(),
+        is_static: func.is_static,
+        params: func.params.clone(),
+        temps: func.temps,
+        blocks,
+        cfg,
+        idom,
+    }
+}
+
+/*
+*************************************************************************************
+  OUT OF SSA: EACH PHI BECOMES A COPY AT THE END OF EVERY PREDECESSOR. THE COPIES
+  INTO ONE BLOCK HAPPEN AT ONCE, SO WHEN ONE OF THEM READS WHAT ANOTHER WRITES THEY
+  GO THROUGH NEW TEMPORARIES. A PREDECESSOR WITH SEVERAL SUCCESSORS WOULD NEED ITS
+  EDGE SPLIT FIRST; GRAPHS BUILT FROM THREE-ADDRESS CODE HAVE NONE.
+**************************************************************************************
+*/
+pub fn OutOfSsa(ssa: &mut SsaFunction) {
+    for id in 0..ssa.blocks.len() {
+        let phis = std::mem::take(&mut ssa.blocks[id].phis);
+        if phis.is_empty() {
+            continue;
+        }
+        for &pred in ssa.cfg.block(id).preds.iter() {
+            debug_assert!(ssa.cfg.block(pred).succs.len() == 1, "critical edge into a phi");
+            let copies: Vec<(Value, Value)> = phis
+                .iter()
+                .filter_map(|phi| phi.args.iter().find(|(from, _)| *from == pred).map(|(_, arg)| (phi.dest.clone(), arg.clone())))
+                .collect();
+            let overlap = copies.iter().any(|(_, src)| copies.iter().any(|(dest, _)| dest == src));
+
+            let body = &mut ssa.blocks[pred].body;
+            if overlap {
+                let mut staged: Vec<(Value, Value)> = Vec::new();
+                for (dest, src) in copies {
+                    ssa.temps += 1;
+                    body.push(Instr::Copy { dest: Value::Temp(ssa.temps), src });
+                    staged.push((dest, Value::Temp(ssa.temps)));
+                }
+                body.extend(staged.into_iter().map(|(dest, src)| Instr::Copy { dest, src }));
+            } else {
+                body.extend(copies.into_iter().map(|(dest, src)| Instr::Copy { dest, src }));
+            }
+        }
+    }
+}
+
+/*
+*************************************************************************************
+  OPTIMIZATION ON SSA NAMES. A VERSION x#N OR A TEMPORARY HAS ONE DEFINITION, SO ITS
+  VALUE IS KNOWN EVERYWHERE AS SOON AS THE DEFINITION FOLDS, AND IT IS DEAD AS SOON
+  AS NOTHING READS IT. A NAME WITHOUT A VERSION IS A PARAMETER ON ENTRY OR A VARIABLE
+  WHOSE ADDRESS IS TAKEN, WHICH A STORE OR A CALL MAY CHANGE: IT IS LEFT ALONE.
+**************************************************************************************
+*/
+fn IsSsaValue(value: &Value) -> bool {
+    match value {
+        Value::Temp(_) => true,
+        Value::Var(name) => name.contains('#'),
+        Value::Const(_) => false,
+    }
+}
+
+fn Known(value: &Value, consts: &BTreeMap<String, i64>) -> Option<i64> {
+    match value {
+        Value::Const(val) => Some(*val),
+        _ => consts.get(&value.to_string()).copied(),
+    }
+}
+
+// The constant an instruction computes, when its operands are known. A division
+// that traps is left to runtime.
+fn FoldInstr(instr: &Instr, consts: &BTreeMap<String, i64>) -> Option<i64> {
+    match instr {
+        Instr::Copy { src, .. } => Known(src, consts),
+        Instr::Binary { op, left, right, .. } => FoldOperation(*op, Known(left, consts)?, Some(Known(right, consts)?)),
+        Instr::Unary { op: op @ (OpType::NEGATE | OpType::BNOT), src, .. } => FoldOperation(*op, Known(src, consts)?, None),
+        _ => None,
+    }
+}
+
+pub fn SsaConstProp(ssa: &mut SsaFunction) -> bool {
+    let mut consts: BTreeMap<String, i64> = BTreeMap::new();
+    // values only ever become known, so this settles
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for block in ssa.blocks.iter() {
+            for phi in block.phis.iter() {
+                if consts.contains_key(&phi.dest.to_string()) {
+                    continue;
+                }
+                // the same constant must arrive over every edge
+                let args: BTreeSet<Option<i64>> = phi.args.iter().map(|(_, arg)| Known(arg, &consts)).collect();
+                if let [Some(val)] = args.into_iter().collect::<Vec<Option<i64>>>()[..] {
+                    consts.insert(phi.dest.to_string(), val);
+                    changed = true;
+                }
+            }
+            for instr in block.body.iter() {
+                let Some(dest) = instr.dest().filter(|dest| IsSsaValue(dest)) else { continue };
+                if consts.contains_key(&dest.to_string()) {
+                    continue;
+                }
+                if let Some(val) = FoldInstr(instr, &consts) {
+                    consts.insert(dest.to_string(), val);
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    let mut made_change = false;
+    for block in ssa.blocks.iter_mut() {
+        for phi in block.phis.iter_mut() {
+            for (_, arg) in phi.args.iter_mut() {
+                if let Some(val) = consts.get(&arg.to_string()) {
+                    *arg = Value::Const(*val);
+                    made_change = true;
+                }
+            }
+        }
+        for instr in block.body.iter_mut() {
+            if let Some(val) = FoldInstr(instr, &consts) {
+                let dest = instr.dest().cloned().expect("a folded instruction has a destination");
+                if *instr != (Instr::Copy { dest: dest.clone(), src: Value::Const(val) }) {
+                    *instr = Instr::Copy { dest, src: Value::Const(val) };
+                    made_change = true;
+                }
+                continue;
+            }
+            // an address and the call a struct's second field comes from are not values
+            if matches!(instr, Instr::ResultHigh { .. } | Instr::Unary { op: OpType::ADDRESSOF, .. }) {
+                continue;
+            }
+            for value in instr.uses_mut() {
+                if let Some(val) = consts.get(&value.to_string()) {
+                    *value = Value::Const(*val);
+                    made_change = true;
+                }
+            }
+        }
+    }
+    made_change
+}
+
+// Whether an instruction must stay even when nobody reads what it writes
+fn HasEffect(instr: &Instr) -> bool {
+    match instr {
+        Instr::Assert { cond: Value::Const(val), .. } => *val == 0,
+        Instr::Call { .. } | Instr::Store { .. } | Instr::Assert { .. } | Instr::Ret { .. } => true,
+        _ => instr.dest().is_some_and(|dest| !IsSsaValue(dest)),
+    }
+}
+
+pub fn SsaDeadCode(ssa: &mut SsaFunction) -> bool {
+    let mut made_change = false;
+    loop {
+        let mut used: BTreeSet<String> = BTreeSet::new();
+        for block in ssa.blocks.iter() {
+            for phi in block.phis.iter() {
+                used.extend(phi.args.iter().map(|(_, arg)| arg.to_string()));
+            }
+            for instr in block.body.iter() {
+                used.extend(instr.uses().into_iter().map(|value| value.to_string()));
+            }
+        }
+        let is_used = |value: &Value| !IsSsaValue(value) || used.contains(&value.to_string());
+
+        let mut removed = false;
+        for block in ssa.blocks.iter_mut() {
+            let count = block.phis.len() + block.body.len();
+            block.phis.retain(|phi| is_used(&phi.dest));
+            block.body.retain(|instr| HasEffect(instr) || instr.dest().is_some_and(is_used));
+            removed |= block.phis.len() + block.body.len() != count;
+            // a call whose result is never read is still made
+            for instr in block.body.iter_mut() {
+                if let Instr::Call { dest, .. } = instr {
+                    if dest.as_ref().is_some_and(|dest| !is_used(dest)) {
+                        *dest = None;
+                        removed = true;
+                    }
+                }
+            }
+        }
+        if !removed {
+            return made_change;
+        }
+        made_change = true;
+    }
+}
+
+impl SsaFunction {
+    // The blocks laid out one after the other. This is only faithful to a graph built
+    // by Cfg::build_tac, where control falls through in layout order.
+    pub fn into_tac(self) -> TacFunction {
+        debug_assert!(self.blocks.iter().all(|block| block.phis.is_empty()), "phis left in the function");
+        TacFunction {
+            name: self.name,
+            is_static: self.is_static,
+            params: self.params,
+            body: self.blocks.into_iter().flat_map(|block| block.body).collect(),
+            temps: self.temps,
+        }
+    }
+}
+
+impl fmt::Display for Phi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let args: Vec<String> = self.args.iter().map(|(from, value)| format!("b{}: {}", from, value)).collect();
+        write!(f, "{} = phi({})", self.dest, args.join(", "))
+    }
+}
+
+impl fmt::Display for SsaFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_static {
+            write!(f, "static ")?;
+        }
+        let params: Vec<String> = self.params.iter().map(|name| Value::Var(name.clone()).to_string()).collect();
+        writeln!(f, "func {}({}) {{", self.name, params.join(", "))?;
+        for (id, block) in self.blocks.iter().enumerate() {
+            writeln!(f, "b{}:", id)?;
+            for phi in block.phis.iter() {
+                writeln!(f, "\t{}", phi)?;
+            }
+            for instr in block.body.iter() {
+                writeln!(f, "\t{}", instr)?;
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tac(text: &str) -> TacFunction {
+        ParseTac(text).unwrap().functions.remove(0)
+    }
+
+    #[test]
+    fn diamond_dominators_and_frontiers() {
+        // 0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3, 3 -> 4 (exit)
+        let cfg = Cfg::from_edges(&[0, 0, 0, 0, 0], &[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)]);
+
+        let idom = Dominators(&cfg);
+        let frontiers = DominanceFrontiers(&cfg, &idom);
+
+        assert_eq!(idom, vec![Some(0), Some(0), Some(0), Some(0), Some(3)]);
+        assert_eq!(frontiers[1], BTreeSet::from([3]));
+        assert_eq!(frontiers[2], BTreeSet::from([3]));
+        assert!(frontiers[0].is_empty() && frontiers[3].is_empty());
+    }
+
+    #[test]
+    fn redefinitions_get_versions() {
+        let func = tac("func foo(a) {\n\
+                        \tx = a + 1\n\
+                        \tx = x * a\n\
+                        \tt1 = addr y\n\
+                        \ty = x\n\
+                        \tassert y @ 3\n\
+                        \tret x\n\
+                        }\n");
+
+        let ssa = ToSsa(&func, Cfg::build_tac(&func));
+
+        let body: Vec<String> = ssa.blocks.iter().flat_map(|block| block.body.iter()).map(|i| i.to_string()).collect();
+        assert_eq!(body, vec!["x#1 = a + 1", "x#2 = x#1 * a", "t1 = addr y", "y = x#2", "assert y @ 3", "ret x#2"]);
+        assert!(ssa.blocks.iter().all(|block| block.phis.is_empty()));
+    }
+
+    #[test]
+    fn versions_never_clash_with_renamed_names() {
+        // x.1 is how a static variable or an inlined local can be named
+        let func = tac("func foo(a) {\n\
+                        \tx = a\n\
+                        \tx.1 = x + 1\n\
+                        \tx = x.1\n\
+                        \tret x\n\
+                        }\n");
+
+        let mut ssa = ToSsa(&func, Cfg::build_tac(&func));
+        OutOfSsa(&mut ssa);
+        let text = ssa.into_tac().to_string();
+
+        assert_eq!(text, "func foo(a) {\n\tx#1 = a\n\tx.1#1 = x#1 + 1\n\tx#2 = x.1#1\n\tret x#2\n}\n");
+        assert_eq!(tac(&text).to_string(), text);
+    }
+
+    #[test]
+    fn joins_get_phis_that_become_copies() {
+        // b0 branches to b1 and b2, which both assign x before joining at b3
+        let func = tac("func foo(a) {\n\
+                        \tx = a\n\
+                        \tx = 1\n\
+                        \tx = 2\n\
+                        \tret x\n\
+                        }\n");
+        let cfg = Cfg::from_edges(&[1, 1, 1, 1, 0], &[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)]);
+
+        let mut ssa = ToSsa(&func, cfg);
+
+        assert_eq!(ssa.blocks[3].phis.len(), 1);
+        assert_eq!(ssa.blocks[3].phis[0].to_string(), "x#4 = phi(b1: x#2, b2: x#3)");
+        assert_eq!(ssa.blocks[3].body[0].to_string(), "ret x#4");
+
+        OutOfSsa(&mut ssa);
+
+        assert!(ssa.blocks[3].phis.is_empty());
+        assert_eq!(ssa.blocks[1].body.last().unwrap().to_string(), "x#4 = x#2");
+        assert_eq!(ssa.blocks[2].body.last().unwrap().to_string(), "x#4 = x#3");
+    }
+
+    #[test]
+    fn constants_fold_and_dead_versions_go() {
+        let func = tac("func foo(a) {\n\
+                        \tx = 2\n\
+                        \tt1 = x * 3\n\
+                        \tx = t1 + a\n\
+                        \ty = x\n\
+                        \tt2 = addr z\n\
+                        \tz = 4\n\
+                        \tu = call bar(z)\n\
+                        \tassert 1 @ 9\n\
+                        \tret x\n\
+                        }\n");
+
+        let mut ssa = ToSsa(&func, Cfg::build_tac(&func));
+        assert!(SsaConstProp(&mut ssa));
+        assert!(SsaDeadCode(&mut ssa));
+        assert!(!SsaConstProp(&mut ssa) && !SsaDeadCode(&mut ssa));
+        OutOfSsa(&mut ssa);
+
+        // z keeps its name and its store, as bar may read it through the pointer
+        assert_eq!(
+            ssa.into_tac().to_string(),
+            "func foo(a) {\n\tx#2 = 6 + a\n\tz = 4\n\tcall bar(z)\n\tret x#2\n}\n"
+        );
+    }
+
+    #[test]
+    fn struct_calls_stay_tied_to_their_field() {
+        let func = tac("func foo(a) {\n\
+                        \tr.x = call mk(a)\n\
+                        \tr.y = high(r.x)\n\
+                        \tret r.y\n\
+                        }\n");
+
+        let mut ssa = ToSsa(&func, Cfg::build_tac(&func));
+        while SsaConstProp(&mut ssa) || SsaDeadCode(&mut ssa) {}
+
+        let body: Vec<String> = ssa.blocks.iter().flat_map(|block| block.body.iter()).map(|i| i.to_string()).collect();
+        assert_eq!(body, vec!["r.x#1 = call mk(a)", "r.y#1 = high(r.x#1)", "ret r.y#1"]);
+    }
+}
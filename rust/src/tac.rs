@@ -0,0 +1,545 @@
+/*
+***********************************************************************
+  TAC.RS : THREE-ADDRESS CODE LOWERED FROM THE SYNTAX TREE
+************************************************************************
+*/
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+use crate::expression::*;
+use std::fmt;
+
+/*
+*************************************************************************************
+  EVERY INSTRUCTION READS AT MOST TWO VALUES AND WRITES AT MOST ONE. A VALUE IS A
+  CONSTANT, A VARIABLE OF THE SOURCE (PARAMETERS AND STRUCT FIELDS INCLUDED) OR A
+  TEMPORARY NUMBERED FROM 1 IN EACH FUNCTION, HOLDING AN INTERMEDIATE RESULT.
+**************************************************************************************
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Const(i64),
+    Var(String),
+    Temp(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instr {
+    // x = y
+    Copy { dest: Value, src: Value },
+    // x = y + z, and every other binary operator
+    Binary { dest: Value, op: OpType, left: Value, right: Value },
+    // x = neg y, x = not y, x = load p, x = addr y
+    Unary { dest: Value, op: OpType, src: Value },
+    // x = call f(y, z), or only call f(y, z) when the result is unused
+    Call { dest: Option<Value>, func: String, args: Vec<Value> },
+    // x = high(r.x): the second field of the struct returned by the call assigned to r.x,
+    // or x = high(result) for the call just before
+    ResultHigh { dest: Value, low: Option<Value> },
+    // *p = x
+    Store { addr: Value, src: Value },
+    // assert x @ line
+    Assert { cond: Value, line: i64 },
+    // ret x, or ret x, y for a struct of two fields
+    Ret { value: Value, high: Option<Value> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TacFunction {
+    pub name: String,
+    pub is_static: bool,
+    pub params: Vec<String>,
+    pub body: Vec<Instr>,
+    // number of temporaries used by the body
+    pub temps: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TacProgram {
+    pub functions: Vec<TacFunction>,
+}
+
+impl Instr {
+    // The value the instruction writes, if any
+    pub fn dest(&self) -> Option<&Value> {
+        match self {
+            Instr::Copy { dest, .. }
+            | Instr::Binary { dest, .. }
+            | Instr::Unary { dest, .. }
+            | Instr::ResultHigh { dest, .. } => Some(dest),
+            Instr::Call { dest, .. } => dest.as_ref(),
+            Instr::Store { .. } | Instr::Assert { .. } | Instr::Ret { .. } => None,
+        }
+    }
+
+    pub fn dest_mut(&mut self) -> Option<&mut Value> {
+        match self {
+            Instr::Copy { dest, .. }
+            | Instr::Binary { dest, .. }
+            | Instr::Unary { dest, .. }
+            | Instr::ResultHigh { dest, .. } => Some(dest),
+            Instr::Call { dest, .. } => dest.as_mut(),
+            Instr::Store { .. } | Instr::Assert { .. } | Instr::Ret { .. } => None,
+        }
+    }
+
+    // The values the instruction reads, in order. The operand of addr names a
+    // location rather than reading it, and the one of high the call it comes
+    // from, but both are listed too.
+    pub fn uses(&self) -> Vec<&Value> {
+        match self {
+            Instr::Copy { src, .. } | Instr::Unary { src, .. } => vec![src],
+            Instr::Binary { left, right, .. } => vec![left, right],
+            Instr::Call { args, .. } => args.iter().collect(),
+            Instr::ResultHigh { low, .. } => low.iter().collect(),
+            Instr::Store { addr, src } => vec![addr, src],
+            Instr::Assert { cond, .. } => vec![cond],
+            Instr::Ret { value, high } => std::iter::once(value).chain(high.iter()).collect(),
+        }
+    }
+
+    pub fn uses_mut(&mut self) -> Vec<&mut Value> {
+        match self {
+            Instr::Copy { src, .. } | Instr::Unary { src, .. } => vec![src],
+            Instr::Binary { left, right, .. } => vec![left, right],
+            Instr::Call { args, .. } => args.iter_mut().collect(),
+            Instr::ResultHigh { low, .. } => low.iter_mut().collect(),
+            Instr::Store { addr, src } => vec![addr, src],
+            Instr::Assert { cond, .. } => vec![cond],
+            Instr::Ret { value, high } => std::iter::once(value).chain(high.iter_mut()).collect(),
+        }
+    }
+}
+
+fn BinarySymbol(op: OpType) -> Option<&'static str> {
+    match op {
+        OpType::MULTIPLY => Some("*"),
+        OpType::DIVIDE => Some("/"),
+        OpType::ADD => Some("+"),
+        OpType::SUBTRACT => Some("-"),
+        OpType::BOR => Some("|"),
+        OpType::BAND => Some("&"),
+        OpType::BXOR => Some("^"),
+        OpType::BSHR => Some(">>"),
+        OpType::BSHL => Some("<<"),
+        OpType::BLSHR => Some(">>>"),
+        _ => None,
+    }
+}
+
+fn UnaryWord(op: OpType) -> Option<&'static str> {
+    match op {
+        OpType::NEGATE => Some("neg"),
+        OpType::BNOT => Some("not"),
+        OpType::DEREF => Some("load"),
+        OpType::ADDRESSOF => Some("addr"),
+        _ => None,
+    }
+}
+
+const BINARY_OPS: [OpType; 10] = [
+    OpType::MULTIPLY,
+    OpType::DIVIDE,
+    OpType::ADD,
+    OpType::SUBTRACT,
+    OpType::BOR,
+    OpType::BAND,
+    OpType::BXOR,
+    OpType::BSHR,
+    OpType::BSHL,
+    OpType::BLSHR,
+];
+const UNARY_OPS: [OpType; 4] = [OpType::NEGATE, OpType::BNOT, OpType::DEREF, OpType::ADDRESSOF];
+
+/*
+*************************************************************************************
+  LOWERING: EACH OPERATION OF A NESTED EXPRESSION GETS ITS OWN INSTRUCTION, OPERANDS
+  FIRST. THE OUTERMOST OPERATION OF AN ASSIGNMENT WRITES THE VARIABLE DIRECTLY.
+**************************************************************************************
+*/
+struct Lowering {
+    body: Vec<Instr>,
+    temps: usize,
+}
+
+impl Lowering {
+    fn new_temp(&mut self) -> Value {
+        self.temps += 1;
+        Value::Temp(self.temps)
+    }
+
+    // The value of an expression, computed into a new temporary when it is not a leaf
+    fn value(&mut self, expr: &RNode) -> Value {
+        match expr.exprCode {
+            ExprType::CONSTANT => Value::Const(expr.value),
+            ExprType::VARIABLE | ExprType::PARAMETER => Value::Var(expr.name.clone()),
+            _ => {
+                let dest = self.new_temp();
+                self.compute(expr, dest.clone());
+                dest
+            }
+        }
+    }
+
+    // Emit the instructions that store the value of expr in dest
+    fn compute(&mut self, expr: &RNode, dest: Value) {
+        if expr.exprCode != ExprType::OPERATION {
+            let src = self.value(expr);
+            self.body.push(Instr::Copy { dest, src });
+            return;
+        }
+        match expr.opCode {
+            OpType::FUNCTIONCALL => {
+                let args: Vec<Value> = expr.arguments.iter().flat_map(|list| list.iter()).map(|arg| self.value(arg)).collect();
+                let func = expr.left.as_ref().map_or(String::new(), |callee| callee.name.clone());
+                self.body.push(Instr::Call { dest: Some(dest), func, args });
+            }
+            OpType::RESULTHIGH => {
+                let low = expr.left.as_ref().map(|var| Value::Var(var.name.clone()));
+                self.body.push(Instr::ResultHigh { dest, low });
+            }
+            // the operand is a memory location, not a value to compute
+            OpType::ADDRESSOF => {
+                let name = expr.left.as_ref().map_or(String::new(), |var| var.name.clone());
+                self.body.push(Instr::Unary { dest, op: OpType::ADDRESSOF, src: Value::Var(name) });
+            }
+            op => {
+                if let (Some(left), Some(right)) = (expr.left.as_ref(), expr.right.as_ref()) {
+                    let left = self.value(left);
+                    let right = self.value(right);
+                    self.body.push(Instr::Binary { dest, op, left, right });
+                } else if let Some(operand) = expr.left.as_ref() {
+                    let src = self.value(operand);
+                    self.body.push(Instr::Unary { dest, op, src });
+                }
+            }
+        }
+    }
+
+    fn statement(&mut self, stmt: &RNode) {
+        match stmt.stmtCode {
+            StmtType::ASSIGN | StmtType::CONSTDECL => {
+                if let Some(right) = stmt.right.as_ref() {
+                    self.compute(right, Value::Var(stmt.name.clone()));
+                }
+            }
+            StmtType::RETURN => {
+                if let Some(left) = stmt.left.as_ref() {
+                    let value = self.value(left);
+                    let high = stmt.right.as_ref().map(|right| self.value(right));
+                    self.body.push(Instr::Ret { value, high });
+                }
+            }
+            StmtType::ASSERT => {
+                if let Some(cond) = stmt.left.as_ref() {
+                    let cond = self.value(cond);
+                    self.body.push(Instr::Assert { cond, line: stmt.value });
+                }
+            }
+            StmtType::STORE => {
+                if let (Some(addr), Some(src)) = (stmt.left.as_ref(), stmt.right.as_ref()) {
+                    let src = self.value(src);
+                    let addr = self.value(addr);
+                    self.body.push(Instr::Store { addr, src });
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn LowerFunction(func: &RNode) -> TacFunction {
+    let mut lowering = Lowering { body: Vec::new(), temps: 0 };
+    for stmt in func.statements.iter().flat_map(|list| list.iter()) {
+        lowering.statement(stmt);
+    }
+    TacFunction {
+        name: func.name.clone(),
+        is_static: func.is_static(),
+        params: func.arguments.iter().flat_map(|list| list.iter()).map(|param| param.name.clone()).collect(),
+        body: lowering.body,
+        temps: lowering.temps,
+    }
+}
+
+// Prototypes have no code, so only function definitions are lowered
+pub fn Lower(worklist: &RList) -> TacProgram {
+    TacProgram {
+        functions: worklist
+            .iter()
+            .filter(|node| node.type_ == NodeType::FUNCTIONDECL && !node.is_prototype())
+            .map(LowerFunction)
+            .collect(),
+    }
+}
+
+/*
+*************************************************************************************
+  TEXTUAL FORM, ONE INSTRUCTION PER LINE:
+      func foo(a, b) {
+        t1 = a + b
+        x = t1 * 2
+        ret x
+      }
+  A SOURCE VARIABLE NAMED LIKE A TEMPORARY (t1) IS WRITTEN t1_ SINCE SOURCE NAMES
+  NEVER CONTAIN AN UNDERSCORE.
+**************************************************************************************
+*/
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Const(value) => write!(f, "{}", value),
+            Value::Temp(number) => write!(f, "t{}", number),
+            Value::Var(name) if IsTempName(name) => write!(f, "{}_", name),
+            Value::Var(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+fn IsTempName(word: &str) -> bool {
+    word.len() > 1 && word.starts_with('t') && word[1..].chars().all(|c| c.is_ascii_digit())
+}
+
+fn JoinValues(values: &[Value]) -> String {
+    values.iter().map(|value| value.to_string()).collect::<Vec<String>>().join(", ")
+}
+
+impl fmt::Display for Instr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instr::Copy { dest, src } => write!(f, "{} = {}", dest, src),
+            Instr::Binary { dest, op, left, right } => {
+                write!(f, "{} = {} {} {}", dest, left, BinarySymbol(*op).unwrap_or("?"), right)
+            }
+            Instr::Unary { dest, op, src } => write!(f, "{} = {} {}", dest, UnaryWord(*op).unwrap_or("?"), src),
+            Instr::Call { dest: Some(dest), func, args } => {
+                write!(f, "{} = call {}({})", dest, func, JoinValues(args))
+            }
+            Instr::Call { dest: None, func, args } => write!(f, "call {}({})", func, JoinValues(args)),
+            Instr::ResultHigh { dest, low: Some(low) } => write!(f, "{} = high({})", dest, low),
+            Instr::ResultHigh { dest, low: None } => write!(f, "{} = high(result)", dest),
+            Instr::Store { addr, src } => write!(f, "*{} = {}", addr, src),
+            Instr::Assert { cond, line } => write!(f, "assert {} @ {}", cond, line),
+            Instr::Ret { value, high: Some(high) } => write!(f, "ret {}, {}", value, high),
+            Instr::Ret { value, high: None } => write!(f, "ret {}", value),
+        }
+    }
+}
+
+impl fmt::Display for TacFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_static {
+            write!(f, "static ")?;
+        }
+        let params: Vec<Value> = self.params.iter().map(|name| Value::Var(name.clone())).collect();
+        writeln!(f, "func {}({}) {{", self.name, JoinValues(&params))?;
+        for instr in self.body.iter() {
+            writeln!(f, "\t{}", instr)?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+impl fmt::Display for TacProgram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for func in self.functions.iter() {
+            write!(f, "{}", func)?;
+        }
+        Ok(())
+    }
+}
+
+/*
+*************************************************************************************
+  PARSER FOR THE TEXTUAL FORM. ERRORS NAME THE LINE THEY WERE FOUND ON.
+**************************************************************************************
+*/
+fn ParseValue(word: &str, temps: &mut usize) -> Result<Value, String> {
+    if let Ok(value) = word.parse::<i64>() {
+        return Ok(Value::Const(value));
+    }
+    if IsTempName(word) {
+        let number: usize = word[1..].parse().map_err(|_| format!("bad temporary '{}'", word))?;
+        *temps = (*temps).max(number);
+        return Ok(Value::Temp(number));
+    }
+    let name = word.strip_suffix('_').unwrap_or(word);
+    let valid = name.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '#');
+    if !valid {
+        return Err(format!("'{}' is not a value", word));
+    }
+    Ok(Value::Var(name.to_string()))
+}
+
+// "f(a, b)" into the function name and its arguments
+fn ParseCall(text: &str, temps: &mut usize) -> Result<(String, Vec<Value>), String> {
+    let (func, rest) = text.split_once('(').ok_or(format!("missing '(' in '{}'", text))?;
+    let args = rest.strip_suffix(')').ok_or(format!("missing ')' in '{}'", text))?;
+    let mut values: Vec<Value> = Vec::new();
+    for arg in args.split(',').map(str::trim).filter(|arg| !arg.is_empty()) {
+        values.push(ParseValue(arg, temps)?);
+    }
+    Ok((func.trim().to_string(), values))
+}
+
+fn ParseInstr(line: &str, temps: &mut usize) -> Result<Instr, String> {
+    if let Some(call) = line.strip_prefix("call ") {
+        let (func, args) = ParseCall(call, temps)?;
+        return Ok(Instr::Call { dest: None, func, args });
+    }
+    if let Some(rest) = line.strip_prefix("ret ") {
+        return match rest.split_once(',') {
+            Some((value, high)) => Ok(Instr::Ret {
+                value: ParseValue(value.trim(), temps)?,
+                high: Some(ParseValue(high.trim(), temps)?),
+            }),
+            None => Ok(Instr::Ret { value: ParseValue(rest.trim(), temps)?, high: None }),
+        };
+    }
+    if let Some(rest) = line.strip_prefix("assert ") {
+        let (cond, at) = rest.split_once('@').ok_or("missing '@ line' in assert")?;
+        let line = at.trim().parse::<i64>().map_err(|_| format!("bad line number '{}'", at.trim()))?;
+        return Ok(Instr::Assert { cond: ParseValue(cond.trim(), temps)?, line });
+    }
+    if let Some(rest) = line.strip_prefix('*') {
+        let (addr, src) = rest.split_once('=').ok_or("missing '=' in store")?;
+        return Ok(Instr::Store { addr: ParseValue(addr.trim(), temps)?, src: ParseValue(src.trim(), temps)? });
+    }
+
+    let (dest, rhs) = line.split_once('=').ok_or(format!("unknown instruction '{}'", line))?;
+    let dest = ParseValue(dest.trim(), temps)?;
+    let rhs = rhs.trim();
+    if let Some(low) = rhs.strip_prefix("high(").and_then(|rest| rest.strip_suffix(')')) {
+        // a field variable always has a dot, so result is never one
+        let low = if low == "result" { None } else { Some(ParseValue(low.trim(), temps)?) };
+        return Ok(Instr::ResultHigh { dest, low });
+    }
+    if let Some(call) = rhs.strip_prefix("call ") {
+        let (func, args) = ParseCall(call, temps)?;
+        return Ok(Instr::Call { dest: Some(dest), func, args });
+    }
+    let words: Vec<&str> = rhs.split_whitespace().collect();
+    match words.as_slice() {
+        [src] => Ok(Instr::Copy { dest, src: ParseValue(src, temps)? }),
+        [word, src] => {
+            let op = UNARY_OPS.iter().find(|op| UnaryWord(**op) == Some(*word)).ok_or(format!("unknown operation '{}'", word))?;
+            Ok(Instr::Unary { dest, op: *op, src: ParseValue(src, temps)? })
+        }
+        [left, symbol, right] => {
+            let op = BINARY_OPS.iter().find(|op| BinarySymbol(**op) == Some(*symbol)).ok_or(format!("unknown operator '{}'", symbol))?;
+            Ok(Instr::Binary { dest, op: *op, left: ParseValue(left, temps)?, right: ParseValue(right, temps)? })
+        }
+        _ => Err(format!("unknown instruction '{}'", line)),
+    }
+}
+
+pub fn ParseTac(text: &str) -> Result<TacProgram, String> {
+    let mut program = TacProgram::default();
+    let mut current: Option<TacFunction> = None;
+
+    for (index, raw) in text.lines().enumerate() {
+        let line = raw.trim();
+        let error = |msg: String| format!("line {}: {}", index + 1, msg);
+        if line.is_empty() {
+            continue;
+        }
+
+        if current.is_none() {
+            let (is_static, head) = match line.strip_prefix("static ") {
+                Some(head) => (true, head),
+                None => (false, line),
+            };
+            let head = head.strip_prefix("func ").ok_or(error(format!("expected a function, found '{}'", line)))?;
+            let head = head.strip_suffix('{').ok_or(error("missing '{' after the function head".to_string()))?;
+            let mut temps: usize = 0;
+            let (name, params) = ParseCall(head.trim(), &mut temps).map_err(error)?;
+            let mut names: Vec<String> = Vec::new();
+            for param in params {
+                match param {
+                    Value::Var(name) => names.push(name),
+                    other => return Err(error(format!("parameter '{}' is not a name", other))),
+                }
+            }
+            current = Some(TacFunction { name, is_static, params: names, body: Vec::new(), temps: 0 });
+        } else if line == "}" {
+            program.functions.extend(current.take());
+        } else if let Some(func) = current.as_mut() {
+            let instr = ParseInstr(line, &mut func.temps).map_err(error)?;
+            func.body.push(instr);
+        }
+    }
+
+    if let Some(func) = current {
+        return Err(format!("function '{}' is missing its closing '}}'", func.name));
+    }
+    Ok(program)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expression::build::*;
+
+    #[test]
+    fn nested_expressions_get_temporaries() {
+        // long foo(long a, long b) { long x = (a + b) * -a; return bar(x, 3) + x; }
+        let program = program(vec![func(
+            "foo",
+            vec!["a", "b"],
+            vec![
+                assign(
+                    "x",
+                    binop(
+                        OpType::MULTIPLY,
+                        binop(OpType::ADD, param_ref("a"), param_ref("b")),
+                        unop(OpType::NEGATE, param_ref("a")),
+                    ),
+                ),
+                ret(binop(OpType::ADD, call("bar", vec![var("x"), constant(3)]), var("x"))),
+            ],
+        )]);
+
+        let tac = Lower(&program);
+
+        assert_eq!(
+            tac.to_string(),
+            "func foo(a, b) {\n\
+             \tt1 = a + b\n\
+             \tt2 = neg a\n\
+             \tx = t1 * t2\n\
+             \tt4 = call bar(x, 3)\n\
+             \tt3 = t4 + x\n\
+             \tret t3\n\
+             }\n"
+        );
+        assert_eq!(tac.functions[0].temps, 4);
+    }
+
+    #[test]
+    fn printed_code_parses_back() {
+        let text = "static func foo(p, t1_) {\n\
+                    \tt1 = load p\n\
+                    \tt2 = t1 >>> -3\n\
+                    \t*p = t2\n\
+                    \tcall bar()\n\
+                    \tr.x = call mk(t1_, 7)\n\
+                    \tr.y = high(r.x)\n\
+                    \ts.y = high(result)\n\
+                    \tassert r.y @ 12\n\
+                    \tret r.x, r.y\n\
+                    }\n";
+
+        let tac = ParseTac(text).unwrap();
+
+        assert_eq!(tac.to_string(), text);
+        assert_eq!(tac.functions[0].params, vec!["p", "t1"]);
+        assert_eq!(tac.functions[0].temps, 2);
+    }
+
+    #[test]
+    fn parse_errors_name_the_line() {
+        let err = ParseTac("func foo() {\n\tx = a % b\n}\n").unwrap_err();
+        assert_eq!(err, "line 2: unknown operator '%'");
+    }
+}
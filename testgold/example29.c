@@ -0,0 +1,9 @@
+// C has no >>>: the logical shift right of tests/example29.c, on an unsigned long
+long foo(long arg1, long arg2) {
+    long temp1 = ~arg1;
+    long temp2 = (long) ((unsigned long) temp1 >> arg2);
+    long temp3 = temp1 >> arg2;
+    long temp4 = ~temp3 + temp2;
+    long temp5 = ~((long) ((unsigned long) temp4 >> 40));
+    return temp5;
+}
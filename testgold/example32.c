@@ -0,0 +1,15 @@
+// C has no overloading: tests/example32.c, with the overloads renamed
+long foo(long arg1, long arg2);
+
+long foo1(long arg1) {
+    return arg1 * 3;
+}
+
+long foo3(long arg1, long arg2, long arg3) {
+    return foo1(arg1) + arg2 * arg3;
+}
+
+long foo(long arg1, long arg2) {
+    long temp1 = foo3(arg1, arg2, 5);
+    return temp1 + foo1(arg2);
+}
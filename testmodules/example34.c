@@ -0,0 +1,12 @@
+// The second file of tests/example34.c
+static long helper(long value) {
+    return value * 2;
+}
+
+long scale(long value, long factor) {
+    return helper(value) * factor;
+}
+
+long offset(long value) {
+    return helper(value) - 5;
+}
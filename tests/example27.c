@@ -0,0 +1,8 @@
+// Example for 64-bit constants: values outside the 32-bit immediate range
+long foo(long arg1, long arg2) {
+    long temp1 = 5000000000;
+    long temp2 = arg1 * 4294967296;
+    long temp3 = temp2 + temp1;
+    long temp4 = temp3 - 9223372036854775807;
+    return temp4;
+ }
@@ -0,0 +1,11 @@
+// Example for constants: file scope and function scope, folded at compile time
+const long WIDTH = 4 * 8;
+const long MASK = WIDTH - 1;
+
+long foo(long arg1, long arg2) {
+    const long SCALE = WIDTH / 2 + MASK;
+    long temp1 = arg1 * SCALE;
+    long temp2 = temp1 & MASK;
+    long temp3 = temp2 + arg2 * WIDTH;
+    return temp3;
+}
@@ -0,0 +1,9 @@
+// Example for bitwise not and the logical shift right >>>
+long foo(long arg1, long arg2) {
+    long temp1 = ~arg1;
+    long temp2 = temp1 >>> arg2;
+    long temp3 = temp1 >> arg2;
+    long temp4 = ~temp3 + temp2;
+    long temp5 = ~(temp4 >>> 40);
+    return temp5;
+}
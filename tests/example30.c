@@ -0,0 +1,12 @@
+// Example for assert: the first check is proven true and removed, the others run
+const long LIMIT = 100;
+
+long foo(long arg1, long arg2) {
+    long temp1 = LIMIT - 1;
+    assert(temp1);
+    long temp2 = arg1 / arg2;
+    assert(temp2);
+    long temp3 = temp2 + temp1;
+    assert(arg2 & 6);
+    return temp3;
+}
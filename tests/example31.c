@@ -0,0 +1,17 @@
+// Example for prototypes: functions called before they are defined
+long scale(long value, long factor);
+long offset(long value);
+
+long foo(long arg1, long arg2) {
+    long temp1 = scale(arg1, arg2);
+    long temp2 = offset(temp1);
+    return temp2;
+}
+
+long scale(long value, long factor) {
+    return offset(value) * factor;
+}
+
+long offset(long value) {
+    return value + 7;
+}
@@ -0,0 +1,16 @@
+// Example for overloading: foo(long, long), declared first, keeps the plain
+// symbol testassembly/main.c calls, and the other overloads get mangled ones
+long foo(long arg1, long arg2);
+
+long foo(long arg1) {
+    return arg1 * 3;
+}
+
+long foo(long arg1, long arg2, long arg3) {
+    return foo(arg1) + arg2 * arg3;
+}
+
+long foo(long arg1, long arg2) {
+    long temp1 = foo(arg1, arg2, 5);
+    return temp1 + foo(arg2);
+}
@@ -0,0 +1,26 @@
+// Example for static functions: local symbols, and an unused one is deleted
+static long square(long value) {
+    return value * value;
+}
+
+static long unused(long value) {
+    return value - 1;
+}
+
+static long combine(long left, long right);
+
+long foo(long arg1, long arg2) {
+    long temp1 = square(arg1);
+    long temp2 = combine(temp1, arg2);
+    return temp2;
+}
+
+// too large to inline, so it stays a function of its own
+static long combine(long left, long right) {
+    long temp1 = left * right + left / right;
+    long temp2 = temp1 - right * 3 + (left ^ right);
+    long temp3 = temp2 * temp1 - (temp2 | right) + (temp1 & left);
+    long temp4 = temp3 / (right + 1) + temp3 * 7 - (temp2 << 2);
+    long temp5 = temp4 - temp3 + (temp2 >> 1) * (temp1 ^ temp4);
+    return temp5 + temp4 * left - temp3;
+}
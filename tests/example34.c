@@ -0,0 +1,13 @@
+// Example for compiling two files into one program: scale and offset are in
+// testmodules/example34.c, which has a static helper of the same name as ours
+long scale(long value, long factor);
+long offset(long value);
+
+static long helper(long value) {
+    return value + 1;
+}
+
+long foo(long arg1, long arg2) {
+    long temp1 = scale(arg1, arg2);
+    return offset(temp1) + helper(arg2);
+}
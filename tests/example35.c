@@ -0,0 +1,15 @@
+// Example for pointers: the address of a local, loads, stores and arithmetic
+long bump(long *target, long amount) {
+    *target = *target + amount;
+    return *target;
+}
+
+long foo(long arg1, long arg2) {
+    long total = arg1;
+    long *p = &total;
+    long before = bump(p, arg2);
+    *p = *p * 2;
+    long *q = p + 1;
+    long diff = q - p;
+    return total + before + diff + *(q - 1);
+}
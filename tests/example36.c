@@ -0,0 +1,36 @@
+// Example for structs: the second field of a returned struct must survive later calls
+struct Pair {
+    long low;
+    long high;
+};
+
+struct Pair make(long a, long b) {
+    struct Pair r;
+    r.low = a + b;
+    r.high = a * b;
+    return r;
+}
+
+long weight(long a) {
+    return a / 3 + 1;
+}
+
+struct Pair swap(struct Pair p) {
+    struct Pair r;
+    r.low = p.high;
+    r.high = p.low;
+    return r;
+}
+
+struct Pair twice(long a, long b) {
+    return make(a + a, b + b);
+}
+
+long foo(long arg1, long arg2) {
+    struct Pair first = make(arg1, arg2);
+    long w = weight(arg1);
+    struct Pair again = make(arg1, arg2);
+    struct Pair other = swap(first);
+    struct Pair big = twice(arg1, arg2);
+    return first.high * 1000 + w * 100 + again.high + other.low + big.high + big.low;
+}